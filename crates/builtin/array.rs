@@ -13,11 +13,11 @@ use microcad_builtin_proc_macros::builtin_mod;
 pub mod array {
     use microcad_builtin_proc_macros::builtin_fn;
     use microcad_lang::{
-        eval::EvalError,
+        eval::{EvalError, EvalResult},
         parameter,
-        value::{Value, ValueAccess},
+        value::{Array, Value, ValueList},
     };
-    use microcad_lang_base::PushDiag;
+    use microcad_lang_base::{PushDiag, SrcReferrer};
 
     /// Return the number of elements in an array.
     #[builtin_fn(x)]
@@ -208,22 +208,8 @@ pub mod array {
     #[builtin_fn(arr, x)]
     pub fn contains() -> Symbol {
         |_params, args, ctx| {
-            let arr = match args.get_value("arr") {
-                Err(_) => args
-                    .get_by_index(0)
-                    .map(|(_, arg)| arg.value.clone())
-                    .unwrap_or_default(),
-                Ok(arr) => arr.clone(),
-            };
-            let x = match args.get_value("x") {
-                Err(_) => args
-                    .get_by_index(1)
-                    .map(|(_, arg)| arg.value.clone())
-                    .unwrap_or_default(),
-                Ok(arr) => arr.clone(),
-            };
-
-            Ok(match arr {
+            let x = args.get_by_id_or_index("x", 1);
+            Ok(match args.get_by_id_or_index("arr", 0) {
                 Value::Array(a) => a.contains(&x).into(),
                 _ => {
                     ctx.error(
@@ -235,4 +221,287 @@ pub mod array {
             })
         }
     }
+
+    /// Return the elements of an array from index `start` up to (but excluding) index `end`.
+    ///
+    /// If `end` is omitted, all elements until the end of the array are returned.
+    #[builtin_fn(x, start: Integer, end: Integer)]
+    pub fn slice() -> Symbol {
+        |_params, args, ctx| {
+            Ok(
+                match (
+                    args.get_by_id_or_index("x", 0),
+                    args.get_by_id_or_index("start", 1),
+                    args.get_by_id_or_index("end", 2),
+                ) {
+                    (Value::Array(a), Value::Integer(start), end) => {
+                        let end = match end {
+                            Value::Integer(end) => end.max(0) as usize,
+                            _ => a.len(),
+                        };
+                        a.slice(start.max(0) as usize, end).into()
+                    }
+                    _ => {
+                        ctx.error(
+                            args,
+                            EvalError::BuiltinError("Expected an array and a start index.".into()),
+                        )?;
+                        Value::None
+                    }
+                },
+            )
+        }
+    }
+
+    /// Flatten an array of arrays by one level.
+    #[builtin_fn(x)]
+    pub fn flatten() -> Symbol {
+        |_params, args, ctx| {
+            let arg = args.get_single()?;
+            Ok(match &arg.1.value {
+                Value::Array(a) => match a.flatten() {
+                    Some(a) => a.into(),
+                    None => {
+                        ctx.error(
+                            arg.1,
+                            EvalError::BuiltinError("Value is not an array of arrays.".into()),
+                        )?;
+                        Value::None
+                    }
+                },
+                _ => {
+                    ctx.error(
+                        arg.1,
+                        EvalError::BuiltinError("Value is not an array.".into()),
+                    )?;
+                    Value::None
+                }
+            })
+        }
+    }
+
+    /// Return an array of `(index, value)` tuples for each element of an array.
+    #[builtin_fn(x)]
+    pub fn enumerate() -> Symbol {
+        |_params, args, ctx| {
+            let arg = args.get_single()?;
+            Ok(match &arg.1.value {
+                Value::Array(a) => a.enumerate().into(),
+                _ => {
+                    ctx.error(
+                        arg.1,
+                        EvalError::BuiltinError("Value is not an array.".into()),
+                    )?;
+                    Value::None
+                }
+            })
+        }
+    }
+
+    /// Combine the elements of two arrays pairwise into an array of `(a, b)` tuples.
+    ///
+    /// The result has the length of the shorter array.
+    #[builtin_fn(a, b)]
+    pub fn zip() -> Symbol {
+        |_params, args, ctx| {
+            Ok(
+                match (
+                    args.get_by_id_or_index("a", 0),
+                    args.get_by_id_or_index("b", 1),
+                ) {
+                    (Value::Array(a), Value::Array(b)) => a.zip(&b).into(),
+                    _ => {
+                        ctx.error(
+                            args,
+                            EvalError::BuiltinError("Values are not arrays.".into()),
+                        )?;
+                        Value::None
+                    }
+                },
+            )
+        }
+    }
+
+    /// Return the smallest element of an array of integers or quantities.
+    #[builtin_fn(x)]
+    pub fn min() -> Symbol {
+        |_params, args, ctx| {
+            let arg = args.get_single()?;
+            Ok(match &arg.1.value {
+                Value::Array(a) => match a.min() {
+                    Value::None => {
+                        ctx.error(
+                            arg.1,
+                            EvalError::BuiltinError("Array has no minimum.".into()),
+                        )?;
+                        Value::None
+                    }
+                    value => value,
+                },
+                _ => {
+                    ctx.error(
+                        arg.1,
+                        EvalError::BuiltinError("Value is not an array.".into()),
+                    )?;
+                    Value::None
+                }
+            })
+        }
+    }
+
+    /// Return the largest element of an array of integers or quantities.
+    #[builtin_fn(x)]
+    pub fn max() -> Symbol {
+        |_params, args, ctx| {
+            let arg = args.get_single()?;
+            Ok(match &arg.1.value {
+                Value::Array(a) => match a.max() {
+                    Value::None => {
+                        ctx.error(
+                            arg.1,
+                            EvalError::BuiltinError("Array has no maximum.".into()),
+                        )?;
+                        Value::None
+                    }
+                    value => value,
+                },
+                _ => {
+                    ctx.error(
+                        arg.1,
+                        EvalError::BuiltinError("Value is not an array.".into()),
+                    )?;
+                    Value::None
+                }
+            })
+        }
+    }
+
+    /// Return the sum of all elements in an array.
+    #[builtin_fn(x)]
+    pub fn sum() -> Symbol {
+        |_params, args, ctx| {
+            let arg = args.get_single()?;
+            Ok(match &arg.1.value {
+                Value::Array(a) => match a.sum() {
+                    Ok(value) => value,
+                    Err(err) => {
+                        ctx.error(arg.1, err)?;
+                        Value::None
+                    }
+                },
+                _ => {
+                    ctx.error(
+                        arg.1,
+                        EvalError::BuiltinError("Value is not an array.".into()),
+                    )?;
+                    Value::None
+                }
+            })
+        }
+    }
+
+    /// Apply the function `f` to each element of an array and return an array of the results.
+    #[builtin_fn(x, f)]
+    pub fn map() -> Symbol {
+        |_params, args, ctx| {
+            Ok(
+                match (
+                    args.get_by_id_or_index("x", 0),
+                    args.get_by_id_or_index("f", 1),
+                ) {
+                    // Keep the element type of empty arrays.
+                    (Value::Array(a), Value::Function(_)) if a.is_empty() => a.into(),
+                    (Value::Array(a), Value::Function(f)) => {
+                        let values = a
+                            .iter()
+                            .map(|x| f.call_with_values([x.clone()], args.src_ref(), ctx))
+                            .collect::<EvalResult<ValueList>>()?;
+                        match Array::try_from(values) {
+                            Ok(a) => a.into(),
+                            Err(err) => {
+                                ctx.error(args, err)?;
+                                Value::None
+                            }
+                        }
+                    }
+                    _ => {
+                        ctx.error(
+                            args,
+                            EvalError::BuiltinError("Expected an array and a function.".into()),
+                        )?;
+                        Value::None
+                    }
+                },
+            )
+        }
+    }
+
+    /// Return all elements of an array for which the function `f` returns `true`.
+    #[builtin_fn(x, f)]
+    pub fn filter() -> Symbol {
+        |_params, args, ctx| {
+            Ok(
+                match (
+                    args.get_by_id_or_index("x", 0),
+                    args.get_by_id_or_index("f", 1),
+                ) {
+                    (Value::Array(a), Value::Function(f)) => a
+                        .filter(|x| -> EvalResult<bool> {
+                            match f.call_with_values([x.clone()], args.src_ref(), ctx)? {
+                                Value::Bool(b) => Ok(b),
+                                value => {
+                                    ctx.error(
+                                        args,
+                                        EvalError::BuiltinError(format!(
+                                            "Filter function {f} must return a Bool, got {value}."
+                                        )),
+                                    )?;
+                                    Ok(false)
+                                }
+                            }
+                        })?
+                        .into(),
+                    _ => {
+                        ctx.error(
+                            args,
+                            EvalError::BuiltinError("Expected an array and a function.".into()),
+                        )?;
+                        Value::None
+                    }
+                },
+            )
+        }
+    }
+
+    /// Combine all elements of an array into a single value.
+    ///
+    /// Starting with `init`, the function `f` is called with the accumulated value
+    /// and each element of the array: `fold([1, 2, 3], 0, add)` calculates `add(add(add(0, 1), 2), 3)`.
+    #[builtin_fn(x, init, f)]
+    pub fn fold() -> Symbol {
+        |_params, args, ctx| {
+            Ok(
+                match (
+                    args.get_by_id_or_index("x", 0),
+                    args.get_by_id_or_index("init", 1),
+                    args.get_by_id_or_index("f", 2),
+                ) {
+                    (Value::Array(a), init, Value::Function(f)) => {
+                        a.iter().try_fold(init, |acc, x| {
+                            f.call_with_values([acc, x.clone()], args.src_ref(), ctx)
+                        })?
+                    }
+                    _ => {
+                        ctx.error(
+                            args,
+                            EvalError::BuiltinError(
+                                "Expected an array, an initial value and a function.".into(),
+                            ),
+                        )?;
+                        Value::None
+                    }
+                },
+            )
+        }
+    }
 }
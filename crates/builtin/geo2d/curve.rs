@@ -0,0 +1,308 @@
+// Copyright © 2026 The µcad authors <info@microcad.xyz>
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+//! Built-in curve primitives.
+
+use geo::orient::{Direction, Orient};
+use microcad_core::*;
+use microcad_lang::{
+    builtin::*,
+    eval::{EvalError, EvalResult},
+    model::*,
+    render::*,
+    ty::Type,
+    value::{Tuple, Value},
+};
+
+use super::polygon::{point_type, points};
+
+/// A curve which is flattened when rendered.
+struct CurveGeometry {
+    curve: Curve,
+    /// Connect the end of the curve with its start and fill it.
+    closed: bool,
+}
+
+impl CurveGeometry {
+    /// Create the workpiece output of a curve.
+    fn output(curve: Curve, closed: bool) -> RenderResult<BuiltinWorkpieceOutput> {
+        Ok(BuiltinWorkpieceOutput::Primitive2D(Box::new(Self {
+            curve,
+            closed,
+        })))
+    }
+
+    /// Report an invalid curve.
+    fn validate(curve: Curve) -> EvalResult<()> {
+        curve
+            .validate()
+            .map_err(|err| EvalError::BuiltinError(err.to_string()))
+    }
+
+    /// The parameters `points` and `closed` which all curves share.
+    fn parameters() -> [(Identifier, ParameterValue); 2] {
+        [
+            (
+                Identifier::no_ref("points"),
+                ParameterValue {
+                    specified_type: Some(Type::Array(Box::new(point_type()))),
+                    ..Default::default()
+                },
+            ),
+            parameter!(closed: Bool),
+        ]
+    }
+
+    /// Get the `points` argument.
+    fn points(args: &Tuple) -> Vec<Vec2> {
+        args.get_value("points").map(points).unwrap_or_default()
+    }
+}
+
+impl Render<Geometry2D> for CurveGeometry {
+    fn render(&self, resolution: &RenderResolution) -> Geometry2D {
+        let points = self.curve.flatten(self.closed, resolution);
+        let line_string = LineString::from(points.iter().map(|p| (p.x, p.y)).collect::<Vec<_>>());
+        match self.closed {
+            true => Geometry2D::Polygon(
+                geo2d::Polygon::new(line_string, vec![]).orient(Direction::Default),
+            ),
+            false => Geometry2D::LineString(line_string),
+        }
+    }
+}
+
+impl RenderWithContext<Geometry2DOutput> for CurveGeometry {
+    fn render_with_context(&self, context: &mut RenderContext) -> RenderResult<Geometry2DOutput> {
+        context.update_2d(|context, _| Ok(self.render(&context.current_resolution())))
+    }
+}
+
+/// Built-in quadratic Bézier path.
+pub struct QuadraticBezier;
+
+impl BuiltinWorkbenchDefinition for QuadraticBezier {
+    fn id() -> &'static str {
+        "QuadraticBezier"
+    }
+
+    fn help() -> Option<&'static str> {
+        Some(
+            "Built-in quadratic Bézier path.\n\nThe points are a start point followed by a control point and an end point for each segment.",
+        )
+    }
+
+    fn kind() -> BuiltinWorkbenchKind {
+        BuiltinWorkbenchKind::Primitive2D
+    }
+
+    fn output_type() -> OutputType {
+        OutputType::Geometry2D
+    }
+
+    fn workpiece_function() -> &'static BuiltinWorkpieceFn {
+        &|args| {
+            CurveGeometry::output(
+                Curve::QuadraticBezier(CurveGeometry::points(args)),
+                args.get("closed"),
+            )
+        }
+    }
+
+    fn validate(args: &Tuple) -> EvalResult<()> {
+        CurveGeometry::validate(Curve::QuadraticBezier(CurveGeometry::points(args)))
+    }
+
+    fn parameters() -> ParameterValueList {
+        CurveGeometry::parameters().into_iter().collect()
+    }
+}
+
+/// Built-in cubic Bézier path.
+pub struct CubicBezier;
+
+impl BuiltinWorkbenchDefinition for CubicBezier {
+    fn id() -> &'static str {
+        "CubicBezier"
+    }
+
+    fn help() -> Option<&'static str> {
+        Some(
+            "Built-in cubic Bézier path.\n\nThe points are a start point followed by two control points and an end point for each segment.",
+        )
+    }
+
+    fn kind() -> BuiltinWorkbenchKind {
+        BuiltinWorkbenchKind::Primitive2D
+    }
+
+    fn output_type() -> OutputType {
+        OutputType::Geometry2D
+    }
+
+    fn workpiece_function() -> &'static BuiltinWorkpieceFn {
+        &|args| {
+            CurveGeometry::output(
+                Curve::CubicBezier(CurveGeometry::points(args)),
+                args.get("closed"),
+            )
+        }
+    }
+
+    fn validate(args: &Tuple) -> EvalResult<()> {
+        CurveGeometry::validate(Curve::CubicBezier(CurveGeometry::points(args)))
+    }
+
+    fn parameters() -> ParameterValueList {
+        CurveGeometry::parameters().into_iter().collect()
+    }
+}
+
+/// Built-in Catmull-Rom spline.
+pub struct CatmullRom;
+
+impl BuiltinWorkbenchDefinition for CatmullRom {
+    fn id() -> &'static str {
+        "CatmullRom"
+    }
+
+    fn help() -> Option<&'static str> {
+        Some("Built-in Catmull-Rom spline.\n\nThe spline passes through all points.")
+    }
+
+    fn kind() -> BuiltinWorkbenchKind {
+        BuiltinWorkbenchKind::Primitive2D
+    }
+
+    fn output_type() -> OutputType {
+        OutputType::Geometry2D
+    }
+
+    fn workpiece_function() -> &'static BuiltinWorkpieceFn {
+        &|args| {
+            CurveGeometry::output(
+                Curve::CatmullRom(CurveGeometry::points(args)),
+                args.get("closed"),
+            )
+        }
+    }
+
+    fn validate(args: &Tuple) -> EvalResult<()> {
+        CurveGeometry::validate(Curve::CatmullRom(CurveGeometry::points(args)))
+    }
+
+    fn parameters() -> ParameterValueList {
+        CurveGeometry::parameters().into_iter().collect()
+    }
+}
+
+/// Built-in uniform B-spline.
+pub struct BSpline;
+
+impl BSpline {
+    fn curve(args: &Tuple) -> Curve {
+        let degree: Integer = args.get("degree");
+        Curve::BSpline {
+            points: CurveGeometry::points(args),
+            degree: degree.max(0) as usize,
+        }
+    }
+}
+
+impl BuiltinWorkbenchDefinition for BSpline {
+    fn id() -> &'static str {
+        "BSpline"
+    }
+
+    fn help() -> Option<&'static str> {
+        Some(
+            "Built-in uniform B-spline.\n\nThe points are control points. An open B-spline starts at the first and ends at the last point.",
+        )
+    }
+
+    fn kind() -> BuiltinWorkbenchKind {
+        BuiltinWorkbenchKind::Primitive2D
+    }
+
+    fn output_type() -> OutputType {
+        OutputType::Geometry2D
+    }
+
+    fn workpiece_function() -> &'static BuiltinWorkpieceFn {
+        &|args| CurveGeometry::output(Self::curve(args), args.get("closed"))
+    }
+
+    fn validate(args: &Tuple) -> EvalResult<()> {
+        CurveGeometry::validate(Self::curve(args))
+    }
+
+    fn parameters() -> ParameterValueList {
+        CurveGeometry::parameters()
+            .into_iter()
+            .chain([parameter!(degree: Integer)])
+            .collect()
+    }
+}
+
+/// Built-in circular arc through three points.
+pub struct Arc;
+
+impl Arc {
+    fn curve(args: &Tuple) -> Curve {
+        let point = |id| {
+            let coordinate = |value: Option<&Value>| match value {
+                Some(Value::Quantity(quantity)) => quantity.value,
+                _ => 0.0,
+            };
+            match args.get_value(id) {
+                Ok(Value::Tuple(tuple)) => Vec2::new(
+                    coordinate(tuple.get_value("x").ok()),
+                    coordinate(tuple.get_value("y").ok()),
+                ),
+                _ => Vec2::new(0.0, 0.0),
+            }
+        };
+        Curve::Arc(point("start"), point("through"), point("end"))
+    }
+}
+
+impl BuiltinWorkbenchDefinition for Arc {
+    fn id() -> &'static str {
+        "Arc"
+    }
+
+    fn help() -> Option<&'static str> {
+        Some("Built-in circular arc from a start point through a point to an end point.")
+    }
+
+    fn kind() -> BuiltinWorkbenchKind {
+        BuiltinWorkbenchKind::Primitive2D
+    }
+
+    fn output_type() -> OutputType {
+        OutputType::Geometry2D
+    }
+
+    fn workpiece_function() -> &'static BuiltinWorkpieceFn {
+        &|args| CurveGeometry::output(Self::curve(args), false)
+    }
+
+    fn validate(args: &Tuple) -> EvalResult<()> {
+        CurveGeometry::validate(Self::curve(args))
+    }
+
+    fn parameters() -> ParameterValueList {
+        ["start", "through", "end"]
+            .into_iter()
+            .map(|id| {
+                (
+                    Identifier::no_ref(id),
+                    ParameterValue {
+                        specified_type: Some(point_type()),
+                        ..Default::default()
+                    },
+                )
+            })
+            .collect()
+    }
+}
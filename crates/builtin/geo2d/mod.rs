@@ -4,9 +4,12 @@
 //! Built-in 2D geometries.
 
 mod circle;
+mod curve;
 mod involute_gear;
 mod line;
 mod ngon;
+mod polygon;
+mod polyline;
 mod rect;
 mod rounded_rect;
 mod sector;
@@ -23,6 +26,13 @@ pub fn geo2d() -> Symbol {
         .builtin::<involute_gear::InvoluteGearProfile>()
         .builtin::<line::Line>()
         .builtin::<ngon::Ngon>()
+        .builtin::<polygon::Polygon>()
+        .builtin::<polyline::Polyline>()
+        .builtin::<curve::QuadraticBezier>()
+        .builtin::<curve::CubicBezier>()
+        .builtin::<curve::CatmullRom>()
+        .builtin::<curve::BSpline>()
+        .builtin::<curve::Arc>()
         .builtin::<sector::Sector>()
         .builtin::<rect::Rect>()
         .builtin::<rounded_rect::RoundedRect>()
@@ -0,0 +1,133 @@
+// Copyright © 2026 The µcad authors <info@microcad.xyz>
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+//! Built-in polygon primitive.
+
+use microcad_core::*;
+use microcad_lang::{
+    builtin::*,
+    eval::{EvalError, EvalResult},
+    model::*,
+    render::*,
+    ty::Type,
+    value::{Array, Tuple, Value},
+};
+
+/// Built-in polygon primitive with optional holes.
+pub struct Polygon {
+    /// Validated polygon.
+    polygon: geo2d::Polygon,
+}
+
+/// Type of a single 2D point: `(x: Length, y: Length)`.
+pub(super) fn point_type() -> Type {
+    Type::Tuple(Box::new(
+        [("x", Type::length()), ("y", Type::length())]
+            .into_iter()
+            .collect(),
+    ))
+}
+
+/// Return the points of an array of `(x: Length, y: Length)` tuples.
+pub(super) fn points(value: &Value) -> Vec<Vec2> {
+    let coordinate = |value: Option<&Value>| match value {
+        Some(Value::Quantity(quantity)) => quantity.value,
+        _ => 0.0,
+    };
+    match value {
+        Value::Array(array) => array
+            .iter()
+            .filter_map(|value| match value {
+                Value::Tuple(tuple) => Some(Vec2::new(
+                    coordinate(tuple.get_value("x").ok()),
+                    coordinate(tuple.get_value("y").ok()),
+                )),
+                _ => None,
+            })
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+impl Polygon {
+    /// Build and validate the polygon from the `points` and `holes` arguments.
+    fn polygon(args: &Tuple) -> Result<geo2d::Polygon, PolygonError> {
+        let exterior = args.get_value("points").map(points).unwrap_or_default();
+        let holes: Vec<_> = match args.get_value("holes") {
+            Ok(Value::Array(array)) => array.iter().map(points).collect(),
+            _ => Vec::new(),
+        };
+        polygon_from_points(&exterior, &holes)
+    }
+}
+
+impl BuiltinWorkbenchDefinition for Polygon {
+    fn id() -> &'static str {
+        "Polygon"
+    }
+
+    fn help() -> Option<&'static str> {
+        Some(
+            "Built-in polygon primitive with optional holes.\n\nOutline and holes must not intersect and holes must be inside the outline. Points may be given clockwise or counter-clockwise.",
+        )
+    }
+
+    fn kind() -> BuiltinWorkbenchKind {
+        BuiltinWorkbenchKind::Primitive2D
+    }
+
+    fn output_type() -> OutputType {
+        OutputType::Geometry2D
+    }
+
+    fn workpiece_function() -> &'static BuiltinWorkpieceFn {
+        &|args| {
+            // Invalid polygons have already been reported during evaluation.
+            Ok(BuiltinWorkpieceOutput::Primitive2D(Box::new(Polygon {
+                polygon: Self::polygon(args)
+                    .unwrap_or_else(|_| geo2d::Polygon::new(LineString::new(vec![]), vec![])),
+            })))
+        }
+    }
+
+    fn validate(args: &Tuple) -> EvalResult<()> {
+        Self::polygon(args)
+            .map(|_| ())
+            .map_err(|err| EvalError::BuiltinError(err.to_string()))
+    }
+
+    fn parameters() -> ParameterValueList {
+        let ring_type = Type::Array(Box::new(point_type()));
+        [
+            (
+                Identifier::no_ref("points"),
+                ParameterValue {
+                    specified_type: Some(ring_type.clone()),
+                    ..Default::default()
+                },
+            ),
+            (
+                Identifier::no_ref("holes"),
+                ParameterValue {
+                    specified_type: Some(Type::Array(Box::new(ring_type.clone()))),
+                    default_value: Some(Value::Array(Array::new(ring_type))),
+                    ..Default::default()
+                },
+            ),
+        ]
+        .into_iter()
+        .collect()
+    }
+}
+
+impl Render<Geometry2D> for Polygon {
+    fn render(&self, _: &RenderResolution) -> Geometry2D {
+        Geometry2D::Polygon(self.polygon.clone())
+    }
+}
+
+impl RenderWithContext<Geometry2DOutput> for Polygon {
+    fn render_with_context(&self, context: &mut RenderContext) -> RenderResult<Geometry2DOutput> {
+        context.update_2d(|context, _| Ok(self.render(&context.current_resolution())))
+    }
+}
@@ -0,0 +1,101 @@
+// Copyright © 2026 The µcad authors <info@microcad.xyz>
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+//! Built-in polyline primitive.
+
+use microcad_core::*;
+use microcad_lang::{
+    builtin::*,
+    eval::{EvalError, EvalResult},
+    model::*,
+    render::*,
+    ty::Type,
+    value::Tuple,
+};
+
+use super::polygon::{point_type, points};
+
+/// Built-in polyline primitive, an open path through points.
+pub struct Polyline {
+    /// Points of the path.
+    points: Vec<Vec2>,
+    /// Connect the last point with the first one.
+    closed: bool,
+}
+
+impl Polyline {
+    /// Return the points without consecutive duplicates.
+    fn points(args: &Tuple) -> Vec<Vec2> {
+        let mut points = args.get_value("points").map(points).unwrap_or_default();
+        points.dedup();
+        points
+    }
+}
+
+impl BuiltinWorkbenchDefinition for Polyline {
+    fn id() -> &'static str {
+        "Polyline"
+    }
+
+    fn help() -> Option<&'static str> {
+        Some("Built-in polyline primitive, an open path through points.")
+    }
+
+    fn kind() -> BuiltinWorkbenchKind {
+        BuiltinWorkbenchKind::Primitive2D
+    }
+
+    fn output_type() -> OutputType {
+        OutputType::Geometry2D
+    }
+
+    fn workpiece_function() -> &'static BuiltinWorkpieceFn {
+        &|args| {
+            Ok(BuiltinWorkpieceOutput::Primitive2D(Box::new(Polyline {
+                points: Self::points(args),
+                closed: args.get("closed"),
+            })))
+        }
+    }
+
+    fn validate(args: &Tuple) -> EvalResult<()> {
+        match Self::points(args).len() < 2 {
+            true => Err(EvalError::BuiltinError(
+                "Polyline needs at least two distinct points".into(),
+            )),
+            false => Ok(()),
+        }
+    }
+
+    fn parameters() -> ParameterValueList {
+        [
+            (
+                Identifier::no_ref("points"),
+                ParameterValue {
+                    specified_type: Some(Type::Array(Box::new(point_type()))),
+                    ..Default::default()
+                },
+            ),
+            parameter!(closed: Bool),
+        ]
+        .into_iter()
+        .collect()
+    }
+}
+
+impl Render<Geometry2D> for Polyline {
+    fn render(&self, _: &RenderResolution) -> Geometry2D {
+        let mut line_string =
+            LineString::from(self.points.iter().map(|p| (p.x, p.y)).collect::<Vec<_>>());
+        if self.closed {
+            line_string.close();
+        }
+        Geometry2D::LineString(line_string)
+    }
+}
+
+impl RenderWithContext<Geometry2DOutput> for Polyline {
+    fn render_with_context(&self, context: &mut RenderContext) -> RenderResult<Geometry2DOutput> {
+        context.update_2d(|context, _| Ok(self.render(&context.current_resolution())))
+    }
+}
@@ -0,0 +1,33 @@
+// Copyright © 2026 The µcad authors <info@microcad.xyz>
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+use microcad_builtin_proc_macros::BuiltinPrimitive3D;
+use microcad_core::*;
+use microcad_lang::{builtin::*, render::*};
+
+/// The builtin capsule primitive, a cylinder with hemispherical ends centered at the origin.
+#[derive(BuiltinPrimitive3D)]
+pub struct Capsule {
+    /// Radius of the capsule in millimeters.
+    pub radius: Length,
+    /// Total height of the capsule along the Z axis in millimeters.
+    pub height: Length,
+}
+
+impl Render<Geometry3D> for Capsule {
+    fn render(&self, resolution: &RenderResolution) -> Geometry3D {
+        let z = (*self.height / 2.0 - *self.radius).max(0.0);
+        hull_of_spheres(
+            &[Vec3::new(0.0, 0.0, -z), Vec3::new(0.0, 0.0, z)],
+            *self.radius,
+            resolution.circular_segments(*self.radius),
+        )
+        .into()
+    }
+}
+
+impl RenderWithContext<Geometry3DOutput> for Capsule {
+    fn render_with_context(&self, context: &mut RenderContext) -> RenderResult<Geometry3DOutput> {
+        context.update_3d(|context, _| Ok(self.render(&context.current_resolution())))
+    }
+}
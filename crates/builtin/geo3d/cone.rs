@@ -0,0 +1,37 @@
+// Copyright © 2026 The µcad authors <info@microcad.xyz>
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+use microcad_builtin_proc_macros::BuiltinPrimitive3D;
+use microcad_core::*;
+use microcad_lang::{builtin::*, render::*};
+
+/// The builtin cone primitive with its base at `z = 0`.
+#[derive(BuiltinPrimitive3D)]
+pub struct Cone {
+    /// Radius of the base in millimeters.
+    pub radius_bottom: Length,
+    /// Radius of the top in millimeters.
+    pub radius_top: Length,
+    /// Height of the cone in millimeters.
+    pub height: Length,
+}
+
+impl Render<Geometry3D> for Cone {
+    fn render(&self, resolution: &RenderResolution) -> Geometry3D {
+        let segments = resolution.circular_segments(self.radius_bottom.max(*self.radius_top));
+        TriangleMesh::cone(
+            *self.radius_bottom,
+            *self.radius_top,
+            *self.height,
+            segments,
+        )
+        .to_manifold()
+        .into()
+    }
+}
+
+impl RenderWithContext<Geometry3DOutput> for Cone {
+    fn render_with_context(&self, context: &mut RenderContext) -> RenderResult<Geometry3DOutput> {
+        context.update_3d(|context, _| Ok(self.render(&context.current_resolution())))
+    }
+}
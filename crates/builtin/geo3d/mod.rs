@@ -3,8 +3,16 @@
 
 //! Built-in 3D geometries.
 
+mod capsule;
+mod cone;
+mod polyhedron;
+mod rounded_box;
 mod sphere;
 
+pub use capsule::*;
+pub use cone::*;
+pub use polyhedron::*;
+pub use rounded_box::*;
 pub use sphere::*;
 
 use microcad_lang::builtin::*;
@@ -12,6 +20,10 @@ use microcad_lang::builtin::*;
 /// geo3d Builtin module
 pub fn geo3d() -> Symbol {
     crate::ModuleBuilder::new("geo3d")
+        .builtin::<Capsule>()
+        .builtin::<Cone>()
+        .builtin::<Polyhedron>()
+        .builtin::<RoundedBox>()
         .builtin::<Sphere>()
         .build()
 }
@@ -0,0 +1,150 @@
+// Copyright © 2026 The µcad authors <info@microcad.xyz>
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+use microcad_core::*;
+use microcad_lang::{
+    builtin::*,
+    eval::{EvalError, EvalResult},
+    model::*,
+    render::*,
+    value::{Tuple, Value},
+};
+
+/// The builtin polyhedron primitive, defined by points and faces.
+pub struct Polyhedron {
+    /// Validated mesh.
+    mesh: TriangleMesh,
+}
+
+impl Polyhedron {
+    /// Type of a single point: `(x: Length, y: Length, z: Length)`.
+    fn point_type() -> microcad_lang::ty::Type {
+        use microcad_lang::ty::*;
+
+        Type::Tuple(Box::new(
+            [
+                ("x", Type::length()),
+                ("y", Type::length()),
+                ("z", Type::length()),
+            ]
+            .into_iter()
+            .collect(),
+        ))
+    }
+
+    /// Build and validate the mesh from the `points` and `faces` arguments.
+    fn mesh(args: &Tuple) -> Result<TriangleMesh, PolyhedronError> {
+        let coordinate = |value: Option<&Value>| match value {
+            Some(Value::Quantity(quantity)) => quantity.value,
+            _ => 0.0,
+        };
+        let points: Vec<Vec3> = match args.get_value("points") {
+            Ok(Value::Array(array)) => array
+                .iter()
+                .filter_map(|value| match value {
+                    Value::Tuple(tuple) => Some(Vec3::new(
+                        coordinate(tuple.get_value("x").ok()),
+                        coordinate(tuple.get_value("y").ok()),
+                        coordinate(tuple.get_value("z").ok()),
+                    )),
+                    _ => None,
+                })
+                .collect(),
+            _ => Vec::new(),
+        };
+        let faces: Vec<Vec<usize>> = match args.get_value("faces") {
+            Ok(Value::Array(array)) => array
+                .iter()
+                .filter_map(|value| match value {
+                    Value::Array(face) => Some(
+                        face.iter()
+                            .filter_map(|index| match index {
+                                // Negative indices are out of range, too.
+                                Value::Integer(index) => {
+                                    Some(usize::try_from(*index).unwrap_or(usize::MAX))
+                                }
+                                _ => None,
+                            })
+                            .collect(),
+                    ),
+                    _ => None,
+                })
+                .collect(),
+            _ => Vec::new(),
+        };
+
+        TriangleMesh::polyhedron(&points, &faces)
+    }
+}
+
+impl BuiltinWorkbenchDefinition for Polyhedron {
+    fn id() -> &'static str {
+        "Polyhedron"
+    }
+
+    fn help() -> Option<&'static str> {
+        Some(
+            "The builtin polyhedron primitive, defined by points and faces.\n\n- `points: [(x: Length, y: Length, z: Length)]`: Corner points.\n- `faces: [[Integer]]`: Faces as lists of point indices. Faces must form a closed surface and be ordered consistently.",
+        )
+    }
+
+    fn kind() -> BuiltinWorkbenchKind {
+        BuiltinWorkbenchKind::Primitive3D
+    }
+
+    fn output_type() -> OutputType {
+        OutputType::Geometry3D
+    }
+
+    fn workpiece_function() -> &'static BuiltinWorkpieceFn {
+        &|args| {
+            // Invalid polyhedrons have already been reported during evaluation.
+            Ok(BuiltinWorkpieceOutput::Primitive3D(Box::new(Polyhedron {
+                mesh: Self::mesh(args).unwrap_or_default(),
+            })))
+        }
+    }
+
+    fn validate(args: &Tuple) -> EvalResult<()> {
+        Self::mesh(args)
+            .map(|_| ())
+            .map_err(|err| EvalError::BuiltinError(err.to_string()))
+    }
+
+    fn parameters() -> ParameterValueList {
+        use microcad_lang::ty::*;
+
+        [
+            (
+                Identifier::no_ref("points"),
+                ParameterValue {
+                    specified_type: Some(Type::Array(Box::new(Self::point_type()))),
+                    ..Default::default()
+                },
+            ),
+            (
+                Identifier::no_ref("faces"),
+                ParameterValue {
+                    specified_type: Some(Type::Array(Box::new(Type::Array(Box::new(
+                        Type::Integer,
+                    ))))),
+                    ..Default::default()
+                },
+            ),
+        ]
+        .into_iter()
+        .collect()
+    }
+}
+
+impl Render<Geometry3D> for Polyhedron {
+    fn render(&self, _: &RenderResolution) -> Geometry3D {
+        self.mesh.to_manifold().into()
+    }
+}
+
+impl RenderWithContext<Geometry3DOutput> for Polyhedron {
+    fn render_with_context(&self, context: &mut RenderContext) -> RenderResult<Geometry3DOutput> {
+        context.update_3d(|context, _| Ok(self.render(&context.current_resolution())))
+    }
+}
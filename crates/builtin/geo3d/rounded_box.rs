@@ -0,0 +1,50 @@
+// Copyright © 2026 The µcad authors <info@microcad.xyz>
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+use microcad_builtin_proc_macros::BuiltinPrimitive3D;
+use microcad_core::*;
+use microcad_lang::{builtin::*, render::*};
+
+/// The builtin rounded box primitive centered at the origin.
+#[derive(BuiltinPrimitive3D)]
+pub struct RoundedBox {
+    /// Size along the X axis in millimeters.
+    pub size_x: Length,
+    /// Size along the Y axis in millimeters.
+    pub size_y: Length,
+    /// Size along the Z axis in millimeters.
+    pub size_z: Length,
+    /// Radius of edges and corners in millimeters.
+    pub radius: Length,
+}
+
+impl Render<Geometry3D> for RoundedBox {
+    fn render(&self, resolution: &RenderResolution) -> Geometry3D {
+        let size = Vec3::new(*self.size_x, *self.size_y, *self.size_z);
+        let radius = self.radius.min(size.x.min(size.y).min(size.z) / 2.0);
+        if radius <= 0.0 {
+            use microcad_core::traits::Center;
+            return Geometry3D::from(Manifold::cube(size.x, size.y, size.z)).center();
+        }
+
+        // Centers of the corner spheres.
+        let (x, y, z) = (
+            size.x / 2.0 - radius,
+            size.y / 2.0 - radius,
+            size.z / 2.0 - radius,
+        );
+        let centers: Vec<_> = [-x, x]
+            .into_iter()
+            .flat_map(|x| [-y, y].into_iter().map(move |y| (x, y)))
+            .flat_map(|(x, y)| [-z, z].into_iter().map(move |z| Vec3::new(x, y, z)))
+            .collect();
+
+        hull_of_spheres(&centers, radius, resolution.circular_segments(radius)).into()
+    }
+}
+
+impl RenderWithContext<Geometry3DOutput> for RoundedBox {
+    fn render_with_context(&self, context: &mut RenderContext) -> RenderResult<Geometry3DOutput> {
+        context.update_3d(|context, _| Ok(self.render(&context.current_resolution())))
+    }
+}
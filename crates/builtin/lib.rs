@@ -105,4 +105,5 @@ pub fn builtin_exporters() -> ExporterRegistry {
         .insert(stl::StlExporter)
         .insert(json::JsonExporter)
         .insert(wkt::WktExporter)
+        .insert(bom::BomExporter)
 }
@@ -0,0 +1,36 @@
+// Copyright © 2026 The µcad authors <info@microcad.xyz>
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+//! Builtin chamfer operation.
+
+use microcad_builtin_proc_macros::BuiltinOperation2D;
+use microcad_core::*;
+use microcad_lang::{builtin::*, render::*};
+
+/// Bevel the corners of a 2D geometry.
+#[derive(BuiltinOperation2D)]
+pub struct Chamfer {
+    /// Distance of the cut from the corner along each edge.
+    distance: Length,
+    /// Bevel concave corners.
+    inner: Bool,
+    /// Bevel convex corners.
+    outer: Bool,
+}
+
+impl Operation for Chamfer {
+    fn process_2d(&self, context: &mut RenderContext) -> RenderResult<Geometry2DOutput> {
+        context.update_2d(|context, model| {
+            let model_ = model.borrow();
+            let geometries: Geometries2D = model_.children.render_with_context(context)?;
+
+            Ok(Geometry2D::MultiPolygon(geometries.chamfer(
+                *self.distance,
+                Corners {
+                    inner: self.inner,
+                    outer: self.outer,
+                },
+            )))
+        })
+    }
+}
@@ -16,6 +16,33 @@ pub struct Extrude {
     scale_y: Scalar,
     /// Twist angle in °0 direction.
     twist: Angle,
+    /// Radius of rounded bottom edges.
+    bottom_fillet: Length,
+    /// Distance of beveled bottom edges.
+    bottom_chamfer: Length,
+    /// Radius of rounded top edges.
+    top_fillet: Length,
+    /// Distance of beveled top edges.
+    top_chamfer: Length,
+}
+
+impl Extrude {
+    /// Return the shape of top or bottom edges.
+    fn edge(fillet: Length, chamfer: Length, context: &mut RenderContext) -> ExtrudeEdge {
+        match (*fillet > 0.0, *chamfer > 0.0) {
+            (true, chamfer) => {
+                if chamfer {
+                    context.warning("Extrude edge has a fillet and a chamfer, using the fillet.");
+                }
+                ExtrudeEdge::Fillet {
+                    radius: *fillet,
+                    segments: context.current_resolution().circular_segments(*fillet),
+                }
+            }
+            (false, true) => ExtrudeEdge::Chamfer(*chamfer),
+            (false, false) => ExtrudeEdge::Sharp,
+        }
+    }
 }
 
 impl Operation for Extrude {
@@ -34,6 +61,8 @@ impl Operation for Extrude {
                 scale_x: self.scale_x,
                 scale_y: self.scale_y,
                 twist: self.twist,
+                bottom: Self::edge(self.bottom_fillet, self.bottom_chamfer, context),
+                top: Self::edge(self.top_fillet, self.top_chamfer, context),
             });
             Ok(WithBounds3D::new(mesh.inner.into(), mesh.bounds))
         })
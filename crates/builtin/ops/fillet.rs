@@ -0,0 +1,38 @@
+// Copyright © 2026 The µcad authors <info@microcad.xyz>
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+//! Builtin fillet operation.
+
+use microcad_builtin_proc_macros::BuiltinOperation2D;
+use microcad_core::{Fillet as _, *};
+use microcad_lang::{builtin::*, render::*};
+
+/// Round the corners of a 2D geometry.
+#[derive(BuiltinOperation2D)]
+pub struct Fillet {
+    /// Radius of the rounded corners.
+    radius: Length,
+    /// Round concave corners.
+    inner: Bool,
+    /// Round convex corners.
+    outer: Bool,
+}
+
+impl Operation for Fillet {
+    fn process_2d(&self, context: &mut RenderContext) -> RenderResult<Geometry2DOutput> {
+        context.update_2d(|context, model| {
+            let model_ = model.borrow();
+            let geometries: Geometries2D = model_.children.render_with_context(context)?;
+            let segments = context.current_resolution().circular_segments(*self.radius);
+
+            Ok(Geometry2D::MultiPolygon(geometries.fillet(
+                *self.radius,
+                Corners {
+                    inner: self.inner,
+                    outer: self.outer,
+                },
+                segments,
+            )))
+        })
+    }
+}
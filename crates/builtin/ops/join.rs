@@ -0,0 +1,79 @@
+// Copyright © 2026 The µcad authors <info@microcad.xyz>
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+//! Builtin join operation.
+
+use cgmath::InnerSpace;
+use geo::orient::{Direction, Orient};
+use microcad_builtin_proc_macros::BuiltinOperation2D;
+use microcad_core::*;
+use microcad_lang::{builtin::*, model::*, render::*};
+
+/// Join the paths of all children end to end into a closed polygon.
+#[derive(BuiltinOperation2D)]
+pub struct Join;
+
+impl Join {
+    /// Return the points of a path geometry.
+    ///
+    /// Polygons are used as paths along their exterior.
+    fn path_points(geometry: &Geometry2D) -> Vec<Vec2> {
+        let points = |line_string: &LineString| -> Vec<Vec2> {
+            line_string.coords().map(|c| Vec2::new(c.x, c.y)).collect()
+        };
+        match geometry {
+            Geometry2D::LineString(line_string) => points(line_string),
+            Geometry2D::MultiLineString(multi_line_string) => {
+                multi_line_string.iter().flat_map(points).collect()
+            }
+            Geometry2D::Polygon(polygon) => points(polygon.exterior()),
+            Geometry2D::MultiPolygon(multi_polygon) => multi_polygon
+                .iter()
+                .flat_map(|polygon| points(polygon.exterior()))
+                .collect(),
+            Geometry2D::Rect(rect) => points(rect.to_polygon().exterior()),
+            Geometry2D::Line(line) => vec![
+                Vec2::new(line.0.x(), line.0.y()),
+                Vec2::new(line.1.x(), line.1.y()),
+            ],
+            Geometry2D::Collection(geometries) => geometries
+                .iter()
+                .flat_map(|g| Self::path_points(g))
+                .collect(),
+        }
+    }
+}
+
+impl Operation for Join {
+    fn process_2d(&self, context: &mut RenderContext) -> RenderResult<Geometry2DOutput> {
+        context.update_2d(|context, model| {
+            let model = model.into_group().unwrap_or(model);
+            let model_ = model.borrow();
+            let paths: Geometries2D = model_.children.render_with_context(context)?;
+
+            // Paths which start where the previous one ends share this point.
+            let mut points: Vec<Vec2> = Vec::new();
+            paths
+                .iter()
+                .flat_map(|path| Self::path_points(path))
+                .for_each(|p| {
+                    if points
+                        .last()
+                        .is_none_or(|last| (p - *last).magnitude2() > Scalar::EPSILON)
+                    {
+                        points.push(p);
+                    }
+                });
+            if points.len() < 3 {
+                context.warning("Joined paths need at least three points.");
+                return Ok(Geometry2D::MultiPolygon(MultiPolygon::empty()));
+            }
+
+            let polygon = geo2d::Polygon::new(
+                LineString::from(points.iter().map(|p| (p.x, p.y)).collect::<Vec<_>>()),
+                vec![],
+            );
+            Ok(Geometry2D::Polygon(polygon.orient(Direction::Default)))
+        })
+    }
+}
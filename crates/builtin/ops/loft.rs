@@ -0,0 +1,96 @@
+// Copyright © 2026 The µcad authors <info@microcad.xyz>
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+//! Builtin loft operation.
+
+use microcad_core::*;
+use microcad_lang::{builtin::*, model::*, render::*, value::Value};
+
+/// Loft between 2D profiles placed at the given heights.
+#[derive(Debug)]
+pub struct Loft {
+    /// Height of each profile.
+    heights: Vec<Scalar>,
+}
+
+impl BuiltinWorkbenchDefinition for Loft {
+    fn id() -> &'static str {
+        "loft"
+    }
+
+    fn help() -> Option<&'static str> {
+        Some(
+            "Loft between 2D profiles.\n\nEach child profile is placed at the height with the same index in `heights`.",
+        )
+    }
+
+    fn kind() -> BuiltinWorkbenchKind {
+        BuiltinWorkbenchKind::Operation
+    }
+
+    fn output_type() -> OutputType {
+        OutputType::Geometry3D
+    }
+
+    fn workpiece_function() -> &'static BuiltinWorkpieceFn {
+        &|args| {
+            let heights = match args.get_value("heights") {
+                Ok(Value::Array(array)) => array
+                    .iter()
+                    .filter_map(|value| Length::try_from(value).ok())
+                    .map(|length| *length)
+                    .collect(),
+                _ => Vec::new(),
+            };
+            Ok(BuiltinWorkpieceOutput::Operation(Box::new(Loft {
+                heights,
+            })))
+        }
+    }
+
+    fn parameters() -> ParameterValueList {
+        use microcad_lang::ty::*;
+
+        [(
+            Identifier::no_ref("heights"),
+            ParameterValue {
+                specified_type: Some(Type::Array(Box::new(Type::Quantity(QuantityType::Length)))),
+                ..Default::default()
+            },
+        )]
+        .into_iter()
+        .collect()
+    }
+}
+
+impl Operation for Loft {
+    fn output_type(&self) -> OutputType {
+        OutputType::Geometry3D
+    }
+
+    fn process_3d(&self, context: &mut RenderContext) -> RenderResult<Geometry3DOutput> {
+        context.update_3d(|context, model| {
+            let model = model.into_group().unwrap_or(model);
+            let model_ = model.borrow();
+            let profiles: Geometries2D = model_.children.render_with_context(context)?;
+
+            if profiles.len() != self.heights.len() {
+                context.warning(format!(
+                    "Loft has {profiles} profiles but {heights} heights, ignoring the surplus.",
+                    profiles = profiles.len(),
+                    heights = self.heights.len()
+                ));
+            }
+
+            let WithBounds3D { inner, bounds } = microcad_core::Loft::with_heights(
+                profiles
+                    .iter()
+                    .zip(&self.heights)
+                    .map(|(profile, height)| (profile.to_multi_polygon(), *height)),
+            )
+            .mesh();
+
+            Ok(WithBounds3D::new(inner.into(), bounds))
+        })
+    }
+}
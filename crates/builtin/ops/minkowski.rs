@@ -0,0 +1,58 @@
+// Copyright © 2026 The µcad authors <info@microcad.xyz>
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+//! Builtin minkowski operation.
+
+use std::rc::Rc;
+
+use microcad_builtin_proc_macros::BuiltinOperation;
+use microcad_core::{traits::Minkowski as _, *};
+use microcad_lang::{builtin::*, render::*};
+
+/// Minkowski sum of the first child with all other children.
+///
+/// In 3D, the sums of the first child with each other child are united.
+#[derive(BuiltinOperation)]
+pub struct Minkowski;
+
+impl Operation for Minkowski {
+    fn process_2d(&self, context: &mut RenderContext) -> RenderResult<Geometry2DOutput> {
+        context.update_2d(|context, model| {
+            let model = model.into_group().unwrap_or(model);
+            let model_ = model.borrow();
+            let geometries: Geometries2D = model_.children.render_with_context(context)?;
+
+            let mut geometries = geometries.iter();
+            let Some(first) = geometries.next() else {
+                return Err(RenderError::NothingToRender);
+            };
+            let other: Geometries2D = geometries.cloned().collect();
+
+            Ok(Geometry2D::MultiPolygon(
+                first
+                    .to_multi_polygon()
+                    .minkowski(&other.to_multi_polygon()),
+            ))
+        })
+    }
+
+    fn process_3d(&self, context: &mut RenderContext) -> RenderResult<Geometry3DOutput> {
+        context.update_3d(|context, model| {
+            let model = model.into_group().unwrap_or(model);
+            let model_ = model.borrow();
+            let geometries: Geometries3D = model_.children.render_with_context(context)?;
+
+            let mut geometries = geometries.iter();
+            let Some(first) = geometries.next() else {
+                return Err(RenderError::NothingToRender);
+            };
+            let first = TriangleMesh::from(first.as_ref());
+
+            let sum = geometries
+                .map(|child| first.minkowski(&TriangleMesh::from(child.as_ref())))
+                .reduce(|a, b| manifold_boolean_op(&a, &b, &BooleanOp::Union))
+                .unwrap_or_else(Manifold::empty);
+            Ok(Geometry3D::Manifold(Rc::new(sum)))
+        })
+    }
+}
@@ -8,16 +8,24 @@ use microcad_lang::builtin::*;
 mod align;
 mod buffer;
 mod center;
+mod chamfer;
 mod distribute_grid;
 mod extrude;
+mod fillet;
 mod hull;
+mod join;
+mod loft;
+mod minkowski;
 mod mirror;
 mod orient;
+mod project;
 mod reflect;
 mod revolve;
 mod rotate;
 mod scale;
+mod section;
 mod spiralize;
+mod sweep;
 mod translate;
 
 /// Creates the builtin `operation` module
@@ -26,20 +34,29 @@ pub fn ops() -> Symbol {
         .builtin::<operation::Union>()
         .builtin::<operation::Subtract>()
         .builtin::<operation::Intersect>()
+        .builtin::<operation::Xor>()
         .builtin::<operation::Multiply>()
         .builtin::<align::Align>()
         .builtin::<buffer::Buffer>()
         .builtin::<center::Center>()
+        .builtin::<chamfer::Chamfer>()
         .builtin::<distribute_grid::DistributeGrid>()
         .builtin::<hull::Hull>()
         .builtin::<extrude::Extrude>()
+        .builtin::<fillet::Fillet>()
+        .builtin::<join::Join>()
+        .builtin::<loft::Loft>()
+        .builtin::<minkowski::Minkowski>()
         .builtin::<mirror::Mirror>()
         .builtin::<orient::Orient>()
+        .builtin::<project::Project>()
         .builtin::<reflect::Reflect>()
         .builtin::<revolve::Revolve>()
         .builtin::<rotate::Rotate>()
         .builtin::<scale::Scale>()
+        .builtin::<section::Section>()
         .builtin::<spiralize::Spiralize>()
+        .builtin::<sweep::Sweep>()
         .builtin::<translate::Translate>()
         .build()
 }
@@ -0,0 +1,37 @@
+// Copyright © 2026 The µcad authors <info@microcad.xyz>
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+//! Builtin project operation.
+
+use microcad_builtin_proc_macros::BuiltinOperation2D;
+use microcad_core::*;
+use microcad_lang::{builtin::*, model::*, render::*};
+
+/// Project the silhouette of a 3D geometry along an axis into a 2D geometry.
+#[derive(BuiltinOperation2D)]
+pub struct Project {
+    /// X component of the projection axis.
+    nx: Scalar,
+    /// Y component of the projection axis.
+    ny: Scalar,
+    /// Z component of the projection axis.
+    nz: Scalar,
+}
+
+impl Operation for Project {
+    fn output_type(&self) -> OutputType {
+        OutputType::Geometry2D
+    }
+
+    fn process_2d(&self, context: &mut RenderContext) -> RenderResult<Geometry2DOutput> {
+        context.update_2d(|context, model| {
+            let model_ = model.borrow();
+            let geometries: Geometries3D = model_.children.render_with_context(context)?;
+
+            Ok(Geometry2D::MultiPolygon(geometries.project(&Plane {
+                p: Vec3::new(0.0, 0.0, 0.0),
+                n: Vec3::new(self.nx, self.ny, self.nz),
+            })))
+        })
+    }
+}
@@ -0,0 +1,43 @@
+// Copyright © 2026 The µcad authors <info@microcad.xyz>
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+//! Builtin section operation.
+
+use microcad_builtin_proc_macros::BuiltinOperation2D;
+use microcad_core::{Section as _, *};
+use microcad_lang::{builtin::*, model::*, render::*};
+
+/// Cut a 3D geometry with a plane into a 2D cross-section.
+#[derive(BuiltinOperation2D)]
+pub struct Section {
+    /// X coordinate of a point on the plane.
+    x: Length,
+    /// Y coordinate of a point on the plane.
+    y: Length,
+    /// Z coordinate of a point on the plane.
+    z: Length,
+    /// X component of the plane normal.
+    nx: Scalar,
+    /// Y component of the plane normal.
+    ny: Scalar,
+    /// Z component of the plane normal.
+    nz: Scalar,
+}
+
+impl Operation for Section {
+    fn output_type(&self) -> OutputType {
+        OutputType::Geometry2D
+    }
+
+    fn process_2d(&self, context: &mut RenderContext) -> RenderResult<Geometry2DOutput> {
+        context.update_2d(|context, model| {
+            let model_ = model.borrow();
+            let geometries: Geometries3D = model_.children.render_with_context(context)?;
+
+            Ok(Geometry2D::MultiPolygon(geometries.section(&Plane {
+                p: Vec3::new(*self.x, *self.y, *self.z),
+                n: Vec3::new(self.nx, self.ny, self.nz),
+            })))
+        })
+    }
+}
@@ -0,0 +1,200 @@
+// Copyright © 2026 The µcad authors <info@microcad.xyz>
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+//! Builtin sweep operation.
+
+use microcad_core::*;
+use microcad_lang::{
+    builtin::*,
+    eval::{EvalError, EvalResult},
+    model::*,
+    render::*,
+    value::{Array, Tuple, Value},
+};
+
+/// Sweep a 2D profile along a path.
+#[derive(Debug)]
+pub struct Sweep {
+    /// Points of a 3D path. If empty, the path is given by the second child.
+    points: Vec<Vec3>,
+    /// Connect the end of the path with its start.
+    closed: bool,
+    /// Rotation of the profile along the path.
+    twist: Angle,
+}
+
+impl Sweep {
+    /// Type of a single path point: `(x: Length, y: Length, z: Length)`.
+    fn point_type() -> microcad_lang::ty::Type {
+        use microcad_lang::ty::*;
+
+        Type::Tuple(Box::new(
+            [
+                ("x", Type::length()),
+                ("y", Type::length()),
+                ("z", Type::length()),
+            ]
+            .into_iter()
+            .collect(),
+        ))
+    }
+
+    /// Return the points of a 2D path geometry in the XY plane.
+    ///
+    /// Polygons are used as open path along their exterior.
+    fn path_points(geometry: &Geometry2D) -> Vec<Vec3> {
+        let points = |line_string: &LineString| -> Vec<Vec3> {
+            line_string
+                .coords()
+                .map(|c| Vec3::new(c.x, c.y, 0.0))
+                .collect()
+        };
+        let mut path = match geometry {
+            Geometry2D::LineString(line_string) => points(line_string),
+            Geometry2D::MultiLineString(multi_line_string) => multi_line_string
+                .iter()
+                .next()
+                .map(points)
+                .unwrap_or_default(),
+            Geometry2D::Polygon(polygon) => points(polygon.exterior()),
+            Geometry2D::MultiPolygon(multi_polygon) => multi_polygon
+                .iter()
+                .next()
+                .map(|polygon| points(polygon.exterior()))
+                .unwrap_or_default(),
+            Geometry2D::Rect(rect) => points(rect.to_polygon().exterior()),
+            Geometry2D::Line(line) => vec![
+                Vec3::new(line.0.x(), line.0.y(), 0.0),
+                Vec3::new(line.1.x(), line.1.y(), 0.0),
+            ],
+            Geometry2D::Collection(geometries) => geometries
+                .iter()
+                .map(|geometry| Self::path_points(geometry))
+                .find(|path| !path.is_empty())
+                .unwrap_or_default(),
+        };
+
+        // Polygon rings end with their start point.
+        if matches!(
+            geometry,
+            Geometry2D::Polygon(_) | Geometry2D::MultiPolygon(_) | Geometry2D::Rect(_)
+        ) {
+            path.pop();
+        }
+        path
+    }
+}
+
+impl BuiltinWorkbenchDefinition for Sweep {
+    fn id() -> &'static str {
+        "sweep"
+    }
+
+    fn help() -> Option<&'static str> {
+        Some(
+            "Sweep a 2D profile along a path.\n\nThe first child is the profile. The path is either given by `points` or by a second child in the XY plane.",
+        )
+    }
+
+    fn kind() -> BuiltinWorkbenchKind {
+        BuiltinWorkbenchKind::Operation
+    }
+
+    fn output_type() -> OutputType {
+        OutputType::Geometry3D
+    }
+
+    fn workpiece_function() -> &'static BuiltinWorkpieceFn {
+        &|args| {
+            let coordinate = |value: Option<&Value>| match value {
+                Some(Value::Quantity(quantity)) => quantity.value,
+                _ => 0.0,
+            };
+            let points = match args.get_value("points") {
+                Ok(Value::Array(array)) => array
+                    .iter()
+                    .filter_map(|value| match value {
+                        Value::Tuple(tuple) => Some(Vec3::new(
+                            coordinate(tuple.get_value("x").ok()),
+                            coordinate(tuple.get_value("y").ok()),
+                            coordinate(tuple.get_value("z").ok()),
+                        )),
+                        _ => None,
+                    })
+                    .collect(),
+                _ => Vec::new(),
+            };
+
+            Ok(BuiltinWorkpieceOutput::Operation(Box::new(Sweep {
+                points,
+                closed: args.get("closed"),
+                twist: args.get("twist"),
+            })))
+        }
+    }
+
+    fn validate(args: &Tuple) -> EvalResult<()> {
+        match args.get_value("points") {
+            Ok(Value::Array(array)) if array.len() == 1 => Err(EvalError::BuiltinError(
+                "Sweep path needs at least two points".into(),
+            )),
+            _ => Ok(()),
+        }
+    }
+
+    fn parameters() -> ParameterValueList {
+        [
+            (
+                Identifier::no_ref("points"),
+                ParameterValue {
+                    specified_type: Some(microcad_lang::ty::Type::Array(Box::new(
+                        Self::point_type(),
+                    ))),
+                    default_value: Some(Value::Array(Array::new(Self::point_type()))),
+                    ..Default::default()
+                },
+            ),
+            parameter!(closed: Bool),
+            parameter!(twist: Angle),
+        ]
+        .into_iter()
+        .collect()
+    }
+}
+
+impl Operation for Sweep {
+    fn output_type(&self) -> OutputType {
+        OutputType::Geometry3D
+    }
+
+    fn process_3d(&self, context: &mut RenderContext) -> RenderResult<Geometry3DOutput> {
+        context.update_3d(|context, model| {
+            let model = model.into_group().unwrap_or(model);
+            let model_ = model.borrow();
+            let children: Geometries2D = model_.children.render_with_context(context)?;
+            let mut children = children.iter();
+
+            let Some(profile) = children.next() else {
+                return Err(RenderError::NothingToRender);
+            };
+            let path = match self.points.is_empty() {
+                true => children
+                    .next()
+                    .map(|path| Self::path_points(path))
+                    .unwrap_or_default(),
+                false => self.points.clone(),
+            };
+            if path.len() < 2 {
+                context.warning("Sweep path needs at least two points.");
+            }
+
+            use microcad_core::Extrude;
+            let WithBounds3D { inner, bounds } =
+                profile
+                    .to_multi_polygon()
+                    .sweep(&path, self.closed, self.twist);
+
+            Ok(WithBounds3D::new(inner.into(), bounds))
+        })
+    }
+}
@@ -10,8 +10,91 @@ use microcad_builtin_proc_macros::builtin_mod;
 #[allow(clippy::module_inception)]
 pub mod string {
     use microcad_builtin_proc_macros::builtin_fn;
-    use microcad_lang::{parameter, value::Value};
-    use microcad_lang_base::PushDiag;
+    use microcad_lang::{
+        eval::EvalError,
+        lower::ir,
+        parameter,
+        ty::Type,
+        value::{Array, Value},
+    };
+    use microcad_lang_base::{Identifier, PushDiag};
+
+    /// Convert a list of strings into an array of strings.
+    fn string_array(items: impl IntoIterator<Item = String>) -> Value {
+        Array::from_values(items.into_iter().map(Value::String).collect(), Type::String).into()
+    }
+
+    /// Write a placeholder value, using `precision` decimal places for numbers.
+    fn format_value(value: &Value, precision: Option<usize>) -> String {
+        match (value, precision) {
+            (Value::Quantity(q), Some(p)) => {
+                format!("{:.p$}{}", q.unit.denormalize(q.value), q.unit)
+            }
+            (Value::Integer(i), Some(p)) => format!("{:.p$}", *i as f64),
+            (value, _) => value.to_string(),
+        }
+    }
+
+    /// Replace the placeholders in `fmt` with `values`.
+    ///
+    /// Placeholders are `{}` (next value), `{0}` (value by index) or `{name}` (value of a tuple).
+    fn format_str(fmt: &str, values: &Value) -> Result<String, String> {
+        let mut result = String::new();
+        let mut next = 0;
+        let mut chars = fmt.chars().peekable();
+        while let Some(c) = chars.next() {
+            match c {
+                '{' if chars.peek() == Some(&'{') => {
+                    chars.next();
+                    result.push('{');
+                }
+                '}' if chars.peek() == Some(&'}') => {
+                    chars.next();
+                    result.push('}');
+                }
+                '}' => return Err("Unmatched `}` in format string.".into()),
+                '{' => {
+                    let placeholder: String = chars.by_ref().take_while(|c| *c != '}').collect();
+                    let (key, spec) = placeholder
+                        .split_once(':')
+                        .unwrap_or((placeholder.as_str(), ""));
+                    let precision = match spec {
+                        "" => None,
+                        spec => Some(
+                            spec.strip_prefix('.')
+                                .and_then(|p| p.parse::<usize>().ok())
+                                .ok_or(format!("Invalid format `{spec}`, expected e.g. `.2`."))?,
+                        ),
+                    };
+
+                    let value = if key.is_empty() || key.chars().all(|c| c.is_ascii_digit()) {
+                        let index = match key.parse::<usize>() {
+                            Ok(index) => index,
+                            Err(_) => {
+                                next += 1;
+                                next - 1
+                            }
+                        };
+                        match values {
+                            Value::Array(array) => array.iter().nth(index).cloned(),
+                            Value::Tuple(_) => None,
+                            value => (index == 0).then(|| value.clone()),
+                        }
+                        .ok_or(format!("No value for placeholder {index}."))?
+                    } else {
+                        match values {
+                            Value::Tuple(tuple) => tuple.by_id(&Identifier::no_ref(key)).cloned(),
+                            _ => None,
+                        }
+                        .ok_or(format!("No value for placeholder `{key}`."))?
+                    };
+                    result.push_str(&format_value(&value, precision));
+                }
+                c => result.push(c),
+            }
+        }
+        Ok(result)
+    }
 
     /// Return the length a string.
     #[builtin_fn(s: String)]
@@ -52,4 +135,307 @@ pub mod string {
             })
         }
     }
+
+    /// Split a string into an array of strings at each occurrence of `sep`.
+    #[builtin_fn(s: String, sep: String)]
+    pub fn split() -> Symbol {
+        |_params, args, ctx| {
+            Ok(
+                match (
+                    args.get_by_id_or_index("s", 0),
+                    args.get_by_id_or_index("sep", 1),
+                ) {
+                    (Value::String(_), Value::String(sep)) if sep.is_empty() => {
+                        ctx.error(
+                            args,
+                            EvalError::BuiltinError("Separator must not be empty.".into()),
+                        )?;
+                        Value::None
+                    }
+                    (Value::String(s), Value::String(sep)) => {
+                        string_array(s.split(sep.as_str()).map(str::to_string))
+                    }
+                    _ => {
+                        ctx.error(
+                            args,
+                            EvalError::BuiltinError("Expected a string and a separator.".into()),
+                        )?;
+                        Value::None
+                    }
+                },
+            )
+        }
+    }
+
+    /// Join an array of strings into a single string, putting `sep` between the elements.
+    #[builtin_fn(x, sep: String)]
+    pub fn join() -> Symbol {
+        |_params, args, ctx| {
+            Ok(
+                match (
+                    args.get_by_id_or_index("x", 0),
+                    args.get_by_id_or_index("sep", 1),
+                ) {
+                    (Value::Array(a), Value::String(sep)) => {
+                        match a
+                            .iter()
+                            .map(|value| match value {
+                                Value::String(s) => Some(s.as_str()),
+                                _ => None,
+                            })
+                            .collect::<Option<Vec<_>>>()
+                        {
+                            Some(items) => Value::String(items.join(&sep)),
+                            None => {
+                                ctx.error(
+                                    args,
+                                    EvalError::BuiltinError(
+                                        "Array must contain strings only.".into(),
+                                    ),
+                                )?;
+                                Value::None
+                            }
+                        }
+                    }
+                    _ => {
+                        ctx.error(
+                            args,
+                            EvalError::BuiltinError(
+                                "Expected an array of strings and a separator.".into(),
+                            ),
+                        )?;
+                        Value::None
+                    }
+                },
+            )
+        }
+    }
+
+    /// Replace all occurrences of `from` in a string with `to`.
+    #[builtin_fn(s: String, from: String, to: String)]
+    pub fn replace() -> Symbol {
+        |_params, args, ctx| {
+            Ok(
+                match (
+                    args.get_by_id_or_index("s", 0),
+                    args.get_by_id_or_index("from", 1),
+                    args.get_by_id_or_index("to", 2),
+                ) {
+                    (Value::String(s), Value::String(from), Value::String(to))
+                        if !from.is_empty() =>
+                    {
+                        Value::String(s.replace(&from, &to))
+                    }
+                    _ => {
+                        ctx.error(
+                            args,
+                            EvalError::BuiltinError(
+                                "Expected a string, a non-empty pattern and a replacement.".into(),
+                            ),
+                        )?;
+                        Value::None
+                    }
+                },
+            )
+        }
+    }
+
+    /// Remove leading and trailing whitespace from a string.
+    #[builtin_fn(s: String)]
+    pub fn trim() -> Symbol {
+        |_params, args, ctx| {
+            let (_, arg) = args.get_single()?;
+            Ok(match &arg.value {
+                Value::String(s) => Value::String(s.trim().to_string()),
+                _ => {
+                    ctx.error(
+                        arg,
+                        EvalError::BuiltinError("Value is not a string.".into()),
+                    )?;
+                    Value::None
+                }
+            })
+        }
+    }
+
+    /// Return `true` if a string starts with `prefix`.
+    #[builtin_fn(s: String, prefix: String)]
+    pub fn starts_with() -> Symbol {
+        |_params, args, ctx| {
+            Ok(
+                match (
+                    args.get_by_id_or_index("s", 0),
+                    args.get_by_id_or_index("prefix", 1),
+                ) {
+                    (Value::String(s), Value::String(prefix)) => {
+                        Value::Bool(s.starts_with(&prefix))
+                    }
+                    _ => {
+                        ctx.error(
+                            args,
+                            EvalError::BuiltinError("Expected a string and a prefix.".into()),
+                        )?;
+                        Value::None
+                    }
+                },
+            )
+        }
+    }
+
+    /// Return `true` if a string ends with `suffix`.
+    #[builtin_fn(s: String, suffix: String)]
+    pub fn ends_with() -> Symbol {
+        |_params, args, ctx| {
+            Ok(
+                match (
+                    args.get_by_id_or_index("s", 0),
+                    args.get_by_id_or_index("suffix", 1),
+                ) {
+                    (Value::String(s), Value::String(suffix)) => Value::Bool(s.ends_with(&suffix)),
+                    _ => {
+                        ctx.error(
+                            args,
+                            EvalError::BuiltinError("Expected a string and a suffix.".into()),
+                        )?;
+                        Value::None
+                    }
+                },
+            )
+        }
+    }
+
+    /// Return the characters of a string from index `start` up to (excluding) index `end`.
+    ///
+    /// If `end` is omitted, the rest of the string is returned.
+    #[builtin_fn(s: String, start: Integer, end: Integer)]
+    pub fn substr() -> Symbol {
+        |_params, args, ctx| {
+            Ok(
+                match (
+                    args.get_by_id_or_index("s", 0),
+                    args.get_by_id_or_index("start", 1),
+                    args.get_by_id_or_index("end", 2),
+                ) {
+                    (Value::String(s), Value::Integer(start), end) => {
+                        let start = start.max(0) as usize;
+                        let end = match end {
+                            Value::Integer(end) => end.max(0) as usize,
+                            _ => usize::MAX,
+                        };
+                        Value::String(
+                            s.chars()
+                                .skip(start)
+                                .take(end.saturating_sub(start))
+                                .collect(),
+                        )
+                    }
+                    _ => {
+                        ctx.error(
+                            args,
+                            EvalError::BuiltinError("Expected a string and a start index.".into()),
+                        )?;
+                        Value::None
+                    }
+                },
+            )
+        }
+    }
+
+    /// Convert all characters of a string to upper case.
+    #[builtin_fn(s: String)]
+    pub fn to_upper() -> Symbol {
+        |_params, args, ctx| {
+            let (_, arg) = args.get_single()?;
+            Ok(match &arg.value {
+                Value::String(s) => Value::String(s.to_uppercase()),
+                _ => {
+                    ctx.error(
+                        arg,
+                        EvalError::BuiltinError("Value is not a string.".into()),
+                    )?;
+                    Value::None
+                }
+            })
+        }
+    }
+
+    /// Convert all characters of a string to lower case.
+    #[builtin_fn(s: String)]
+    pub fn to_lower() -> Symbol {
+        |_params, args, ctx| {
+            let (_, arg) = args.get_single()?;
+            Ok(match &arg.value {
+                Value::String(s) => Value::String(s.to_lowercase()),
+                _ => {
+                    ctx.error(
+                        arg,
+                        EvalError::BuiltinError("Value is not a string.".into()),
+                    )?;
+                    Value::None
+                }
+            })
+        }
+    }
+
+    /// Format a string by replacing placeholders with values.
+    ///
+    /// `{}` is replaced by the next value and `{1}` by the value with index 1 of an array.
+    /// `{name}` is replaced by the value `name` of a tuple.
+    /// Numbers can be written with a number of decimal places, e.g. `{:.2}` or `{width:.1}`.
+    /// `{{` and `}}` are written as `{` and `}`.
+    #[builtin_fn(fmt: String, values)]
+    pub fn format() -> Symbol {
+        |_params, args, ctx| {
+            Ok(
+                match (
+                    args.get_by_id_or_index("fmt", 0),
+                    args.get_by_id_or_index("values", 1),
+                ) {
+                    (Value::String(fmt), values) => match format_str(&fmt, &values) {
+                        Ok(s) => Value::String(s),
+                        Err(err) => {
+                            ctx.error(args, EvalError::BuiltinError(err))?;
+                            Value::None
+                        }
+                    },
+                    _ => {
+                        ctx.error(
+                            args,
+                            EvalError::BuiltinError("Expected a format string and values.".into()),
+                        )?;
+                        Value::None
+                    }
+                },
+            )
+        }
+    }
+
+    /// Parse a string containing a µcad literal into a value.
+    ///
+    /// Numbers may have units, e.g. `"12.5mm"` results in a length and `"45°"` in an angle.
+    #[builtin_fn(s: String)]
+    pub fn parse() -> Symbol {
+        |_params, args, ctx| {
+            let (_, arg) = args.get_single()?;
+            Ok(match &arg.value {
+                Value::String(s) => match s.trim().parse::<ir::Literal>() {
+                    Ok(literal) => literal.value().clone(),
+                    Err(_) => {
+                        ctx.error(
+                            arg,
+                            EvalError::BuiltinError(format!("Cannot parse value from \"{s}\".")),
+                        )?;
+                        Value::None
+                    }
+                },
+                _ => {
+                    ctx.error(
+                        arg,
+                        EvalError::BuiltinError("Value is not a string.".into()),
+                    )?;
+                    Value::None
+                }
+            })
+        }
+    }
 }
@@ -6,6 +6,7 @@
 use std::str::FromStr;
 
 use clap::Parser;
+use miette::IntoDiagnostic;
 
 use crate::commands::*;
 use microcad_driver::prelude as mu;
@@ -22,6 +23,10 @@ pub struct Cli {
     #[arg(short = 'C', long = "config")]
     config_path: Option<std::path::PathBuf>,
 
+    /// Load a plugin library which provides modules for `extern mod`.
+    #[arg(long = "plugin", value_name = "LIBRARY")]
+    plugin_paths: Vec<std::path::PathBuf>,
+
     /// Verbosity level (use -v, -vv, or -vvv)
     #[arg(short, action = clap::ArgAction::Count)]
     pub(crate) verbose: u8,
@@ -33,6 +38,10 @@ pub struct Cli {
     /// The loaded or default CLI config.
     #[clap(skip)]
     pub config: std::rc::Rc<mu::DriverConfig>,
+
+    /// Plugins loaded from `plugin_paths`.
+    #[clap(skip)]
+    plugins: mu::PluginRegistry,
 }
 
 impl Cli {
@@ -43,9 +52,27 @@ impl Cli {
         #[cfg(not(debug_assertions))]
         mu::install_std()?;
 
-        if let Some(config_path) = &cli.config_path {
-            cli.config = std::rc::Rc::new(mu::DriverConfig::load(config_path)?);
+        let mut config = match &cli.config_path {
+            Some(config_path) => mu::DriverConfig::load(config_path)?,
+            None => mu::DriverConfig::default(),
+        };
+
+        // Apply the project manifest found next to the input or in its parent directories.
+        let project_dir = match cli.command.input() {
+            Some(input) => std::path::absolute(input).into_diagnostic()?,
+            None => std::env::current_dir().into_diagnostic()?,
+        };
+        if let Some(project_path) = mu::Project::find(project_dir) {
+            mu::Project::load(project_path)?.apply(&mut config)?;
         }
+
+        cli.config = std::rc::Rc::new(config);
+        cli.plugins = cli
+            .plugin_paths
+            .iter()
+            .try_fold(mu::PluginRegistry::default(), |plugins, path| {
+                mu::load_plugin(path).map(|plugin| plugins.insert(plugin))
+            })?;
         Ok(cli)
     }
 
@@ -55,13 +82,20 @@ impl Cli {
             resolve: mu::ResolveParameters {
                 search_paths: self.config.search_paths.clone(),
                 no_builtin: false,
+                plugins: self.plugins.clone(),
+                source_cache: None,
             },
+            eval: Default::default(),
         }
     }
 
-    /// Retrieve render parameters from resolution string.
-    pub fn render_params(&self, resolution: &str) -> mu::Result<mu::RenderParameters> {
-        Ok(mu::RenderParameters::from_str(resolution)?.with_empty_cache())
+    /// Retrieve render parameters from resolution string or from the export config.
+    pub fn render_params(&self, resolution: Option<&str>) -> mu::Result<mu::RenderParameters> {
+        Ok(match resolution {
+            Some(resolution) => mu::RenderParameters::from_str(resolution)?,
+            None => mu::RenderParameters::from(self.config.export.render_resolution()),
+        }
+        .with_empty_cache())
     }
 
     /// Print diagnostics with colors and unicode.
@@ -101,6 +135,12 @@ impl Cli {
             Commands::Doc(doc) => {
                 doc.run(self)?;
             }
+            Commands::Test(test) => {
+                test.run(self)?;
+            }
+            Commands::Info(info) => {
+                info.run(self)?;
+            }
         }
 
         if self.time {
@@ -7,7 +7,23 @@ use crate::{Cli, commands::RunCommand};
 #[derive(clap::Parser)]
 pub struct Check {
     /// Input µcad file.
-    input: String,
+    pub input: String,
+
+    /// Render the file and check the geometry for printability issues.
+    #[arg(long)]
+    geometry: bool,
+
+    /// The resolution to render the models (default: resolution of the export config).
+    #[arg(short, long, requires = "geometry")]
+    resolution: Option<String>,
+
+    /// Report walls thinner than this thickness in mm.
+    #[arg(long, requires = "geometry")]
+    min_thickness: Option<f64>,
+
+    /// Report holes with a diameter smaller than this in mm.
+    #[arg(long, requires = "geometry")]
+    min_hole: Option<f64>,
 }
 
 impl RunCommand<()> for Check {
@@ -17,6 +33,33 @@ impl RunCommand<()> for Check {
 
         let mut document = mu::Document::open(&self.input)?;
 
+        if self.geometry {
+            let params = mu::ValidateParameters {
+                compile: cli.compile_parameters(),
+                render: cli.render_params(self.resolution.as_deref())?,
+                validation: mu::ValidationParameters {
+                    min_thickness: self.min_thickness,
+                    min_hole: self.min_hole,
+                },
+            };
+
+            return match document.validate(params) {
+                Ok(0) => {
+                    eprintln!("✅ Geometry is valid: {}", self.input);
+                    Ok(())
+                }
+                Ok(count) => {
+                    cli.print_diagnostics(&document);
+                    Err(miette::miette!("Geometry has {count} issue(s)"))
+                }
+                Err(err) => {
+                    eprintln!("⚠️ File has issues:\n{err}");
+                    cli.print_diagnostics(&document);
+                    Err(err)
+                }
+            };
+        }
+
         match document.compile(cli.compile_parameters()) {
             Ok(_) => {
                 eprintln!("✅ File is valid: {}", self.input);
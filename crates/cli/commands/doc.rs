@@ -11,7 +11,7 @@ pub struct Doc {
     /// Input file or library name.
     ///
     /// Build documentation for an external library (only `__builtin` and `std` are possible).
-    input: String,
+    pub input: String,
 
     /// Generator (md (default), mdbook).
     #[arg(short = 'g', long = "generator")]
@@ -41,6 +41,7 @@ impl RunCommand<()> for Doc {
                 true => mu::ResolveParameters {
                     search_paths: vec![],
                     no_builtin: true,
+                    ..Default::default()
                 },
                 false => mu::ResolveParameters::default(),
             },
@@ -3,45 +3,110 @@
 
 //! µcad CLI export command
 
-use crate::{Cli, commands::RunCommand};
+use crate::{Cli, commands::RunCommand, progress::ProgressBar};
+use microcad_driver::prelude as mu;
 
 /// Parse and evaluate and export a µcad file.
 #[derive(clap::Parser)]
 pub struct Export {
     pub input: String,
 
-    /// Output file (e.g. an SVG or STL).
+    /// Output file (e.g. an SVG or STL) or output directory for Markdown files.
     pub output: Option<std::path::PathBuf>,
 
     /// The resolution of this export.
     ///
-    /// The resolution can changed relatively `200%` or to an absolute value `0.05mm`.
-    #[arg(short, long, default_value = "0.1mm")]
-    pub resolution: String,
+    /// The resolution can changed relatively `200%` or to an absolute value `0.05mm`
+    /// (default: resolution of the export config).
+    #[arg(short, long)]
+    pub resolution: Option<String>,
 
     /// List all export target files.
     #[arg(short, long)]
     pub dry_run: bool,
+
+    /// Override a constant or the default argument of a workbench parameter.
+    ///
+    /// E.g. `-D SIZE=20mm` or `-D Bracket::width=4mm`.
+    #[arg(short = 'D', value_name = "NAME=VALUE")]
+    pub define: Vec<String>,
+
+    /// Export every parameter set of a TOML file to its own output file.
+    #[arg(long, value_name = "FILE", conflicts_with = "output")]
+    pub variants: Option<std::path::PathBuf>,
 }
 
 impl RunCommand for Export {
     fn run(&self, cli: &Cli) -> miette::Result<()> {
-        use microcad_driver::prelude as mu;
+        let defines = self
+            .define
+            .iter()
+            .map(|define| mu::override_from_str(define))
+            .collect::<miette::Result<Vec<_>>>()?;
+        let render_parameters = cli.render_params(self.resolution.as_deref())?;
+
+        match &self.variants {
+            Some(variants) => {
+                // All variants share the render cache of `render_parameters`.
+                for variant in mu::Variants::load(variants)?.iter() {
+                    eprintln!("Variant `{}`:", variant.name);
+                    self.export(
+                        cli,
+                        defines.iter().chain(&variant.overrides).cloned().collect(),
+                        render_parameters.clone(),
+                        Some(variant.output.clone()),
+                    )?;
+                }
+                Ok(())
+            }
+            None => self.export(
+                cli,
+                defines.into_iter().collect(),
+                render_parameters,
+                self.output.clone(),
+            ),
+        }
+    }
+}
+
+impl Export {
+    /// Compile, render and export the input with the given overrides.
+    fn export(
+        &self,
+        cli: &Cli,
+        overrides: mu::Overrides,
+        render_parameters: mu::RenderParameters,
+        output_path: Option<std::path::PathBuf>,
+    ) -> miette::Result<()> {
         use mu::traits::*;
 
         let mut document = mu::Document::open(&self.input)?;
 
+        let mut compile_parameters = cli.compile_parameters();
+        compile_parameters.eval.overrides = overrides;
+
         let params = mu::ExportParameters {
             input_path: std::path::PathBuf::from(&self.input),
-            output_path: self.output.clone(),
+            output_path,
             config: cli.config.export.clone(),
         };
 
-        match document
-            .compile(cli.compile_parameters())
-            .and(document.render(cli.render_params(&self.resolution)?))
-            .and(document.get_export_targets(params))
-        {
+        let (progress_bar, render_parameters) = match ProgressBar::start() {
+            Some((progress_bar, progress_tx)) => (
+                Some(progress_bar),
+                render_parameters.with_progress(progress_tx),
+            ),
+            None => (None, render_parameters),
+        };
+
+        let result = document
+            .compile(compile_parameters)
+            .and(document.render(render_parameters));
+        if let Some(progress_bar) = progress_bar {
+            progress_bar.finish();
+        }
+
+        match result.and(document.get_export_targets(params)) {
             Ok(targets) => {
                 if self.dry_run {
                     eprintln!("{targets}");
@@ -0,0 +1,53 @@
+// Copyright © 2026 The µcad authors <info@microcad.xyz>
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+//! µcad CLI info command
+
+use miette::IntoDiagnostic;
+
+use crate::{Cli, commands::RunCommand};
+use microcad_driver::prelude as mu;
+
+/// Print model tree, bounds, area or volume and statistics of the exported models.
+#[derive(clap::Parser)]
+pub struct Info {
+    /// Input µcad file.
+    pub input: String,
+
+    /// The resolution to render the models (default: resolution of the export config).
+    #[arg(short, long)]
+    resolution: Option<String>,
+
+    /// Print the report as JSON.
+    #[arg(long)]
+    json: bool,
+}
+
+impl RunCommand<()> for Info {
+    fn run(&self, cli: &Cli) -> miette::Result<()> {
+        use mu::traits::Info as _;
+
+        let mut document = mu::Document::open(&self.input)?;
+        let params = mu::InfoParameters {
+            compile: cli.compile_parameters(),
+            render: cli.render_params(self.resolution.as_deref())?,
+            export: mu::ExportParameters {
+                input_path: std::path::PathBuf::from(&self.input),
+                output_path: None,
+                config: cli.config.export.clone(),
+            },
+        };
+
+        match document.info(params) {
+            Ok(info) => match self.json {
+                true => println!("{}", serde_json::to_string_pretty(&info).into_diagnostic()?),
+                false => print!("{info}"),
+            },
+            Err(err) => {
+                cli.print_diagnostics(&document);
+                return Err(err);
+            }
+        }
+        Ok(())
+    }
+}
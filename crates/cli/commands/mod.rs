@@ -9,6 +9,8 @@ mod create;
 mod doc;
 mod export;
 mod format;
+mod info;
+mod test;
 mod watch;
 
 use clap::Subcommand;
@@ -18,6 +20,8 @@ pub use create::Create;
 pub use doc::Doc;
 pub use export::Export;
 pub use format::Format;
+pub use info::Info;
+pub use test::Test;
 pub use watch::Watch;
 
 use crate::commands::completions::Completions;
@@ -43,10 +47,34 @@ pub enum Commands {
     /// Generate Markdown docs.
     Doc(Doc),
 
+    /// Run tests in µcad files and markdown code blocks.
+    Test(Test),
+
+    /// Print model tree and statistics of a µcad file.
+    Info(Info),
+
     /// Print shell completions
     Completions(Completions),
 }
 
+impl Commands {
+    /// Return the input file or directory of the command, if it has one.
+    pub fn input(&self) -> Option<&std::path::Path> {
+        use std::path::Path;
+
+        match self {
+            Commands::Check(check) => Some(Path::new(&check.input)),
+            Commands::Export(export) => Some(Path::new(&export.input)),
+            Commands::Watch(watch) => Some(Path::new(&watch.input)),
+            Commands::Format(format) => format.input.as_deref().map(Path::new),
+            Commands::Doc(doc) => Some(Path::new(&doc.input)),
+            Commands::Test(test) => test.inputs.first().map(|input| input.as_path()),
+            Commands::Info(info) => Some(Path::new(&info.input)),
+            Commands::Create(_) | Commands::Completions(_) => None,
+        }
+    }
+}
+
 /// Run this command for a CLI.
 pub trait RunCommand<T = ()> {
     fn run(&self, cli: &crate::cli::Cli) -> miette::Result<T>;
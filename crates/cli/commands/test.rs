@@ -0,0 +1,177 @@
+// Copyright © 2026 The µcad authors <info@microcad.xyz>
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+//! µcad CLI test command
+
+use std::path::{Path, PathBuf};
+
+use miette::IntoDiagnostic;
+
+use crate::{Cli, commands::RunCommand};
+use microcad_driver::prelude as mu;
+
+/// Report format of test results.
+#[derive(clap::ValueEnum, Clone, Copy, Default)]
+pub enum TestReportFormat {
+    /// Test Anything Protocol.
+    #[default]
+    Tap,
+    /// JUnit XML.
+    Junit,
+}
+
+/// Run `#[test]` workbenches and functions in µcad files and named code blocks in markdown files.
+#[derive(clap::Parser)]
+pub struct Test {
+    /// Files or directories to search for tests (default: current directory).
+    pub inputs: Vec<PathBuf>,
+
+    /// Report format.
+    #[arg(short = 'f', long, value_enum, default_value_t)]
+    format: TestReportFormat,
+
+    /// Write the report into a file instead of stdout.
+    #[arg(short = 'o', long)]
+    output: Option<PathBuf>,
+
+    /// Compare the rendered models of tests against reference geometries.
+    ///
+    /// 2D references are stored as WKT and 3D references as STL.
+    #[arg(long)]
+    golden: bool,
+
+    /// Write the rendered models as new references (implies `--golden`).
+    #[arg(long)]
+    bless: bool,
+
+    /// Directory of the references (default: `golden` next to each tested file).
+    #[arg(long)]
+    golden_dir: Option<PathBuf>,
+
+    /// The resolution to render models for comparison (default: resolution of the export config).
+    #[arg(short, long)]
+    resolution: Option<String>,
+
+    /// Maximum area (mm²) or volume (mm³) of the symmetric difference to the reference.
+    #[arg(long, default_value_t = 0.01)]
+    difference_tolerance: f64,
+
+    /// Maximum Hausdorff distance (mm) to the reference.
+    #[arg(long, default_value_t = 0.01)]
+    distance_tolerance: f64,
+}
+
+impl Test {
+    /// Find all µcad and markdown files within the inputs.
+    fn files(&self) -> miette::Result<Vec<PathBuf>> {
+        let mut files = Vec::new();
+        match self.inputs.is_empty() {
+            true => Self::find_files(Path::new("."), &mut files)?,
+            false => self
+                .inputs
+                .iter()
+                .try_for_each(|input| match input.is_dir() {
+                    true => Self::find_files(input, &mut files),
+                    false => {
+                        files.push(input.clone());
+                        Ok(())
+                    }
+                })?,
+        }
+        Ok(files)
+    }
+
+    /// Recursively find test files in a directory but skip hidden directories and `target`.
+    fn find_files(dir: &Path, files: &mut Vec<PathBuf>) -> miette::Result<()> {
+        let mut entries = std::fs::read_dir(dir)
+            .into_diagnostic()?
+            .map(|entry| entry.map(|entry| entry.path()))
+            .collect::<Result<Vec<_>, _>>()
+            .into_diagnostic()?;
+        entries.sort();
+
+        entries.into_iter().try_for_each(|path| {
+            let name = path
+                .file_name()
+                .map(|name| name.to_string_lossy().to_string())
+                .unwrap_or_default();
+            if path.is_dir() {
+                if !name.starts_with('.') && name != "target" {
+                    Self::find_files(&path, files)?;
+                }
+            } else if let Some(ext) = path.extension().and_then(|ext| ext.to_str())
+                && (ext == "md" || mu::base::MICROCAD_EXTENSIONS.contains(&ext))
+            {
+                files.push(path);
+            }
+            Ok(())
+        })
+    }
+
+    /// Generate test parameters.
+    fn test_parameters(&self, cli: &Cli) -> miette::Result<mu::TestParameters> {
+        Ok(mu::TestParameters {
+            compile: cli.compile_parameters(),
+            golden: match self.golden || self.bless {
+                true => Some(mu::GoldenParameters {
+                    dir: self.golden_dir.clone(),
+                    bless: self.bless,
+                    resolution: cli.render_params(self.resolution.as_deref())?.resolution,
+                    difference_tolerance: self.difference_tolerance,
+                    distance_tolerance: self.distance_tolerance,
+                }),
+                false => None,
+            },
+        })
+    }
+
+    /// Run the tests of a single file.
+    fn test_file(path: &Path, params: &mu::TestParameters) -> mu::TestResults {
+        use mu::traits::Test as _;
+
+        match mu::Document::from_file(path).and_then(|mut document| document.test(params.clone())) {
+            Ok(results) => results,
+            Err(err) => {
+                let mut results = mu::TestResults::default();
+                results.push(mu::TestResult {
+                    name: path.display().to_string(),
+                    outcome: mu::TestOutcome::Failed,
+                    message: err.to_string(),
+                    duration: Default::default(),
+                });
+                results
+            }
+        }
+    }
+}
+
+impl RunCommand<()> for Test {
+    fn run(&self, cli: &Cli) -> miette::Result<()> {
+        let params = self.test_parameters(cli)?;
+        let mut results = mu::TestResults::default();
+        self.files()?
+            .iter()
+            .for_each(|path| results.extend(Self::test_file(path, &params)));
+
+        let mut report = String::new();
+        match self.format {
+            TestReportFormat::Tap => results.write_tap(&mut report),
+            TestReportFormat::Junit => results.write_junit(&mut report),
+        }
+        .into_diagnostic()?;
+
+        match &self.output {
+            Some(output) => std::fs::write(output, report).into_diagnostic()?,
+            None => print!("{report}"),
+        }
+
+        eprintln!("{results}");
+        match results.success() {
+            true => Ok(()),
+            false => Err(miette::miette!(
+                "{} test(s) failed",
+                results.count(mu::TestOutcome::Failed)
+            )),
+        }
+    }
+}
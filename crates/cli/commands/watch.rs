@@ -3,7 +3,7 @@
 
 //! µcad CLI watch command
 
-use crate::*;
+use crate::{progress::ProgressBar, *};
 
 #[derive(clap::Parser)]
 pub struct Watch {
@@ -14,9 +14,10 @@ pub struct Watch {
 
     /// The resolution of this export.
     ///
-    /// The resolution can changed relatively `200%` or to an absolute value `0.05mm`.
-    #[arg(short, long, default_value = "0.1mm")]
-    pub resolution: String,
+    /// The resolution can changed relatively `200%` or to an absolute value `0.05mm`
+    /// (default: resolution of the export config).
+    #[arg(short, long)]
+    pub resolution: Option<String>,
 }
 
 /// Run this command for a CLI.
@@ -30,7 +31,7 @@ impl RunCommand for Watch {
 
         let compile_params = cli.compile_parameters();
         let render_params = cli
-            .render_params(&self.resolution)?
+            .render_params(self.resolution.as_deref())?
             .with_cache(render_cache.clone());
 
         // Recompile whenever something relevant happens.
@@ -40,15 +41,37 @@ impl RunCommand for Watch {
                 output_path: self.output.clone(),
                 config: cli.config.export.clone(),
             };
+            // Stop rendering as soon as the source changes.
+            let render_params = render_params
+                .clone()
+                .with_cancellation(watcher.cancellation_token());
+            let (progress_bar, render_params) = match ProgressBar::start() {
+                Some((progress_bar, progress_tx)) => {
+                    (Some(progress_bar), render_params.with_progress(progress_tx))
+                }
+                None => (None, render_params),
+            };
+
             let mut document = mu::Document::open(&self.input)?;
-            match document
+            let result = document
                 .compile(compile_params.clone())
-                .and(document.render(render_params.clone()))
-                .and(document.export(export_params))
-            {
+                .and(document.render(render_params));
+            if let Some(progress_bar) = progress_bar {
+                progress_bar.finish();
+            }
+
+            match result.and_then(|_| document.export(export_params)) {
                 Ok(exported_files) => {
                     eprint!("{exported_files}");
                 }
+                Err(err)
+                    if matches!(
+                        err.downcast_ref::<mu::RenderError>(),
+                        Some(mu::RenderError::Cancelled)
+                    ) =>
+                {
+                    eprintln!("Source changed, render cancelled.");
+                }
                 Err(err) => {
                     eprintln!("{err}");
                     cli.print_diagnostics(&document);
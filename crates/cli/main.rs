@@ -7,6 +7,7 @@ extern crate clap;
 
 mod cli;
 mod commands;
+mod progress;
 pub use cli::*;
 use commands::*;
 
@@ -0,0 +1,87 @@
+// Copyright © 2026 The µcad authors <info@microcad.xyz>
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+//! µcad CLI render progress bar
+
+use std::{
+    io::IsTerminal,
+    sync::mpsc,
+    time::{Duration, Instant},
+};
+
+use microcad_driver::prelude as mu;
+
+/// Progress bar which shows the render progress on stderr.
+pub struct ProgressBar {
+    thread: std::thread::JoinHandle<()>,
+}
+
+impl ProgressBar {
+    /// Minimum time between two redraws.
+    const REDRAW_INTERVAL: Duration = Duration::from_millis(100);
+
+    /// Width of the bar in characters.
+    const WIDTH: usize = 30;
+
+    /// Start a progress bar and return it with the sender for the render parameters.
+    ///
+    /// Returns `None` if stderr is not a terminal.
+    pub fn start() -> Option<(Self, mu::ProgressTx)> {
+        if !std::io::stderr().is_terminal() {
+            return None;
+        }
+        let (tx, rx) = mpsc::channel();
+        let thread = std::thread::spawn(move || Self::run(rx));
+        Some((Self { thread }, tx))
+    }
+
+    /// Wait until all senders have been dropped and clear the progress bar.
+    pub fn finish(self) {
+        self.thread.join().ok();
+    }
+
+    fn run(rx: mpsc::Receiver<mu::RenderProgress>) {
+        let start = Instant::now();
+        let mut last_draw: Option<Instant> = None;
+        let mut percent = 0.0;
+        let mut operation = String::new();
+
+        while let Ok(event) = rx.recv() {
+            match &event {
+                mu::RenderProgress::Model { .. } => percent = event.percent().unwrap_or(percent),
+                mu::RenderProgress::BooleanOp { op, operands } => {
+                    operation = format!("{op:?} of {operands}").to_lowercase()
+                }
+            }
+
+            if last_draw.is_some_and(|last_draw| last_draw.elapsed() < Self::REDRAW_INTERVAL) {
+                continue;
+            }
+            last_draw = Some(Instant::now());
+            eprint!(
+                "\r\x1b[2K{}",
+                Self::line(percent, start.elapsed(), &operation)
+            );
+        }
+
+        if last_draw.is_some() {
+            eprint!("\r\x1b[2K");
+        }
+    }
+
+    fn line(percent: f32, elapsed: Duration, operation: &str) -> String {
+        let filled = ((percent / 100.0 * Self::WIDTH as f32) as usize).min(Self::WIDTH);
+        let eta = match percent > 0.0 {
+            true => format!(
+                "{:.0}s",
+                elapsed.as_secs_f32() * (100.0 - percent) / percent
+            ),
+            false => "?".into(),
+        };
+        format!(
+            "[{}{}] {percent:>3.0}% ETA {eta} {operation}",
+            "#".repeat(filled),
+            "-".repeat(Self::WIDTH - filled)
+        )
+    }
+}
@@ -3,15 +3,15 @@
 
 //! Boolean operations
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 /// Boolean operations
 pub enum BooleanOp {
     /// Computes the union R = P ∪ Q
     Union,
     /// computes the difference R = P ∖ Q
     Subtract,
-    /// computes the complement R=P̅
-    Complement,
+    /// computes the symmetric difference R = P △ Q
+    Xor,
     /// computes the intersection R = P ∩ Q
     Intersect,
 }
@@ -30,7 +30,7 @@ impl From<&BooleanOp> for OpType {
             BooleanOp::Subtract => OpType::Difference,
             BooleanOp::Union => OpType::Union,
             BooleanOp::Intersect => OpType::Intersection,
-            BooleanOp::Complement => OpType::Xor,
+            BooleanOp::Xor => OpType::Xor,
         }
     }
 }
@@ -0,0 +1,347 @@
+// Copyright © 2026 The µcad authors <info@microcad.xyz>
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+//! Geometric deviation between a geometry and a reference geometry.
+
+use cgmath::InnerSpace;
+use geo::{Area, BooleanOps, HasDimensions, LinesIter};
+
+use crate::{validation::Grid, *};
+
+/// Number of sample spacings along the largest extent of the compared boundaries.
+const SAMPLES_PER_EXTENT: Scalar = 64.0;
+
+/// Deviation of a geometry from a reference geometry.
+#[derive(Debug, Clone)]
+pub struct Deviation<B> {
+    /// Area (2D) or volume (3D) of the symmetric difference.
+    pub difference: Scalar,
+    /// Hausdorff distance between the boundaries, approximated by samples of their edges and faces.
+    pub distance: Scalar,
+    /// Bounds of the symmetric difference or `None` if geometries are equal.
+    pub region: Option<B>,
+}
+
+/// 2D deviation.
+pub type Deviation2D = Deviation<Bounds2D>;
+
+/// 3D deviation.
+pub type Deviation3D = Deviation<Bounds3D>;
+
+impl<B> Deviation<B> {
+    /// Return `true` if difference and distance do not exceed the given tolerances.
+    pub fn is_within(&self, difference: Scalar, distance: Scalar) -> bool {
+        self.difference <= difference && self.distance <= distance
+    }
+}
+
+/// Calculate the deviation of a geometry from a reference geometry.
+pub trait CalcDeviation {
+    /// Bounds type of the deviation region.
+    type Bounds;
+
+    /// Compare with a reference geometry.
+    fn deviation(&self, reference: &Self) -> Deviation<Self::Bounds>;
+}
+
+impl CalcDeviation for geo2d::MultiPolygon {
+    type Bounds = Bounds2D;
+
+    fn deviation(&self, reference: &Self) -> Deviation2D {
+        let xor = self.xor(reference);
+        let segments = |mp: &Self| -> Vec<Segment> {
+            mp.lines_iter()
+                .map(|l| {
+                    Segment(
+                        Vec3::new(l.start.x, l.start.y, 0.0),
+                        Vec3::new(l.end.x, l.end.y, 0.0),
+                    )
+                })
+                .collect()
+        };
+
+        Deviation {
+            difference: xor.unsigned_area(),
+            distance: hausdorff_distance(&segments(self), &segments(reference)),
+            region: match xor.is_empty() {
+                true => None,
+                false => Some(xor.calc_bounds_2d()),
+            },
+        }
+    }
+}
+
+impl CalcDeviation for TriangleMesh {
+    type Bounds = Bounds3D;
+
+    fn deviation(&self, reference: &Self) -> Deviation3D {
+        let xor = TriangleMesh::from(
+            manifold_boolean_op(
+                &self.to_manifold(),
+                &reference.to_manifold(),
+                &BooleanOp::Xor,
+            )
+            .to_mesh(),
+        );
+        let triangles = |mesh: &Self| -> Vec<Triangle<Vec3>> {
+            mesh.triangles()
+                .map(|t| {
+                    Triangle(
+                        t.0.cast().expect("Successful cast"),
+                        t.1.cast().expect("Successful cast"),
+                        t.2.cast().expect("Successful cast"),
+                    )
+                })
+                .collect()
+        };
+
+        Deviation {
+            difference: xor.volume(),
+            distance: hausdorff_distance(&triangles(self), &triangles(reference)),
+            region: match xor.is_empty() {
+                true => None,
+                false => Some(xor.calc_bounds_3d()),
+            },
+        }
+    }
+}
+
+/// Element of a boundary: an edge in 2D or a face in 3D.
+trait BoundaryElement {
+    /// Bounds of the element.
+    fn bounds(&self) -> Bounds3D;
+
+    /// Distance between the element and point `p`.
+    fn distance(&self, p: Vec3) -> Scalar;
+
+    /// Points on the element (including its vertices) which are at most `step` apart.
+    fn samples(&self, step: Scalar) -> Vec<Vec3>;
+}
+
+/// Line segment of a 2D boundary.
+struct Segment(Vec3, Vec3);
+
+impl BoundaryElement for Segment {
+    fn bounds(&self) -> Bounds3D {
+        [self.0, self.1].into_iter().collect()
+    }
+
+    fn distance(&self, p: Vec3) -> Scalar {
+        let (a, ab) = (self.0, self.1 - self.0);
+        let len2 = ab.magnitude2();
+        let t = if len2 > 0.0 {
+            ((p - a).dot(ab) / len2).clamp(0.0, 1.0)
+        } else {
+            0.0
+        };
+        (p - (a + ab * t)).magnitude()
+    }
+
+    fn samples(&self, step: Scalar) -> Vec<Vec3> {
+        let ab = self.1 - self.0;
+        let n = sample_count(ab.magnitude(), step);
+        (0..=n)
+            .map(|i| self.0 + ab * (i as Scalar / n as Scalar))
+            .collect()
+    }
+}
+
+impl BoundaryElement for Triangle<Vec3> {
+    fn bounds(&self) -> Bounds3D {
+        [self.0, self.1, self.2].into_iter().collect()
+    }
+
+    fn distance(&self, p: Vec3) -> Scalar {
+        (p - closest_point_on_triangle(p, self)).magnitude()
+    }
+
+    fn samples(&self, step: Scalar) -> Vec<Vec3> {
+        let (ab, ac) = (self.1 - self.0, self.2 - self.0);
+        let longest = ab
+            .magnitude()
+            .max(ac.magnitude())
+            .max((ac - ab).magnitude());
+        let n = sample_count(longest, step);
+        (0..=n)
+            .flat_map(|i| (0..=n - i).map(move |j| (i, j)))
+            .map(|(i, j)| {
+                self.0 + ab * (i as Scalar / n as Scalar) + ac * (j as Scalar / n as Scalar)
+            })
+            .collect()
+    }
+}
+
+/// Number of sample intervals to divide a length into intervals not longer than `step`.
+fn sample_count(length: Scalar, step: Scalar) -> usize {
+    match step > 0.0 {
+        true => ((length / step).ceil() as usize).max(1),
+        false => 1,
+    }
+}
+
+/// Symmetric Hausdorff distance between two boundaries.
+///
+/// Both boundaries are sampled with a spacing of 1/[`SAMPLES_PER_EXTENT`] of their extent,
+/// so the result may fall below the exact distance by at most this spacing.
+/// The nearest elements of each sample are searched in a grid.
+///
+/// Returns infinity if only one of both boundaries is empty.
+fn hausdorff_distance<E: BoundaryElement>(lhs: &[E], rhs: &[E]) -> Scalar {
+    match (lhs.is_empty(), rhs.is_empty()) {
+        (true, true) => return 0.0,
+        (false, false) => {}
+        _ => return Scalar::INFINITY,
+    }
+
+    let bounds = |elements: &[E]| -> Vec<Bounds3D> { elements.iter().map(E::bounds).collect() };
+    let (lhs_bounds, rhs_bounds) = (bounds(lhs), bounds(rhs));
+    let total =
+        |bounds: &[Bounds3D]| -> Bounds3D { bounds.iter().flat_map(|b| [b.min, b.max]).collect() };
+    let size = {
+        let total = total(&lhs_bounds).extend(total(&rhs_bounds));
+        total.max - total.min
+    };
+    let step = size.x.max(size.y).max(size.z) / SAMPLES_PER_EXTENT;
+
+    let directed = |from: &[E], to: &[E], to_bounds: &[Bounds3D]| {
+        let bounds = total(to_bounds);
+        let grid = Grid::new(to_bounds.iter(), &bounds);
+        from.iter()
+            .flat_map(|element| element.samples(step))
+            .map(|p| nearest_distance(p, to, &grid, &bounds))
+            .fold(0.0, Scalar::max)
+    };
+    directed(lhs, rhs, &rhs_bounds).max(directed(rhs, lhs, &lhs_bounds))
+}
+
+/// Distance between point `p` and the nearest of the `elements`, which lie within `bounds`.
+///
+/// The search region around `p` grows until it contains the nearest element.
+fn nearest_distance<E: BoundaryElement>(
+    p: Vec3,
+    elements: &[E],
+    grid: &Grid,
+    bounds: &Bounds3D,
+) -> Scalar {
+    let clamp = |v: Vec3| {
+        Vec3::new(
+            v.x.clamp(bounds.min.x, bounds.max.x),
+            v.y.clamp(bounds.min.y, bounds.max.y),
+            v.z.clamp(bounds.min.z, bounds.max.z),
+        )
+    };
+
+    let mut radius = (p - clamp(p)).magnitude() + grid.cell_size();
+    loop {
+        let offset = Vec3::new(radius, radius, radius);
+        let region = Bounds3D::new(clamp(p - offset), clamp(p + offset));
+        let distance = grid
+            .query(&region)
+            .into_iter()
+            .map(|i| elements[i].distance(p))
+            .fold(Scalar::INFINITY, Scalar::min);
+        // Elements outside the region are farther away than `radius`.
+        if distance <= radius {
+            return distance;
+        }
+        radius *= 2.0;
+    }
+}
+
+/// Closest point to `p` on a triangle.
+///
+/// See *Real-Time Collision Detection* by Christer Ericson, chapter 5.1.5.
+fn closest_point_on_triangle(p: Vec3, t: &Triangle<Vec3>) -> Vec3 {
+    let (a, b, c) = (t.0, t.1, t.2);
+    let (ab, ac, ap) = (b - a, c - a, p - a);
+
+    let (d1, d2) = (ab.dot(ap), ac.dot(ap));
+    if d1 <= 0.0 && d2 <= 0.0 {
+        return a;
+    }
+
+    let bp = p - b;
+    let (d3, d4) = (ab.dot(bp), ac.dot(bp));
+    if d3 >= 0.0 && d4 <= d3 {
+        return b;
+    }
+
+    let vc = d1 * d4 - d3 * d2;
+    if vc <= 0.0 && d1 >= 0.0 && d3 <= 0.0 {
+        return a + ab * (d1 / (d1 - d3));
+    }
+
+    let cp = p - c;
+    let (d5, d6) = (ab.dot(cp), ac.dot(cp));
+    if d6 >= 0.0 && d5 <= d6 {
+        return c;
+    }
+
+    let vb = d5 * d2 - d1 * d6;
+    if vb <= 0.0 && d2 >= 0.0 && d6 <= 0.0 {
+        return a + ac * (d2 / (d2 - d6));
+    }
+
+    let va = d3 * d6 - d5 * d4;
+    if va <= 0.0 && d4 >= d3 && d5 >= d6 {
+        return b + (c - b) * ((d4 - d3) / ((d4 - d3) + (d5 - d6)));
+    }
+
+    let sum = va + vb + vc;
+    if sum == 0.0 {
+        // degenerated triangle
+        return a;
+    }
+    a + ab * (vb / sum) + ac * (vc / sum)
+}
+
+#[test]
+fn test_deviation_2d() {
+    let square = |x: Scalar, size: Scalar| {
+        geo2d::MultiPolygon::new(vec![
+            geo::Rect::new(
+                geo::coord! {x: x, y: 0.0},
+                geo::coord! {x: x + size, y: size},
+            )
+            .to_polygon(),
+        ])
+    };
+
+    let equal = square(0.0, 10.0).deviation(&square(0.0, 10.0));
+    assert!(equal.difference < 1e-9 && equal.distance < 1e-9);
+    assert!(equal.region.is_none());
+
+    let shifted = square(1.0, 10.0).deviation(&square(0.0, 10.0));
+    assert!((shifted.difference - 20.0).abs() < 1e-9);
+    assert!((shifted.distance - 1.0).abs() < 1e-9);
+    let region = shifted.region.expect("deviation region");
+    assert!((region.min.x - 0.0).abs() < 1e-9 && (region.max.x - 11.0).abs() < 1e-9);
+
+    assert!(shifted.is_within(20.0, 1.0));
+    assert!(!shifted.is_within(10.0, 1.0));
+
+    // The vertices of both geometries are on the boundary of the other geometry,
+    // but the middle of the long edges is 4 away from the nearest square.
+    let rect = geo2d::MultiPolygon::new(vec![
+        geo::Rect::new(geo::coord! {x: 0.0, y: 0.0}, geo::coord! {x: 10.0, y: 1.0}).to_polygon(),
+    ]);
+    let mut squares = square(0.0, 1.0);
+    squares.0.extend(square(9.0, 1.0));
+    let gap = rect.deviation(&squares);
+    assert!((gap.distance - 4.0).abs() < 1e-9);
+}
+
+#[test]
+fn test_deviation_3d() {
+    let cube = TriangleMesh::from(Manifold::cube(2.0, 2.0, 2.0).to_mesh());
+    let larger = TriangleMesh::from(Manifold::cube(2.0, 2.0, 3.0).to_mesh());
+
+    let equal = cube.deviation(&cube);
+    assert!(equal.difference < 1e-3 && equal.distance < 1e-6);
+
+    let deviation = larger.deviation(&cube);
+    assert!((deviation.difference - 4.0).abs() < 1e-3);
+    assert!((deviation.distance - 1.0).abs() < 1e-6);
+    let region = deviation.region.expect("deviation region");
+    assert!((region.min.z - 2.0).abs() < 1e-3 && (region.max.z - 3.0).abs() < 1e-3);
+}
@@ -0,0 +1,375 @@
+// Copyright © 2026 The µcad authors <info@microcad.xyz>
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+//! 2D curves which are flattened into points.
+
+use cgmath::InnerSpace;
+use thiserror::Error;
+
+use crate::{
+    geo2d::bezier::{Bezier, TValue},
+    *,
+};
+
+/// Error of an invalid curve.
+#[derive(Debug, Error, PartialEq)]
+pub enum CurveError {
+    /// The number of points does not match the curve.
+    #[error("{curve} needs {expected} points, got {found}")]
+    PointCount {
+        /// Name of the curve.
+        curve: &'static str,
+        /// Description of the expected number of points.
+        expected: &'static str,
+        /// Actual number of points.
+        found: usize,
+    },
+
+    /// The degree of a B-spline is less than one.
+    #[error("B-spline degree must be at least 1, got {0}")]
+    Degree(usize),
+
+    /// The points of an arc are on a line.
+    #[error("Arc points must not be on a line")]
+    CollinearArc,
+}
+
+/// A 2D curve.
+#[derive(Debug, Clone)]
+pub enum Curve {
+    /// Quadratic Bézier path: A start point followed by a control point and an end point per segment.
+    QuadraticBezier(Vec<Vec2>),
+    /// Cubic Bézier path: A start point followed by two control points and an end point per segment.
+    CubicBezier(Vec<Vec2>),
+    /// Catmull-Rom spline which passes through all points.
+    CatmullRom(Vec<Vec2>),
+    /// Uniform B-spline with control points.
+    ///
+    /// Open B-splines start and end at their first and last control point.
+    BSpline {
+        /// Control points.
+        points: Vec<Vec2>,
+        /// Degree of the polynomial segments.
+        degree: usize,
+    },
+    /// Circular arc from a start point through a point to an end point.
+    Arc(Vec2, Vec2, Vec2),
+}
+
+impl Curve {
+    /// Check if the curve is well defined.
+    pub fn validate(&self) -> Result<(), CurveError> {
+        let count = |curve, expected, found, valid: bool| match valid {
+            true => Ok(()),
+            false => Err(CurveError::PointCount {
+                curve,
+                expected,
+                found,
+            }),
+        };
+
+        match self {
+            Curve::QuadraticBezier(points) => count(
+                "Quadratic Bézier path",
+                "1 + 2n",
+                points.len(),
+                points.len() >= 3 && (points.len() - 1) % 2 == 0,
+            ),
+            Curve::CubicBezier(points) => count(
+                "Cubic Bézier path",
+                "1 + 3n",
+                points.len(),
+                points.len() >= 4 && (points.len() - 1) % 3 == 0,
+            ),
+            Curve::CatmullRom(points) => count(
+                "Catmull-Rom spline",
+                "at least 2",
+                points.len(),
+                points.len() >= 2,
+            ),
+            Curve::BSpline { points, degree } => match degree {
+                0 => Err(CurveError::Degree(*degree)),
+                _ => count(
+                    "B-spline",
+                    "more than degree",
+                    points.len(),
+                    points.len() > *degree,
+                ),
+            },
+            Curve::Arc(a, b, c) => match circumcircle(*a, *b, *c) {
+                Some(_) => Ok(()),
+                None => Err(CurveError::CollinearArc),
+            },
+        }
+    }
+
+    /// Flatten the curve into points.
+    ///
+    /// The distance between the curve and the lines between the points is at most `resolution.linear`.
+    /// A `closed` curve is connected from its end to its start and the first point is not repeated.
+    pub fn flatten(&self, closed: bool, resolution: &RenderResolution) -> Vec<Vec2> {
+        let tolerance = resolution.linear.max(Scalar::EPSILON);
+        let mut points = match self {
+            Curve::QuadraticBezier(points) => bezier_path(points, 2, tolerance),
+            Curve::CubicBezier(points) => bezier_path(points, 3, tolerance),
+            Curve::CatmullRom(points) => catmull_rom(points, closed, tolerance),
+            Curve::BSpline { points, degree } => b_spline(points, *degree, closed, tolerance),
+            Curve::Arc(a, b, c) => arc(*a, *b, *c, resolution),
+        };
+
+        points.dedup_by(|a, b| (*a - *b).magnitude2() < Scalar::EPSILON);
+        if closed
+            && points.len() > 1
+            && (points[0] - points[points.len() - 1]).magnitude2() < Scalar::EPSILON
+        {
+            points.pop();
+        }
+        points
+    }
+}
+
+/// Number of line segments needed to approximate a polynomial segment within `tolerance`.
+///
+/// `curvature` is the maximum length of the second derivative on the segment.
+fn steps(curvature: Scalar, tolerance: Scalar) -> usize {
+    ((curvature / (8.0 * tolerance)).sqrt().ceil() as usize).clamp(1, 1024)
+}
+
+/// Create a quadratic or cubic Bézier segment from its control points.
+fn bezier(control: &[Vec2]) -> Bezier {
+    let point = |i: usize| (control[i].x, control[i].y).into();
+    match control.len() {
+        3 => Bezier::from_quadratic_dvec2(point(0), point(1), point(2)),
+        _ => Bezier::from_cubic_dvec2(point(0), point(1), point(2), point(3)),
+    }
+}
+
+/// Maximum length of the second differences of control points.
+fn second_difference(control: &[Vec2]) -> Scalar {
+    control
+        .windows(3)
+        .map(|p| (p[0] - p[1] * 2.0 + p[2]).magnitude())
+        .fold(0.0, Scalar::max)
+}
+
+/// Flatten a single Bézier segment without its start point.
+fn bezier_segment(control: &[Vec2], tolerance: Scalar) -> impl Iterator<Item = Vec2> {
+    let degree = (control.len() - 1) as Scalar;
+    let n = steps(
+        degree * (degree - 1.0) * second_difference(control),
+        tolerance,
+    );
+    let bezier = bezier(control);
+    (1..=n).map(move |i| {
+        let point = bezier.evaluate(TValue::Parametric(i as Scalar / n as Scalar));
+        Vec2::new(point.x, point.y)
+    })
+}
+
+/// Flatten a path of Bézier segments of a `degree` which share their end points.
+fn bezier_path(points: &[Vec2], degree: usize, tolerance: Scalar) -> Vec<Vec2> {
+    let Some(first) = points.first() else {
+        return Vec::new();
+    };
+    std::iter::once(*first)
+        .chain(
+            points
+                .windows(degree + 1)
+                .step_by(degree)
+                .flat_map(|control| bezier_segment(control, tolerance)),
+        )
+        .collect()
+}
+
+/// Flatten a uniform Catmull-Rom spline by converting its segments into cubic Bézier segments.
+fn catmull_rom(points: &[Vec2], closed: bool, tolerance: Scalar) -> Vec<Vec2> {
+    let n = points.len();
+    if n < 2 {
+        return points.to_vec();
+    }
+    let point = |i: isize| match closed {
+        true => points[i.rem_euclid(n as isize) as usize],
+        // Open splines repeat their end points.
+        false => points[i.clamp(0, n as isize - 1) as usize],
+    };
+    let segments = match closed {
+        true => n,
+        false => n - 1,
+    } as isize;
+
+    std::iter::once(points[0])
+        .chain((0..segments).flat_map(|i| {
+            let (p0, p1, p2, p3) = (point(i - 1), point(i), point(i + 1), point(i + 2));
+            let control = [p1, p1 + (p2 - p0) / 6.0, p2 - (p3 - p1) / 6.0, p2];
+            bezier_segment(&control, tolerance).collect::<Vec<_>>()
+        }))
+        .collect()
+}
+
+/// Evaluate a B-spline with de Boor's algorithm.
+///
+/// `span` is the index of the knot interval `knots[span]..knots[span + 1]` which contains `t`.
+fn de_boor(points: &[Vec2], knots: &[Scalar], degree: usize, span: usize, t: Scalar) -> Vec2 {
+    let mut d: Vec<Vec2> = (0..=degree).map(|j| points[j + span - degree]).collect();
+    for r in 1..=degree {
+        for j in (r..=degree).rev() {
+            let i = j + span - degree;
+            let denominator = knots[i + degree + 1 - r] - knots[i];
+            let alpha = match denominator.abs() > Scalar::EPSILON {
+                true => (t - knots[i]) / denominator,
+                false => 0.0,
+            };
+            d[j] = d[j - 1] * (1.0 - alpha) + d[j] * alpha;
+        }
+    }
+    d[degree]
+}
+
+/// Flatten a uniform B-spline.
+///
+/// Open B-splines use a clamped knot vector, closed B-splines repeat their first control points.
+fn b_spline(points: &[Vec2], degree: usize, closed: bool, tolerance: Scalar) -> Vec<Vec2> {
+    if degree == 0 || points.len() <= degree {
+        return points.to_vec();
+    }
+    let (points, knots): (Vec<Vec2>, Vec<Scalar>) = match closed {
+        true => {
+            let points: Vec<_> = points.iter().chain(&points[..degree]).copied().collect();
+            let knots = (0..points.len() + degree + 1)
+                .map(|i| i as Scalar)
+                .collect();
+            (points, knots)
+        }
+        false => {
+            let inner = points.len() - degree;
+            let knots = std::iter::repeat_n(0.0, degree)
+                .chain((0..=inner).map(|i| i as Scalar))
+                .chain(std::iter::repeat_n(inner as Scalar, degree))
+                .collect();
+            (points.to_vec(), knots)
+        }
+    };
+
+    let n = steps(
+        (degree * degree.saturating_sub(1)) as Scalar * second_difference(&points),
+        tolerance,
+    );
+    (degree..points.len())
+        .filter(|span| knots[span + 1] > knots[*span])
+        .flat_map(|span| {
+            let (start, end) = (knots[span], knots[span + 1]);
+            let first = match span == degree {
+                true => 0,
+                false => 1,
+            };
+            (first..=n)
+                .map(|i| {
+                    let t = start + (end - start) * i as Scalar / n as Scalar;
+                    de_boor(&points, &knots, degree, span, t)
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect()
+}
+
+/// Calculate center and radius of the circle through three points.
+fn circumcircle(a: Vec2, b: Vec2, c: Vec2) -> Option<(Vec2, Scalar)> {
+    let (ab, ac) = (b - a, c - a);
+    let d = 2.0 * ab.perp_dot(ac);
+    if d.abs() <= Scalar::EPSILON * ab.magnitude() * ac.magnitude() {
+        return None;
+    }
+    let center = a + Vec2::new(
+        ac.y * ab.magnitude2() - ab.y * ac.magnitude2(),
+        ab.x * ac.magnitude2() - ac.x * ab.magnitude2(),
+    ) / d;
+    Some((center, (a - center).magnitude()))
+}
+
+/// Flatten a circular arc from `a` through `b` to `c`.
+fn arc(a: Vec2, b: Vec2, c: Vec2, resolution: &RenderResolution) -> Vec<Vec2> {
+    let Some((center, radius)) = circumcircle(a, b, c) else {
+        return vec![a, c];
+    };
+    let angle = |p: Vec2| (p - center).y.atan2((p - center).x);
+    let start = angle(a);
+    // Counter-clockwise angle from `start` to another point.
+    let sweep = |p: Vec2| (angle(p) - start).rem_euclid(consts::TAU);
+    let end = sweep(c);
+    // The arc runs clockwise if `b` is not on the counter-clockwise way to `c`.
+    let total = match sweep(b) < end {
+        true => end,
+        false => end - consts::TAU,
+    };
+
+    let n = ((resolution.circular_segments(radius) as Scalar * total.abs() / consts::TAU).ceil()
+        as usize)
+        .max(1);
+    (0..=n)
+        .map(|i| {
+            let angle = start + total * i as Scalar / n as Scalar;
+            center + Vec2::new(angle.cos(), angle.sin()) * radius
+        })
+        .collect()
+}
+
+#[test]
+fn test_curve_end_points() {
+    let resolution = RenderResolution::high();
+    let points = vec![
+        Vec2::new(0.0, 0.0),
+        Vec2::new(10.0, 20.0),
+        Vec2::new(20.0, 20.0),
+        Vec2::new(30.0, 0.0),
+    ];
+
+    for curve in [
+        Curve::CubicBezier(points.clone()),
+        Curve::CatmullRom(points.clone()),
+        Curve::BSpline {
+            points: points.clone(),
+            degree: 3,
+        },
+    ] {
+        assert_eq!(curve.validate(), Ok(()));
+        let flat = curve.flatten(false, &resolution);
+        assert!((flat[0] - points[0]).magnitude() < 1e-9);
+        assert!((flat[flat.len() - 1] - points[3]).magnitude() < 1e-9);
+    }
+
+    assert!(Curve::QuadraticBezier(points.clone()).validate().is_err());
+}
+
+#[test]
+fn test_curve_arc() {
+    let arc = Curve::Arc(
+        Vec2::new(10.0, 0.0),
+        Vec2::new(0.0, 10.0),
+        Vec2::new(-10.0, 0.0),
+    );
+    let points = arc.flatten(false, &RenderResolution::high());
+    assert!(
+        points
+            .iter()
+            .all(|p| (p.magnitude() - 10.0).abs() < 1e-9 && p.y >= -1e-9)
+    );
+
+    // Clockwise through the lower half.
+    let arc = Curve::Arc(
+        Vec2::new(10.0, 0.0),
+        Vec2::new(0.0, -10.0),
+        Vec2::new(-10.0, 0.0),
+    );
+    let points = arc.flatten(false, &RenderResolution::high());
+    assert!(points.iter().all(|p| p.y <= 1e-9));
+
+    assert_eq!(
+        Curve::Arc(
+            Vec2::new(0.0, 0.0),
+            Vec2::new(1.0, 1.0),
+            Vec2::new(2.0, 2.0)
+        )
+        .validate(),
+        Err(CurveError::CollinearArc)
+    );
+}
@@ -0,0 +1,205 @@
+// Copyright © 2026 The µcad authors <info@microcad.xyz>
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+//! Rounding and beveling of polygon corners.
+
+use cgmath::InnerSpace;
+use geo::orient::{Direction, Orient};
+
+use crate::*;
+
+/// Selection of polygon corners.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Corners {
+    /// Select concave corners.
+    pub inner: bool,
+    /// Select convex corners.
+    pub outer: bool,
+}
+
+impl Corners {
+    /// Select all corners.
+    pub fn all() -> Self {
+        Self {
+            inner: true,
+            outer: true,
+        }
+    }
+
+    /// Return `true` if a corner with the given convexity is selected.
+    fn contains(&self, convex: bool) -> bool {
+        match convex {
+            true => self.outer,
+            false => self.inner,
+        }
+    }
+}
+
+/// Shape of a modified corner.
+#[derive(Clone, Copy, Debug)]
+enum CornerShape {
+    /// Circular arc with a radius, approximated by a number of segments for a full circle.
+    Fillet(Scalar, u32),
+    /// Straight cut with a distance from the corner along each edge.
+    Chamfer(Scalar),
+}
+
+/// Round or bevel the corners of a geometry.
+pub trait Fillet {
+    /// Round selected corners with an arc of `radius`.
+    ///
+    /// `segments` is the number of segments of a full circle with that radius.
+    fn fillet(&self, radius: Scalar, corners: Corners, segments: u32) -> MultiPolygon;
+
+    /// Bevel selected corners by cutting `distance` from the corner along each edge.
+    fn chamfer(&self, distance: Scalar, corners: Corners) -> MultiPolygon;
+}
+
+impl Fillet for MultiPolygon {
+    fn fillet(&self, radius: Scalar, corners: Corners, segments: u32) -> MultiPolygon {
+        modify_corners(self, CornerShape::Fillet(radius, segments), corners)
+    }
+
+    fn chamfer(&self, distance: Scalar, corners: Corners) -> MultiPolygon {
+        modify_corners(self, CornerShape::Chamfer(distance), corners)
+    }
+}
+
+impl Fillet for Geometry2D {
+    fn fillet(&self, radius: Scalar, corners: Corners, segments: u32) -> MultiPolygon {
+        self.to_multi_polygon().fillet(radius, corners, segments)
+    }
+
+    fn chamfer(&self, distance: Scalar, corners: Corners) -> MultiPolygon {
+        self.to_multi_polygon().chamfer(distance, corners)
+    }
+}
+
+impl Fillet for Geometries2D {
+    fn fillet(&self, radius: Scalar, corners: Corners, segments: u32) -> MultiPolygon {
+        self.to_multi_polygon().fillet(radius, corners, segments)
+    }
+
+    fn chamfer(&self, distance: Scalar, corners: Corners) -> MultiPolygon {
+        self.to_multi_polygon().chamfer(distance, corners)
+    }
+}
+
+fn modify_corners(
+    multi_polygon: &MultiPolygon,
+    shape: CornerShape,
+    corners: Corners,
+) -> MultiPolygon {
+    // Exteriors are counter-clockwise and interiors clockwise,
+    // so the polygon's area is always left of each edge.
+    multi_polygon
+        .orient(Direction::Default)
+        .iter()
+        .map(|polygon| {
+            Polygon::new(
+                modify_ring(polygon.exterior(), shape, corners),
+                polygon
+                    .interiors()
+                    .iter()
+                    .map(|interior| modify_ring(interior, shape, corners))
+                    .collect(),
+            )
+        })
+        .collect()
+}
+
+/// Modify the corners of a ring with the polygon's area on the left side.
+fn modify_ring(ring: &LineString, shape: CornerShape, corners: Corners) -> LineString {
+    let mut points: Vec<Vec2> = ring.coords().map(|c| Vec2::new(c.x, c.y)).collect();
+    if points.len() > 1 && points.first() == points.last() {
+        points.pop();
+    }
+    let len = points.len();
+    if len < 3 {
+        return ring.clone();
+    }
+
+    let mut result = Vec::with_capacity(len);
+    for i in 0..len {
+        let prev = points[(i + len - 1) % len];
+        let corner = points[i];
+        let next = points[(i + 1) % len];
+        let (l1, l2) = ((corner - prev).magnitude(), (next - corner).magnitude());
+        if l1 <= Scalar::EPSILON || l2 <= Scalar::EPSILON {
+            continue;
+        }
+        let (d1, d2) = ((corner - prev) / l1, (next - corner) / l2);
+
+        // Left turns are convex corners, right turns concave corners.
+        let turn = d1.perp_dot(d2);
+        let turning_angle = turn.atan2(d1.dot(d2));
+        if turning_angle.abs() < 1e-9 || !corners.contains(turn > 0.0) {
+            result.push(corner);
+            continue;
+        }
+
+        // Half of the inner angle between both edges.
+        let half_angle = (consts::PI - turning_angle.abs()) / 2.0;
+        // Each edge is shared by two corners, so a corner may use only half of it.
+        let max_distance = l1.min(l2) / 2.0;
+
+        match shape {
+            CornerShape::Chamfer(distance) => {
+                let distance = distance.min(max_distance);
+                result.push(corner - d1 * distance);
+                result.push(corner + d2 * distance);
+            }
+            CornerShape::Fillet(radius, segments) => {
+                let distance = (radius / half_angle.tan()).min(max_distance);
+                let radius = distance * half_angle.tan();
+                let start = corner - d1 * distance;
+                // The arc's center lies on the side the ring turns to.
+                let normal = Vec2::new(-d1.y, d1.x) * turn.signum();
+                let center = start + normal * radius;
+
+                let n = ((segments as Scalar * turning_angle.abs() / consts::TAU).ceil() as usize)
+                    .max(1);
+                let start_angle = (start - center).y.atan2((start - center).x);
+                (0..=n).for_each(|j| {
+                    let angle = start_angle + turning_angle * j as Scalar / n as Scalar;
+                    result.push(center + Vec2::new(angle.cos(), angle.sin()) * radius);
+                });
+            }
+        }
+    }
+
+    // Neighboring corners may share a point if they use a whole edge.
+    result.dedup_by(|a, b| (*a - *b).magnitude2() < Scalar::EPSILON);
+    if result.len() > 1 && (result[0] - result[result.len() - 1]).magnitude2() < Scalar::EPSILON {
+        result.pop();
+    }
+    LineString::from(result.iter().map(|p| (p.x, p.y)).collect::<Vec<_>>())
+}
+
+#[test]
+fn test_chamfer_square() {
+    use geo::Area;
+
+    let square = MultiPolygon::new(vec![Rect::new((0.0, 0.0), (10.0, 10.0)).to_polygon()]);
+    let chamfered = square.chamfer(1.0, Corners::all());
+    assert_eq!(chamfered.0[0].exterior().0.len(), 9);
+    assert!((chamfered.unsigned_area() - 98.0).abs() < 1e-9);
+
+    let inner_only = Corners {
+        inner: true,
+        outer: false,
+    };
+    assert!((square.chamfer(1.0, inner_only).unsigned_area() - 100.0).abs() < 1e-9);
+}
+
+#[test]
+fn test_fillet_square() {
+    use geo::Area;
+
+    let square = MultiPolygon::new(vec![Rect::new((0.0, 0.0), (10.0, 10.0)).to_polygon()]);
+    let filleted = square.fillet(5.0, Corners::all(), 64);
+
+    // A square with a fillet radius of half its size becomes a circle.
+    let circle = std::f64::consts::PI * 25.0;
+    assert!((filleted.unsigned_area() - circle).abs() < 0.5);
+}
@@ -0,0 +1,86 @@
+// Copyright © 2026 The µcad authors <info@microcad.xyz>
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+//! Minkowski sum of 2D geometries.
+
+use geo::{Area, ConvexHull, TriangulateEarcut};
+
+use crate::{traits::Minkowski, *};
+
+/// Split a multi polygon into convex pieces.
+///
+/// A single convex polygon without holes is used as it is,
+/// other polygons are split into triangles.
+fn convex_pieces(multi_polygon: &MultiPolygon) -> Vec<Vec<geo::Coord<Scalar>>> {
+    multi_polygon
+        .iter()
+        .flat_map(|polygon| {
+            let convex = polygon.interiors().is_empty()
+                && (polygon.convex_hull().unsigned_area() - polygon.unsigned_area()).abs()
+                    <= Scalar::EPSILON * polygon.unsigned_area().max(1.0);
+            match convex {
+                true => vec![polygon.exterior().0.clone()],
+                false => polygon
+                    .earcut_triangles_iter()
+                    .map(|triangle| triangle.to_array().to_vec())
+                    .collect(),
+            }
+        })
+        .collect()
+}
+
+impl Minkowski for MultiPolygon {
+    /// The Minkowski sum is the union of the convex hulls of all pairs of convex pieces.
+    fn minkowski(&self, other: &Self) -> MultiPolygon {
+        let (a, b) = (convex_pieces(self), convex_pieces(other));
+
+        let hulls: Vec<Polygon> = a
+            .iter()
+            .flat_map(|a| {
+                b.iter().map(move |b| {
+                    let sums: Vec<_> = a
+                        .iter()
+                        .flat_map(|p| b.iter().map(move |q| *p + *q))
+                        .collect();
+                    geo::MultiPoint::from(sums).convex_hull()
+                })
+            })
+            .collect();
+
+        geo::unary_union(&hulls)
+    }
+}
+
+#[test]
+fn test_minkowski_squares() {
+    let square = |size: Scalar| {
+        MultiPolygon::new(vec![
+            Rect::new((-size / 2.0, -size / 2.0), (size / 2.0, size / 2.0)).to_polygon(),
+        ])
+    };
+
+    // Minkowski sum of two centered squares is a square with the sum of both sizes.
+    let sum = square(10.0).minkowski(&square(2.0));
+    assert!((sum.unsigned_area() - 144.0).abs() < 1e-9);
+}
+
+#[test]
+fn test_minkowski_concave() {
+    // L-shape with an area of 3.
+    let l_shape = MultiPolygon::new(vec![Polygon::new(
+        LineString::from(vec![
+            (0.0, 0.0),
+            (2.0, 0.0),
+            (2.0, 1.0),
+            (1.0, 1.0),
+            (1.0, 2.0),
+            (0.0, 2.0),
+        ]),
+        vec![],
+    )]);
+    let point_like = MultiPolygon::new(vec![Rect::new((0.0, 0.0), (1e-3, 1e-3)).to_polygon()]);
+
+    // Adding an almost point-like square keeps the shape.
+    let sum = l_shape.minkowski(&point_like);
+    assert!((sum.unsigned_area() - 3.0).abs() < 1e-2);
+}
@@ -8,8 +8,12 @@ pub mod bezier;
 mod bounds;
 mod circle;
 mod collection;
+mod curve;
+mod fillet;
 mod geometry;
 mod line;
+mod minkowski;
+mod polygon;
 mod primitives;
 mod reflect;
 mod size;
@@ -20,9 +24,12 @@ pub use align::*;
 pub use bounds::*;
 pub use circle::*;
 pub use collection::*;
+pub use curve::*;
+pub use fillet::*;
 use geo::AffineTransform;
 pub use geometry::*;
 pub use line::*;
+pub use polygon::*;
 pub use primitives::*;
 pub use reflect::*;
 pub use size::*;
@@ -0,0 +1,179 @@
+// Copyright © 2026 The µcad authors <info@microcad.xyz>
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+//! Polygons from points.
+
+use geo::{
+    Contains, Intersects,
+    orient::{Direction, Orient},
+};
+use thiserror::Error;
+
+use crate::*;
+
+/// Ring of a polygon.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Ring {
+    /// Outline of the polygon.
+    Exterior,
+    /// Hole with an index.
+    Hole(usize),
+}
+
+impl std::fmt::Display for Ring {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Ring::Exterior => write!(f, "Outline"),
+            Ring::Hole(index) => write!(f, "Hole {index}"),
+        }
+    }
+}
+
+/// Error of an invalid polygon.
+#[derive(Debug, Error, PartialEq)]
+pub enum PolygonError {
+    /// A ring has less than three distinct points.
+    #[error("{0} needs at least three distinct points")]
+    TooFewPoints(Ring),
+
+    /// Edges of a ring intersect each other.
+    #[error("{0} intersects itself")]
+    SelfIntersection(Ring),
+
+    /// Two rings intersect each other.
+    #[error("{0} intersects {1}")]
+    RingIntersection(Ring, Ring),
+
+    /// A hole is not inside the outline.
+    #[error("{0} is not inside the outline")]
+    HoleOutside(Ring),
+}
+
+/// Create a polygon from the points of its outline and holes.
+///
+/// The rings must not intersect themselves or each other and holes must be inside the outline.
+/// Points may be given in any order, the resulting polygon has a counter-clockwise outline
+/// and clockwise holes.
+pub fn polygon_from_points(
+    exterior: &[Vec2],
+    holes: &[Vec<Vec2>],
+) -> Result<Polygon, PolygonError> {
+    let rings = std::iter::once((Ring::Exterior, exterior))
+        .chain(
+            holes
+                .iter()
+                .enumerate()
+                .map(|(index, hole)| (Ring::Hole(index), hole.as_slice())),
+        )
+        .map(|(ring, points)| {
+            let points = ring_points(points);
+            match points.len() < 3 {
+                true => Err(PolygonError::TooFewPoints(ring)),
+                false => Ok((ring, points)),
+            }
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    // Edges of all rings with their ring and their index within the ring.
+    let edges: Vec<_> = rings
+        .iter()
+        .flat_map(|(ring, points)| {
+            (0..points.len()).map(move |i| {
+                let (a, b) = (points[i], points[(i + 1) % points.len()]);
+                (
+                    *ring,
+                    i,
+                    points.len(),
+                    geo::Line::new((a.x, a.y), (b.x, b.y)),
+                )
+            })
+        })
+        .collect();
+
+    for (i, (ring_a, index_a, len, line_a)) in edges.iter().enumerate() {
+        for (ring_b, index_b, _, line_b) in &edges[i + 1..] {
+            // Neighboring edges share a point.
+            if ring_a == ring_b
+                && (index_b - index_a == 1 || (*index_a == 0 && *index_b == len - 1))
+            {
+                continue;
+            }
+            if line_a.intersects(line_b) {
+                return Err(match ring_a == ring_b {
+                    true => PolygonError::SelfIntersection(*ring_a),
+                    false => PolygonError::RingIntersection(*ring_a, *ring_b),
+                });
+            }
+        }
+    }
+
+    let line_string =
+        |points: &[Vec2]| LineString::from(points.iter().map(|p| (p.x, p.y)).collect::<Vec<_>>());
+    let (_, exterior) = &rings[0];
+    let outline = Polygon::new(line_string(exterior), vec![]);
+
+    // Rings do not intersect, so a hole is inside if any of its points is.
+    if let Some((ring, _)) = rings[1..]
+        .iter()
+        .find(|(_, points)| !outline.contains(&Point::new(points[0].x, points[0].y)))
+    {
+        return Err(PolygonError::HoleOutside(*ring));
+    }
+
+    Ok(Polygon::new(
+        line_string(exterior),
+        rings[1..]
+            .iter()
+            .map(|(_, points)| line_string(points))
+            .collect(),
+    )
+    .orient(Direction::Default))
+}
+
+/// Remove consecutive duplicates and a closing point from the points of a ring.
+fn ring_points(points: &[Vec2]) -> Vec<Vec2> {
+    let mut points = points.to_vec();
+    points.dedup();
+    if points.len() > 1 && points.first() == points.last() {
+        points.pop();
+    }
+    points
+}
+
+#[test]
+fn test_polygon_from_points() {
+    use geo::Area;
+
+    let square = |size: Scalar| {
+        vec![
+            Vec2::new(-size, -size),
+            Vec2::new(-size, size),
+            Vec2::new(size, size),
+            Vec2::new(size, -size),
+        ]
+    };
+
+    // Clockwise points are reoriented.
+    let polygon = polygon_from_points(&square(2.0), &[square(1.0)]).expect("valid polygon");
+    assert!((polygon.signed_area() - 12.0).abs() < 1e-9);
+
+    assert_eq!(
+        polygon_from_points(&square(1.0), &[square(2.0)]),
+        Err(PolygonError::HoleOutside(Ring::Hole(0)))
+    );
+
+    let bowtie = [
+        Vec2::new(0.0, 0.0),
+        Vec2::new(1.0, 1.0),
+        Vec2::new(1.0, 0.0),
+        Vec2::new(0.0, 1.0),
+    ];
+    assert_eq!(
+        polygon_from_points(&bowtie, &[]),
+        Err(PolygonError::SelfIntersection(Ring::Exterior))
+    );
+    assert_eq!(
+        polygon_from_points(&bowtie[..2], &[]),
+        Err(PolygonError::TooFewPoints(Ring::Exterior))
+    );
+}
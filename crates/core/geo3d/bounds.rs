@@ -153,6 +153,20 @@ impl Default for Bounds3D {
     }
 }
 
+impl std::fmt::Display for Bounds3D {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.is_valid() {
+            true => write!(
+                f,
+                "[{min:?}, {max:?}]",
+                min = (self.min.x, self.min.y, self.min.z),
+                max = (self.max.x, self.max.y, self.max.z)
+            ),
+            false => write!(f, "[no bounds]"),
+        }
+    }
+}
+
 impl FromIterator<Vec3> for Bounds3D {
     fn from_iter<I: IntoIterator<Item = Vec3>>(iter: I) -> Self {
         let mut iter = iter.into_iter();
@@ -51,7 +51,7 @@ impl Geometries3D {
         manifold_list[1..]
             .iter()
             .fold(manifold_list[0].clone(), |acc, other| {
-                Rc::new(acc.boolean_op(other, op.into()))
+                Rc::new(manifold_boolean_op(&acc, other, op))
             })
     }
 }
@@ -11,6 +11,71 @@ use geo::TriangulateEarcut;
 
 use crate::*;
 
+/// Shape of the top or bottom edges of a linear extrusion.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum ExtrudeEdge {
+    /// Sharp edge.
+    #[default]
+    Sharp,
+    /// Rounded edge.
+    Fillet {
+        /// Radius of the rounding.
+        radius: Scalar,
+        /// Number of segments for a full circle with that radius.
+        segments: u32,
+    },
+    /// Beveled edge with a distance from the edge.
+    Chamfer(Scalar),
+}
+
+impl ExtrudeEdge {
+    /// Size of the edge in Z direction.
+    fn size(&self) -> Scalar {
+        match self {
+            ExtrudeEdge::Sharp => 0.0,
+            ExtrudeEdge::Fillet { radius, .. } => *radius,
+            ExtrudeEdge::Chamfer(distance) => *distance,
+        }
+    }
+
+    /// Return the edge scaled by a factor.
+    fn scaled(self, factor: Scalar) -> Self {
+        match self {
+            ExtrudeEdge::Sharp => ExtrudeEdge::Sharp,
+            ExtrudeEdge::Fillet { radius, segments } => ExtrudeEdge::Fillet {
+                radius: radius * factor,
+                segments,
+            },
+            ExtrudeEdge::Chamfer(distance) => ExtrudeEdge::Chamfer(distance * factor),
+        }
+    }
+
+    /// Return the edge scaled down to a maximum size.
+    fn clamped(self, max: Scalar) -> Self {
+        match self.size() > max {
+            true => self.scaled(max / self.size()),
+            false => self,
+        }
+    }
+
+    /// Profile offsets and heights from the outermost edge to the full profile.
+    fn levels(&self) -> Vec<(Scalar, Scalar)> {
+        match *self {
+            ExtrudeEdge::Sharp => vec![(0.0, 0.0)],
+            ExtrudeEdge::Chamfer(distance) => vec![(-distance, 0.0), (0.0, distance)],
+            ExtrudeEdge::Fillet { radius, segments } => {
+                let n = (segments / 4).max(1);
+                (0..=n)
+                    .map(|i| {
+                        let angle = PI * 0.5 * i as Scalar / n as Scalar;
+                        (radius * (angle.sin() - 1.0), radius * (1.0 - angle.cos()))
+                    })
+                    .collect()
+            }
+        }
+    }
+}
+
 /// A type of an extrusion with certain parameters.
 pub enum Extrusion {
     /// A linear extrusion.
@@ -23,6 +88,10 @@ pub enum Extrusion {
         scale_y: Scalar,
         /// Twist angle (default = °0).
         twist: Angle,
+        /// Shape of the bottom edges.
+        bottom: ExtrudeEdge,
+        /// Shape of the top edges.
+        top: ExtrudeEdge,
     },
     /// Revolve extrusion.
     Revolve {
@@ -43,7 +112,17 @@ pub trait Extrude {
                 scale_x,
                 scale_y,
                 twist,
+                bottom: ExtrudeEdge::Sharp,
+                top: ExtrudeEdge::Sharp,
             } => self.linear_extrude(height, scale_x, scale_y, twist),
+            Extrusion::Linear {
+                height,
+                scale_x,
+                scale_y,
+                twist,
+                bottom,
+                top,
+            } => self.linear_extrude_with_edges(height, scale_x, scale_y, twist, bottom, top),
             Extrusion::Revolve { angle, segments } => self.revolve_extrude(angle, segments),
         }
     }
@@ -76,6 +155,22 @@ pub trait Extrude {
         WithBounds3D::new(mesh, bounds)
     }
 
+    /// Perform a linear extrusion with rounded or beveled top and bottom edges.
+    ///
+    /// Only geometries with an area support edges, others are extruded with sharp edges.
+    fn linear_extrude_with_edges(
+        &self,
+        height: Length,
+        scale_x: Scalar,
+        scale_y: Scalar,
+        twist: Angle,
+        _bottom: ExtrudeEdge,
+        _top: ExtrudeEdge,
+    ) -> WithBounds3D<TriangleMesh> {
+        log::warn!("Extrusion edges are only supported for polygons.");
+        self.linear_extrude(height, scale_x, scale_y, twist)
+    }
+
     /// Perform a revolve extrusion with a certain angle.
     fn revolve_extrude(&self, angle: Angle, segments: usize) -> WithBounds3D<TriangleMesh> {
         let mut mesh = TriangleMesh::default();
@@ -116,6 +211,34 @@ pub trait Extrude {
         WithBounds3D::new(mesh, bounds)
     }
 
+    /// Sweep the geometry along a `path` with rotation-minimizing frames.
+    ///
+    /// The XY plane of the geometry is kept perpendicular to the path.
+    /// A `closed` path connects the last point with the first and generates no caps.
+    /// `twist` rotates the geometry along the path.
+    fn sweep(&self, path: &[Vec3], closed: bool, twist: Angle) -> WithBounds3D<TriangleMesh> {
+        let transforms = sweep_frames(path, closed, twist);
+        if transforms.len() < 2 {
+            return WithBounds3D::default();
+        }
+
+        let mut mesh = TriangleMesh::default();
+        transforms.windows(2).for_each(|m| {
+            mesh.append(&self.extrude_slice(&m[0], &m[1]));
+        });
+
+        if !closed {
+            let m_start = &transforms[0];
+            let m_end = transforms.last().expect("Transform");
+            mesh.append(&self.cap(m_start, true));
+            mesh.append(&self.cap(m_end, false));
+        }
+
+        let bounds = mesh.calc_bounds_3d();
+        mesh.repair(&bounds);
+        WithBounds3D::new(mesh, bounds)
+    }
+
     /// Perform a helix/spiral‐extrusion: rotate profile while translating upward,
     /// with varying radius from inner_radius to outer_radius and a given number of full turns.
     /// `height` = total vertical height of the helix.
@@ -284,6 +407,36 @@ impl Extrude for Polygon {
     }
 }
 
+/// Return the largest inset up to `max` which leaves a part of the profile.
+///
+/// The inset is approximated by bisection, so it is a bit smaller than the inradius of the profile.
+fn max_inset(profile: &MultiPolygon, max: Scalar) -> Scalar {
+    use geo::{
+        Area, Buffer,
+        buffer::{BufferStyle, LineJoin},
+    };
+
+    let remains = |inset: Scalar| {
+        profile
+            .buffer_with_style(BufferStyle::new(-inset).line_join(LineJoin::Miter(10.0)))
+            .unsigned_area()
+            > Scalar::EPSILON
+    };
+    if max <= 0.0 || remains(max) {
+        return max;
+    }
+
+    let (mut inside, mut outside) = (0.0, max);
+    for _ in 0..24 {
+        let inset = (inside + outside) * 0.5;
+        match remains(inset) {
+            true => inside = inset,
+            false => outside = inset,
+        }
+    }
+    inside
+}
+
 impl Extrude for MultiPolygon {
     fn extrude_slice(&self, m_a: &Mat4, m_b: &Mat4) -> TriangleMesh {
         let mut mesh = TriangleMesh::default();
@@ -293,6 +446,70 @@ impl Extrude for MultiPolygon {
         mesh
     }
 
+    /// The edges are built by lofting between profiles which are offset step by step.
+    fn linear_extrude_with_edges(
+        &self,
+        height: Length,
+        scale_x: Scalar,
+        scale_y: Scalar,
+        twist: Angle,
+        bottom: ExtrudeEdge,
+        top: ExtrudeEdge,
+    ) -> WithBounds3D<TriangleMesh> {
+        use geo::{
+            Buffer,
+            buffer::{BufferStyle, LineJoin},
+        };
+
+        if *height <= 0.0 {
+            return self.linear_extrude(height, scale_x, scale_y, twist);
+        }
+
+        let height = *height;
+        // Shrink the edges if they do not fit into the height.
+        let edges = bottom.size() + top.size();
+        let (bottom, top) = match edges > height && edges > 0.0 {
+            true => (bottom.scaled(height / edges), top.scaled(height / edges)),
+            false => (bottom, top),
+        };
+        // Shrink the edges if the profile vanishes before their full inset.
+        let inset = max_inset(self, bottom.size().max(top.size()));
+        let (bottom, top) = (bottom.clamped(inset), top.clamped(inset));
+
+        let mut levels = bottom.levels();
+        top.levels()
+            .into_iter()
+            .rev()
+            .map(|(offset, z)| (offset, height - z))
+            .for_each(|level| {
+                if levels
+                    .last()
+                    .is_none_or(|last| level.1 - last.1 > Scalar::EPSILON)
+                {
+                    levels.push(level);
+                }
+            });
+
+        let profiles = levels.into_iter().map(|(offset, z)| {
+            let f = z / height;
+            let profile = match offset.abs() > Scalar::EPSILON {
+                true => self
+                    .buffer_with_style(BufferStyle::new(offset).line_join(LineJoin::Miter(10.0))),
+                false => self.clone(),
+            };
+            let m = Mat4::from_angle_z(twist * f)
+                * Mat4::from_translation(Vec3::new(0.0, 0.0, z))
+                * Mat4::from_nonuniform_scale(
+                    1.0 + (scale_x - 1.0) * f,
+                    1.0 + (scale_y - 1.0) * f,
+                    1.0,
+                );
+            (profile, m)
+        });
+
+        Loft::new(profiles).mesh()
+    }
+
     fn cap(&self, m: &Mat4, flip: bool) -> TriangleMesh {
         let mut mesh = TriangleMesh::default();
         self.iter().for_each(|polygon| {
@@ -307,7 +524,39 @@ impl Extrude for Geometries2D {
         self.to_multi_polygon().extrude_slice(m_a, m_b)
     }
 
+    fn linear_extrude_with_edges(
+        &self,
+        height: Length,
+        scale_x: Scalar,
+        scale_y: Scalar,
+        twist: Angle,
+        bottom: ExtrudeEdge,
+        top: ExtrudeEdge,
+    ) -> WithBounds3D<TriangleMesh> {
+        self.to_multi_polygon()
+            .linear_extrude_with_edges(height, scale_x, scale_y, twist, bottom, top)
+    }
+
     fn cap(&self, m: &Mat4, flip: bool) -> TriangleMesh {
         self.to_multi_polygon().cap(m, flip)
     }
 }
+
+#[test]
+fn test_extrude_edges_clamped() {
+    let square = MultiPolygon::new(vec![Rect::new((0.0, 0.0), (2.0, 2.0)).to_polygon()]);
+    let mesh = square.linear_extrude_with_edges(
+        Length::mm(10.0),
+        1.0,
+        1.0,
+        cgmath::Rad(0.0),
+        ExtrudeEdge::Sharp,
+        ExtrudeEdge::Fillet {
+            radius: 1.5,
+            segments: 16,
+        },
+    );
+    assert!(!mesh.inner.is_empty());
+    assert!((mesh.bounds.max.z - 10.0).abs() < 1e-6);
+    assert!((mesh.bounds.max.x - 2.0).abs() < 1e-6);
+}
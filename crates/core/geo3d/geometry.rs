@@ -38,10 +38,11 @@ impl Geometry3D {
 
     /// Execute boolean operation.
     pub fn boolean_op(&self, other: &Geometry3D, op: &BooleanOp) -> Option<Self> {
-        let op: manifold_rs::BooleanOp = op.into();
         let a: Rc<Manifold> = self.clone().into();
         let b: Rc<Manifold> = other.clone().into();
-        Some(Geometry3D::Manifold(Rc::new(a.boolean_op(&b, op))))
+        Some(Geometry3D::Manifold(Rc::new(manifold_boolean_op(
+            &a, &b, op,
+        ))))
     }
 
     /// Calculate contex hull.
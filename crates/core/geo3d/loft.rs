@@ -0,0 +1,356 @@
+// Copyright © 2026 The µcad authors <info@microcad.xyz>
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+//! Loft between multiple 2D profiles.
+
+use cgmath::{InnerSpace, Transform};
+
+use crate::*;
+
+/// A loft builds a solid between an ordered list of 2D profiles.
+///
+/// Each profile is placed in 3D space with a matrix.
+/// Corresponding rings of neighboring profiles are resampled to the same number of points,
+/// so profiles with different vertex counts (e.g. a rectangle and a circle) can be connected.
+pub struct Loft {
+    profiles: Vec<(MultiPolygon, Mat4)>,
+}
+
+/// Rings of a single polygon: exterior first, followed by the interiors.
+type Rings = Vec<Vec<Vec2>>;
+
+impl Loft {
+    /// Create a new loft from profiles and their placement.
+    pub fn new(profiles: impl IntoIterator<Item = (MultiPolygon, Mat4)>) -> Self {
+        Self {
+            profiles: profiles.into_iter().collect(),
+        }
+    }
+
+    /// Create a new loft from profiles placed on top of each other at the given heights.
+    pub fn with_heights(profiles: impl IntoIterator<Item = (MultiPolygon, Scalar)>) -> Self {
+        Self::new(
+            profiles.into_iter().map(|(profile, height)| {
+                (profile, Mat4::from_translation(Vec3::unit_z() * height))
+            }),
+        )
+    }
+
+    /// Generate the watertight mesh of the loft.
+    ///
+    /// Empty profiles (e.g. a profile which has been buffered away) are skipped.
+    pub fn mesh(&self) -> WithBounds3D<TriangleMesh> {
+        let profiles: Vec<_> = self
+            .profiles
+            .iter()
+            .filter(|(profile, _)| !polygons(profile).is_empty())
+            .collect();
+        if profiles.len() < 2 {
+            return WithBounds3D::default();
+        }
+
+        let polygons = matching_polygons(&profiles);
+        let mut mesh = TriangleMesh::default();
+
+        for polygon in 0..polygons[0].len() {
+            let ring_count = polygons[0][polygon].len();
+
+            // Resample each ring to the maximum number of points of the ring in all profiles.
+            let mut resampled: Vec<Rings> = Vec::with_capacity(polygons.len());
+            for (p, profile) in polygons.iter().enumerate() {
+                let rings = (0..ring_count)
+                    .map(|ring| {
+                        let n = polygons
+                            .iter()
+                            .map(|profile| profile[polygon][ring].len())
+                            .max()
+                            .unwrap_or_default();
+                        let points = resample(&profile[polygon][ring], n);
+                        match p {
+                            0 => points,
+                            _ => align(&resampled[p - 1][ring], points),
+                        }
+                    })
+                    .collect();
+                resampled.push(rings);
+            }
+
+            // Walls between neighboring profiles.
+            resampled
+                .windows(2)
+                .zip(profiles.windows(2))
+                .for_each(|(rings, placement)| {
+                    rings[0].iter().zip(&rings[1]).for_each(|(a, b)| {
+                        mesh.append(&wall(a, &placement[0].1, b, &placement[1].1));
+                    });
+                });
+
+            // Caps at the first and last profile.
+            let cap = |rings: &Rings| {
+                let mut rings = rings.iter().map(|ring| {
+                    LineString::from(ring.iter().map(|p| (p.x, p.y)).collect::<Vec<_>>())
+                });
+                let exterior = rings.next().unwrap_or_else(|| LineString::new(vec![]));
+                Polygon::new(exterior, rings.collect())
+            };
+            let first = profiles.first().expect("Profile");
+            let last = profiles.last().expect("Profile");
+            mesh.append(&cap(resampled.first().expect("Rings")).cap(&first.1, true));
+            mesh.append(&cap(resampled.last().expect("Rings")).cap(&last.1, false));
+        }
+
+        let bounds = mesh.calc_bounds_3d();
+        mesh.repair(&bounds);
+        WithBounds3D::new(mesh, bounds)
+    }
+}
+
+/// Return the non-degenerated polygons of a profile, the largest first.
+fn polygons(profile: &MultiPolygon) -> Vec<Polygon> {
+    use geo::Area;
+
+    let mut polygons: Vec<_> = profile
+        .iter()
+        .filter(|polygon| polygon.exterior().0.len() > 3)
+        .cloned()
+        .collect();
+    polygons.sort_by(|a, b| b.unsigned_area().total_cmp(&a.unsigned_area()));
+    polygons
+}
+
+/// Return the oriented rings of the polygons of each profile.
+///
+/// All profiles get the same number of polygons with the same number of rings.
+/// If polygon or hole counts differ between profiles, only the largest polygons and their
+/// exteriors are used.
+/// Each profile must contain at least one polygon.
+fn matching_polygons(profiles: &[&(MultiPolygon, Mat4)]) -> Vec<Vec<Rings>> {
+    let profiles: Vec<_> = profiles.iter().map(|(p, _)| polygons(p)).collect();
+
+    let same_count = profiles.windows(2).all(|p| p[0].len() == p[1].len());
+    if !same_count {
+        log::warn!("Loft profiles have different numbers of polygons, using the largest ones.");
+    }
+    let count = match same_count {
+        true => profiles[0].len(),
+        false => 1,
+    };
+
+    let same_holes = (0..count).all(|i| {
+        profiles.windows(2).all(|p| {
+            p[0].get(i).map(|p| p.interiors().len()) == p[1].get(i).map(|p| p.interiors().len())
+        })
+    });
+    if !same_holes {
+        log::warn!("Loft profiles have different numbers of holes, ignoring holes.");
+    }
+
+    profiles
+        .iter()
+        .map(|polygons| {
+            polygons
+                .iter()
+                .take(count)
+                .map(|polygon| {
+                    let mut rings = vec![ring_points(polygon.exterior(), true)];
+                    if same_holes {
+                        rings.extend(
+                            polygon
+                                .interiors()
+                                .iter()
+                                .map(|interior| ring_points(interior, false)),
+                        );
+                    }
+                    rings
+                })
+                .collect()
+        })
+        .collect()
+}
+
+/// Return the points of a closed ring without the closing point in the given orientation.
+fn ring_points(ring: &LineString, ccw: bool) -> Vec<Vec2> {
+    let mut points: Vec<_> = ring.0.iter().map(|c| Vec2::new(c.x, c.y)).collect();
+    if points.len() > 1 && points.first() == points.last() {
+        points.pop();
+    }
+
+    // Shoelace formula, positive for counter-clockwise rings.
+    let area: Scalar = points
+        .iter()
+        .zip(points.iter().cycle().skip(1))
+        .map(|(a, b)| a.perp_dot(*b))
+        .sum();
+    if (area > 0.0) != ccw {
+        points.reverse();
+    }
+    points
+}
+
+/// Resample a ring to `n` points by subdividing its edges.
+///
+/// The original points are kept, so corners of a profile stay sharp.
+/// Additional points are distributed along the edges proportional to their length.
+fn resample(points: &[Vec2], n: usize) -> Vec<Vec2> {
+    let len = points.len();
+    if len == 0 || n <= len {
+        return points.to_vec();
+    }
+
+    let edges: Vec<_> = (0..len)
+        .map(|i| (points[(i + 1) % len] - points[i]).magnitude())
+        .collect();
+    let total: Scalar = edges.iter().sum();
+    if total <= 0.0 {
+        return points.to_vec();
+    }
+    let extra = n - len;
+
+    // Number of additional points for each edge (largest remainder method).
+    let shares: Vec<_> = edges.iter().map(|l| l / total * extra as Scalar).collect();
+    let mut counts: Vec<usize> = shares.iter().map(|s| s.floor() as usize).collect();
+    let mut remainders: Vec<_> = (0..len).collect();
+    remainders.sort_by(|a, b| {
+        (shares[*b] - shares[*b].floor()).total_cmp(&(shares[*a] - shares[*a].floor()))
+    });
+    let missing = extra - counts.iter().sum::<usize>();
+    remainders
+        .iter()
+        .take(missing)
+        .for_each(|i| counts[*i] += 1);
+
+    (0..len)
+        .flat_map(|i| {
+            let (a, b) = (points[i], points[(i + 1) % len]);
+            let count = counts[i];
+            (0..=count).map(move |j| a + (b - a) * (j as Scalar / (count + 1) as Scalar))
+        })
+        .collect()
+}
+
+/// Rotate the start point of `points` to best match the `reference` ring.
+///
+/// Both rings are compared relative to their centroids to avoid twisting between the profiles.
+fn align(reference: &[Vec2], points: Vec<Vec2>) -> Vec<Vec2> {
+    let n = points.len();
+    if n == 0 || reference.len() != n {
+        return points;
+    }
+
+    let centroid =
+        |ring: &[Vec2]| ring.iter().fold(Vec2::new(0.0, 0.0), |sum, p| sum + *p) / n as Scalar;
+    let (c_ref, c) = (centroid(reference), centroid(&points));
+
+    let offset = (0..n)
+        .min_by(|a, b| {
+            let distance = |offset: &usize| -> Scalar {
+                (0..n)
+                    .map(|i| ((points[(i + offset) % n] - c) - (reference[i] - c_ref)).magnitude2())
+                    .sum()
+            };
+            distance(a).total_cmp(&distance(b))
+        })
+        .unwrap_or_default();
+
+    points
+        .iter()
+        .cycle()
+        .skip(offset)
+        .take(n)
+        .copied()
+        .collect()
+}
+
+/// Generate the wall between two rings with the same number of points.
+fn wall(a: &[Vec2], m_a: &Mat4, b: &[Vec2], m_b: &Mat4) -> TriangleMesh {
+    let mut mesh = TriangleMesh::default();
+    let len = a.len();
+    if len < 2 || len != b.len() {
+        return mesh;
+    }
+
+    let transform = |p: &Vec2, m: &Mat4| -> cgmath::Vector3<f32> {
+        let p = m.transform_point(cgmath::Point3::new(p.x, p.y, 0.0));
+        cgmath::Vector3::new(p.x as f32, p.y as f32, p.z as f32)
+    };
+
+    // Interleave bottom and top vertex positions like `Extrude::extrude_slice`.
+    a.iter().zip(b).for_each(|(p_a, p_b)| {
+        mesh.positions.push(transform(p_a, m_a));
+        mesh.positions.push(transform(p_b, m_b));
+    });
+
+    for i in 0..len {
+        let next = (i + 1) % len;
+        let bl = (i * 2) as u32;
+        let br = (next * 2) as u32;
+        let tl = bl + 1;
+        let tr = br + 1;
+        mesh.triangle_indices.push(Triangle(bl, br, tr));
+        mesh.triangle_indices.push(Triangle(bl, tr, tl));
+    }
+
+    mesh
+}
+
+#[test]
+fn test_loft_resample() {
+    let square = [
+        Vec2::new(0.0, 0.0),
+        Vec2::new(1.0, 0.0),
+        Vec2::new(1.0, 1.0),
+        Vec2::new(0.0, 1.0),
+    ];
+    let points = resample(&square, 8);
+    assert_eq!(points.len(), 8);
+    // Corners are kept.
+    assert_eq!(points[0], square[0]);
+    assert_eq!(points[2], square[1]);
+}
+
+#[test]
+fn test_loft_square_to_circle() {
+    use geo::Area;
+
+    let square = MultiPolygon::new(vec![Rect::new((-1.0, -1.0), (1.0, 1.0)).to_polygon()]);
+    let circle = MultiPolygon::new(vec![Polygon::new(
+        LineString::from(
+            NgonIterator::new(32)
+                .map(|c| (c.x, c.y))
+                .collect::<Vec<_>>(),
+        ),
+        vec![],
+    )]);
+    assert!(square.unsigned_area() > circle.unsigned_area());
+
+    let mesh = Loft::with_heights([(square, 0.0), (circle, 2.0)]).mesh();
+    assert!(!mesh.inner.is_empty());
+    assert!((mesh.bounds.max.z - 2.0).abs() < 1e-6);
+
+    // Volume lies between a cylinder and a cube of the same height.
+    let volume = mesh.inner.volume();
+    assert!(volume > std::f64::consts::PI * 2.0 * 0.9 && volume < 8.0);
+}
+
+#[test]
+fn test_loft_empty_profile() {
+    let square = MultiPolygon::new(vec![Rect::new((-1.0, -1.0), (1.0, 1.0)).to_polygon()]);
+    let two_squares = MultiPolygon::new(vec![
+        Rect::new((-1.0, -1.0), (0.0, 1.0)).to_polygon(),
+        Rect::new((0.5, -1.0), (1.0, 1.0)).to_polygon(),
+    ]);
+
+    // Different polygon counts followed by an empty profile.
+    let mesh = Loft::with_heights([
+        (two_squares, 0.0),
+        (square.clone(), 1.0),
+        (MultiPolygon::new(vec![]), 2.0),
+    ])
+    .mesh();
+    assert!(!mesh.inner.is_empty());
+    assert!((mesh.bounds.max.z - 1.0).abs() < 1e-6);
+
+    // A single remaining profile results in an empty mesh.
+    let mesh = Loft::with_heights([(square, 0.0), (MultiPolygon::new(vec![]), 1.0)]).mesh();
+    assert!(mesh.inner.is_empty());
+}
@@ -0,0 +1,183 @@
+// Copyright © 2026 The µcad authors <info@microcad.xyz>
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+//! Minkowski sum of 3D geometries.
+
+use cgmath::Vector3;
+
+use crate::{traits::Minkowski, *};
+
+/// Maximum number of splits of the convex decomposition.
+const MAX_SPLIT_DEPTH: usize = 6;
+
+/// Return `true` if a mesh is convex, i.e. its volume equals the volume of its hull.
+fn is_convex(mesh: &TriangleMesh) -> bool {
+    let volume = mesh.volume();
+    let hull = TriangleMesh::from(mesh.to_manifold().hull()).volume();
+    (hull - volume).abs() <= 1e-6 * hull.max(1.0)
+}
+
+/// Axis aligned box from `min` to `max`.
+fn cuboid(min: Vec3, max: Vec3) -> Manifold {
+    let size = max - min;
+    let cube = TriangleMesh::from(Manifold::cube(size.x, size.y, size.z));
+    let offset = min - cube.calc_bounds_3d().min;
+    cube.transformed_3d(&Mat4::from_translation(offset))
+        .to_manifold()
+}
+
+/// Decompose a mesh into convex parts.
+///
+/// A non-convex mesh is cut in half across the longest side of its bounds until all parts are convex.
+/// Parts which are still not convex after [`MAX_SPLIT_DEPTH`] cuts (e.g. curved concave surfaces)
+/// are replaced by their hull, so the decomposition is approximate in that case.
+fn convex_parts(mesh: TriangleMesh, depth: usize) -> Vec<TriangleMesh> {
+    if mesh.is_empty() {
+        return Vec::new();
+    }
+    if is_convex(&mesh) {
+        return vec![mesh];
+    }
+    if depth == 0 {
+        return vec![TriangleMesh::from(mesh.to_manifold().hull())];
+    }
+
+    let bounds = mesh.calc_bounds_3d();
+    let size = bounds.max - bounds.min;
+    let axis = if size.x >= size.y && size.x >= size.z {
+        0
+    } else if size.y >= size.z {
+        1
+    } else {
+        2
+    };
+
+    // Both halves exceed the bounds, so no cut face is coplanar with a face of the mesh.
+    let margin = Vec3::new(1.0, 1.0, 1.0) * size[axis];
+    let (min, max) = (bounds.min - margin, bounds.max + margin);
+    let center = (bounds.min[axis] + bounds.max[axis]) / 2.0;
+    let (mut lower_max, mut upper_min) = (max, min);
+    lower_max[axis] = center;
+    upper_min[axis] = center;
+
+    let manifold = mesh.to_manifold();
+    [cuboid(min, lower_max), cuboid(upper_min, max)]
+        .iter()
+        .flat_map(|half| {
+            convex_parts(
+                TriangleMesh::from(manifold_boolean_op(&manifold, half, &BooleanOp::Intersect)),
+                depth - 1,
+            )
+        })
+        .collect()
+}
+
+/// Translate a mesh by a vector.
+fn translated(mesh: &TriangleMesh, v: &Vector3<f32>) -> TriangleMesh {
+    mesh.transformed_3d(&Mat4::from_translation(v.cast().expect("Successful cast")))
+}
+
+/// Union of all manifolds.
+///
+/// Manifolds are united pairwise, so the operands of each union stay small.
+fn union_all(mut manifolds: Vec<Manifold>) -> Manifold {
+    while manifolds.len() > 1 {
+        let mut pairs = manifolds.into_iter();
+        let mut united = Vec::with_capacity(pairs.len().div_ceil(2));
+        while let Some(a) = pairs.next() {
+            united.push(match pairs.next() {
+                Some(b) => a.boolean_op(&b, manifold_rs::BooleanOp::Union),
+                None => a,
+            });
+        }
+        manifolds = united;
+    }
+    manifolds.pop().unwrap_or_else(Manifold::empty)
+}
+
+/// Minkowski sum of any mesh and a convex mesh.
+///
+/// The sum is built from the convex hulls of each triangle of `mesh` moved along `convex`,
+/// together with `mesh` moved into `convex`, which fills the inside.
+fn minkowski_convex(mesh: &TriangleMesh, convex: &TriangleMesh) -> Manifold {
+    let Some(origin) = convex.positions.first() else {
+        return Manifold::empty();
+    };
+
+    let mut manifolds: Vec<_> = mesh
+        .fetch_triangles()
+        .iter()
+        .map(|t| {
+            let mut hull = translated(convex, &t.0);
+            hull.append(&translated(convex, &t.1));
+            hull.append(&translated(convex, &t.2));
+            hull.to_manifold().hull()
+        })
+        .collect();
+    manifolds.push(translated(mesh, origin).to_manifold());
+    union_all(manifolds)
+}
+
+impl Minkowski<Manifold> for TriangleMesh {
+    /// If both meshes are not convex, the mesh with fewer triangles is decomposed into convex parts
+    /// and the sums of the other mesh with each part are united.
+    fn minkowski(&self, other: &Self) -> Manifold {
+        if other.positions.is_empty() || self.positions.is_empty() {
+            return Manifold::empty();
+        }
+        if is_convex(other) {
+            minkowski_convex(self, other)
+        } else if is_convex(self) {
+            minkowski_convex(other, self)
+        } else {
+            let (mesh, decomposed) = if self.triangle_indices.len() < other.triangle_indices.len() {
+                (other, self)
+            } else {
+                (self, other)
+            };
+            union_all(
+                convex_parts(decomposed.clone(), MAX_SPLIT_DEPTH)
+                    .iter()
+                    .map(|part| minkowski_convex(mesh, part))
+                    .collect(),
+            )
+        }
+    }
+}
+
+#[test]
+fn test_minkowski_cubes() {
+    let cube = |size: f64| TriangleMesh::from(Manifold::cube(size, size, size));
+
+    // Minkowski sum of two cubes is a cube with the sum of both sizes.
+    let sum = TriangleMesh::from(cube(10.0).minkowski(&cube(2.0)));
+    assert!((sum.volume() - 1728.0).abs() < 1e-2);
+}
+
+#[test]
+fn test_minkowski_non_convex() {
+    let cube = |size: f64| TriangleMesh::from(Manifold::cube(size, size, size));
+
+    // An L-shaped mesh of three cubes.
+    let l_shape = |size: f64| {
+        let moved = |x: f64, y: f64| {
+            cube(size)
+                .transformed_3d(&Mat4::from_translation(Vec3::new(x, y, 0.0)))
+                .to_manifold()
+        };
+        let union = |a: Manifold, b: Manifold| manifold_boolean_op(&a, &b, &BooleanOp::Union);
+        TriangleMesh::from(union(
+            union(moved(0.0, 0.0), moved(size, 0.0)),
+            moved(0.0, size),
+        ))
+    };
+
+    // The sum of an L-shape and a cube grows by the cube size in each direction.
+    let sum = TriangleMesh::from(l_shape(2.0).minkowski(&cube(1.0)));
+    assert!((sum.volume() - 21.0 * 3.0).abs() < 1e-2);
+
+    // Both operands are not convex: the sum of two L-shapes is a staircase
+    // with a base area of 6·3 + 5·1 + 4·1 + 3·1 and a height of 3.
+    let sum = TriangleMesh::from(l_shape(2.0).minkowski(&l_shape(1.0)));
+    assert!((sum.volume() - 30.0 * 3.0).abs() < 1e-2);
+}
@@ -8,9 +8,14 @@ mod bounds;
 mod collection;
 mod extrude;
 mod geometry;
+mod loft;
 mod mesh;
+mod minkowski;
 mod plane;
+mod primitives;
 mod reflect;
+mod section;
+mod sweep;
 mod triangle;
 mod vertex;
 
@@ -19,22 +24,32 @@ pub use bounds::*;
 pub use collection::*;
 pub use extrude::*;
 pub use geometry::*;
+pub use loft::*;
 pub use manifold_rs::Manifold;
 pub use mesh::TriangleMesh;
 pub use plane::Plane;
+pub use primitives::*;
 pub use reflect::*;
+pub use section::*;
+pub use sweep::*;
 pub use vertex::Vertex;
 
 use crate::BooleanOp;
 
-impl From<&BooleanOp> for manifold_rs::BooleanOp {
-    fn from(op: &BooleanOp) -> Self {
-        match op {
-            BooleanOp::Union => manifold_rs::BooleanOp::Union,
-            BooleanOp::Intersect => manifold_rs::BooleanOp::Intersection,
-            BooleanOp::Subtract => manifold_rs::BooleanOp::Difference,
-            _ => unimplemented!(),
-        }
+/// Apply a boolean operation on two manifolds.
+///
+/// Manifold has no symmetric difference, so `Xor` is computed as `(P ∖ Q) ∪ (Q ∖ P)`.
+pub fn manifold_boolean_op(lhs: &Manifold, rhs: &Manifold, op: &BooleanOp) -> Manifold {
+    match op {
+        BooleanOp::Union => lhs.boolean_op(rhs, manifold_rs::BooleanOp::Union),
+        BooleanOp::Intersect => lhs.boolean_op(rhs, manifold_rs::BooleanOp::Intersection),
+        BooleanOp::Subtract => lhs.boolean_op(rhs, manifold_rs::BooleanOp::Difference),
+        BooleanOp::Xor => lhs
+            .boolean_op(rhs, manifold_rs::BooleanOp::Difference)
+            .boolean_op(
+                &rhs.boolean_op(lhs, manifold_rs::BooleanOp::Difference),
+                manifold_rs::BooleanOp::Union,
+            ),
     }
 }
 
@@ -46,3 +61,17 @@ fn test_mesh_volume() {
     let volume = mesh.volume();
     assert!((volume - 4.0 / 3.0 * std::f64::consts::PI).abs() < 1e-3);
 }
+
+#[test]
+fn test_manifold_xor() {
+    use crate::{Mat4, Vec3};
+
+    let a = Manifold::cube(2.0, 2.0, 2.0);
+    let b = TriangleMesh::from(Manifold::cube(2.0, 2.0, 2.0))
+        .transformed_3d(&Mat4::from_translation(Vec3::new(1.0, 0.0, 0.0)))
+        .to_manifold();
+
+    // Two cubes of volume 8 overlapping by 4.
+    let xor = TriangleMesh::from(manifold_boolean_op(&a, &b, &BooleanOp::Xor).to_mesh());
+    assert!((xor.volume() - 8.0).abs() < 1e-3);
+}
@@ -3,12 +3,45 @@
 
 //! 3D plane geometry.
 
-use crate::Vec3;
+use cgmath::InnerSpace;
+
+use crate::{Vec2, Vec3};
 
 /// A 3D plane with normal and point.
+#[derive(Clone, Debug)]
 pub struct Plane {
     /// Plane origin point.
     pub p: Vec3,
     /// Plane normal.
     pub n: Vec3,
 }
+
+impl Plane {
+    /// Signed distance of a point to the plane, positive on the side the normal points to.
+    pub fn distance(&self, v: Vec3) -> f64 {
+        (v - self.p).dot(self.n.normalize())
+    }
+
+    /// Return the axes `(u, v)` of the 2D coordinate system within the plane.
+    ///
+    /// The coordinate system is seen from the side the normal points to:
+    /// * Normal `Z` results in `(X, Y)`.
+    /// * Normal `-Y` results in `(X, Z)`.
+    /// * Normal `X` results in `(Y, Z)`.
+    pub fn axes(&self) -> (Vec3, Vec3) {
+        let n = self.n.normalize();
+        let u = if n.x.abs() < f64::EPSILON && n.y.abs() < f64::EPSILON {
+            Vec3::unit_x()
+        } else {
+            Vec3::unit_z().cross(n).normalize()
+        };
+        (u, n.cross(u))
+    }
+
+    /// Project a point into the 2D coordinate system of the plane.
+    pub fn project(&self, v: Vec3) -> Vec2 {
+        let (a, b) = self.axes();
+        let d = v - self.p;
+        Vec2::new(d.dot(a), d.dot(b))
+    }
+}
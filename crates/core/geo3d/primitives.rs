@@ -0,0 +1,219 @@
+// Copyright © 2026 The µcad authors <info@microcad.xyz>
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+//! 3D primitives
+
+use cgmath::Vector3;
+use thiserror::Error;
+
+use crate::{hash::HashMap, *};
+
+/// Error of an invalid polyhedron.
+#[derive(Debug, Error, PartialEq)]
+pub enum PolyhedronError {
+    /// A face refers to a point which does not exist.
+    #[error("Face {face} refers to point {index}, but there are only {count} points")]
+    IndexOutOfRange {
+        /// Index of the face.
+        face: usize,
+        /// Invalid point index.
+        index: usize,
+        /// Number of points.
+        count: usize,
+    },
+
+    /// A face has less than three distinct points.
+    #[error("Face {0} needs at least three distinct points")]
+    DegenerateFace(usize),
+
+    /// An edge belongs to a single face only.
+    #[error("Polyhedron is not closed: edge ({0}, {1}) belongs to a single face")]
+    OpenEdge(usize, usize),
+
+    /// An edge is used twice in the same direction.
+    #[error(
+        "Faces are not consistently oriented or edge ({0}, {1}) is shared by more than two faces"
+    )]
+    InconsistentEdge(usize, usize),
+
+    /// The polyhedron does not enclose any volume.
+    #[error("Polyhedron has no volume")]
+    NoVolume,
+}
+
+impl TriangleMesh {
+    /// Create a cone frustum with its base at `z = 0`.
+    ///
+    /// If `radius_top` is zero, the cone ends in a single apex.
+    pub fn cone(radius_bottom: Scalar, radius_top: Scalar, height: Scalar, segments: u32) -> Self {
+        let n = segments.max(3);
+        let ring = |radius: Scalar, z: Scalar| {
+            (0..n).map(move |i| {
+                let angle = consts::TAU * i as Scalar / n as Scalar;
+                Vector3::new(
+                    (radius * angle.cos()) as f32,
+                    (radius * angle.sin()) as f32,
+                    z as f32,
+                )
+            })
+        };
+
+        // Bottom center, bottom ring, then either an apex or top center and top ring.
+        let mut positions = vec![Vector3::new(0.0, 0.0, 0.0)];
+        positions.extend(ring(radius_bottom, 0.0));
+        positions.push(Vector3::new(0.0, 0.0, height as f32));
+        let apex = radius_top <= 0.0;
+        if !apex {
+            positions.extend(ring(radius_top, height));
+        }
+
+        let (bottom_center, top_center) = (0, n + 1);
+        let b = |i: u32| 1 + i % n;
+        let t = |i: u32| n + 2 + i % n;
+
+        let mut triangle_indices = Vec::new();
+        (0..n).for_each(|i| {
+            triangle_indices.push(Triangle(bottom_center, b(i + 1), b(i)));
+            match apex {
+                true => triangle_indices.push(Triangle(b(i), b(i + 1), top_center)),
+                false => {
+                    triangle_indices.push(Triangle(b(i), b(i + 1), t(i + 1)));
+                    triangle_indices.push(Triangle(b(i), t(i + 1), t(i)));
+                    triangle_indices.push(Triangle(top_center, t(i), t(i + 1)));
+                }
+            }
+        });
+
+        Self {
+            positions,
+            normals: None,
+            triangle_indices,
+        }
+    }
+
+    /// Create a polyhedron from points and faces.
+    ///
+    /// Each face is a list of point indices and is triangulated as a fan from its first point,
+    /// so faces should be planar and convex.
+    /// The faces must form a closed surface in which each edge is shared by exactly two faces.
+    /// Faces may be ordered either way, as long as all faces are ordered consistently.
+    pub fn polyhedron(points: &[Vec3], faces: &[Vec<usize>]) -> Result<Self, PolyhedronError> {
+        let mut triangle_indices = Vec::new();
+        let mut edges: HashMap<(usize, usize), usize> = HashMap::default();
+
+        for (face_index, face) in faces.iter().enumerate() {
+            if let Some(index) = face.iter().find(|index| **index >= points.len()) {
+                return Err(PolyhedronError::IndexOutOfRange {
+                    face: face_index,
+                    index: *index,
+                    count: points.len(),
+                });
+            }
+
+            // Ignore repeated points, e.g. a closing point which equals the first one.
+            let mut face = face.clone();
+            face.dedup();
+            if face.len() > 1 && face.first() == face.last() {
+                face.pop();
+            }
+            if face.len() < 3 {
+                return Err(PolyhedronError::DegenerateFace(face_index));
+            }
+
+            (0..face.len()).for_each(|i| {
+                *edges
+                    .entry((face[i], face[(i + 1) % face.len()]))
+                    .or_default() += 1;
+            });
+            (1..face.len() - 1).for_each(|i| {
+                triangle_indices.push(Triangle(face[0] as u32, face[i] as u32, face[i + 1] as u32))
+            });
+        }
+
+        for (&(a, b), &count) in &edges {
+            if count > 1 {
+                return Err(PolyhedronError::InconsistentEdge(a, b));
+            }
+            if !edges.contains_key(&(b, a)) {
+                return Err(PolyhedronError::OpenEdge(a, b));
+            }
+        }
+
+        let mut mesh = Self {
+            positions: points
+                .iter()
+                .map(|p| Vector3::new(p.x as f32, p.y as f32, p.z as f32))
+                .collect(),
+            normals: None,
+            triangle_indices,
+        };
+
+        // Faces ordered clockwise from outside result in a negative volume.
+        let volume: f32 = mesh.triangles().map(|t| t.signed_volume()).sum();
+        if volume.abs() <= f32::EPSILON {
+            return Err(PolyhedronError::NoVolume);
+        }
+        if volume < 0.0 {
+            mesh.triangle_indices = mesh.triangle_indices.iter().map(|t| t.flipped()).collect();
+        }
+
+        Ok(mesh)
+    }
+}
+
+/// Create the convex hull of spheres with the same `radius` placed at `centers`.
+pub fn hull_of_spheres(centers: &[Vec3], radius: Scalar, segments: u32) -> Manifold {
+    let sphere = TriangleMesh::from(Manifold::sphere(radius, segments));
+    centers
+        .iter()
+        .fold(TriangleMesh::default(), |mut mesh, center| {
+            mesh.append(&sphere.transformed_3d(&Mat4::from_translation(*center)));
+            mesh
+        })
+        .to_manifold()
+        .hull()
+}
+
+#[test]
+fn test_cone() {
+    let cylinder = TriangleMesh::cone(1.0, 1.0, 1.0, 512);
+    assert!((cylinder.volume() - std::f64::consts::PI).abs() < 1e-3);
+
+    let cone = TriangleMesh::cone(1.0, 0.0, 3.0, 512);
+    assert!((cone.volume() - std::f64::consts::PI).abs() < 1e-3);
+}
+
+#[test]
+fn test_polyhedron() {
+    let points = [
+        Vec3::new(0.0, 0.0, 0.0),
+        Vec3::new(1.0, 0.0, 0.0),
+        Vec3::new(0.0, 1.0, 0.0),
+        Vec3::new(0.0, 0.0, 1.0),
+    ];
+    let faces = vec![vec![0, 2, 1], vec![0, 1, 3], vec![1, 2, 3], vec![0, 3, 2]];
+    let tetrahedron = TriangleMesh::polyhedron(&points, &faces).expect("valid polyhedron");
+    assert!((tetrahedron.volume() - 1.0 / 6.0).abs() < 1e-6);
+
+    // Clockwise faces are flipped.
+    let reversed: Vec<_> = faces
+        .iter()
+        .map(|face| face.iter().rev().copied().collect())
+        .collect();
+    let tetrahedron = TriangleMesh::polyhedron(&points, &reversed).expect("valid polyhedron");
+    let volume: f32 = tetrahedron.triangles().map(|t| t.signed_volume()).sum();
+    assert!(volume > 0.0);
+
+    assert!(matches!(
+        TriangleMesh::polyhedron(&points, &faces[..3]),
+        Err(PolyhedronError::OpenEdge(..))
+    ));
+    assert_eq!(
+        TriangleMesh::polyhedron(&points, &[vec![0, 1, 4]]).err(),
+        Some(PolyhedronError::IndexOutOfRange {
+            face: 0,
+            index: 4,
+            count: 4
+        })
+    );
+}
@@ -0,0 +1,190 @@
+// Copyright © 2026 The µcad authors <info@microcad.xyz>
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+//! Planar sections and projections of 3D geometries.
+//!
+//! Both operations return 2D geometries in the coordinate system of the plane (see [`Plane::axes`]).
+
+use cgmath::Vector3;
+use geo::BooleanOps;
+
+use crate::{hash::HashMap, *};
+
+/// Convert a 3D geometry into a 2D geometry by a plane.
+pub trait Section {
+    /// Cut the geometry with a plane and return the cross-section.
+    fn section(&self, plane: &Plane) -> MultiPolygon;
+
+    /// Project the geometry onto a plane and return its silhouette.
+    fn project(&self, plane: &Plane) -> MultiPolygon;
+}
+
+/// Points closer than this are considered equal when section segments are joined into rings.
+const SECTION_PRECISION: Scalar = 1e-6;
+
+fn to_vec3(v: &Vector3<f32>) -> Vec3 {
+    Vec3::new(v.x as Scalar, v.y as Scalar, v.z as Scalar)
+}
+
+fn point_key(p: &Vec2) -> (i64, i64) {
+    (
+        (p.x / SECTION_PRECISION).round() as i64,
+        (p.y / SECTION_PRECISION).round() as i64,
+    )
+}
+
+/// Join unordered segments into closed rings.
+///
+/// Segments which cannot be closed to a ring are dropped.
+fn join_segments(segments: &[(Vec2, Vec2)]) -> Vec<LineString> {
+    let mut ends: HashMap<(i64, i64), Vec<usize>> = HashMap::default();
+    segments.iter().enumerate().for_each(|(i, (a, b))| {
+        ends.entry(point_key(a)).or_default().push(i);
+        ends.entry(point_key(b)).or_default().push(i);
+    });
+
+    let mut used = vec![false; segments.len()];
+    let mut rings = Vec::new();
+
+    for start in 0..segments.len() {
+        if used[start] {
+            continue;
+        }
+        used[start] = true;
+
+        let (first, mut last) = segments[start];
+        let first_key = point_key(&first);
+        let mut ring = vec![first, last];
+
+        let closed = loop {
+            let key = point_key(&last);
+            if key == first_key {
+                break true;
+            }
+            let next = ends
+                .get(&key)
+                .and_then(|candidates| candidates.iter().find(|i| !used[**i]).copied());
+            match next {
+                Some(next) => {
+                    used[next] = true;
+                    let (a, b) = segments[next];
+                    last = if point_key(&a) == key { b } else { a };
+                    ring.push(last);
+                }
+                None => break false,
+            }
+        };
+
+        // A closed ring has at least three distinct points.
+        if closed && ring.len() > 3 {
+            rings.push(LineString::from(
+                ring.iter().map(|p| (p.x, p.y)).collect::<Vec<_>>(),
+            ));
+        }
+    }
+
+    rings
+}
+
+impl Section for TriangleMesh {
+    fn section(&self, plane: &Plane) -> MultiPolygon {
+        let distances: Vec<_> = self
+            .positions
+            .iter()
+            .map(|v| plane.distance(to_vec3(v)))
+            .collect();
+
+        // Intersection of the plane with the edge between the vertices `i` and `j`.
+        let intersect = |i: u32, j: u32| {
+            let (i, j) = (i as usize, j as usize);
+            let (p, q) = (to_vec3(&self.positions[i]), to_vec3(&self.positions[j]));
+            let t = distances[i] / (distances[i] - distances[j]);
+            plane.project(p + (q - p) * t)
+        };
+
+        let segments: Vec<_> = self
+            .triangle_indices
+            .iter()
+            .filter_map(|t| {
+                let [a, b, c] = [t.0, t.1, t.2].map(|i| distances[i as usize] >= 0.0);
+                let crossing: Vec<_> = [(t.0, t.1, a != b), (t.1, t.2, b != c), (t.2, t.0, c != a)]
+                    .into_iter()
+                    .filter(|(_, _, crosses)| *crosses)
+                    .map(|(i, j, _)| intersect(i, j))
+                    .collect();
+                match crossing.as_slice() {
+                    [p, q] if point_key(p) != point_key(q) => Some((*p, *q)),
+                    _ => None,
+                }
+            })
+            .collect();
+
+        // Holes are rings inside of other rings, hence combining the rings with XOR
+        // results in the correct polygons with holes.
+        join_segments(&segments)
+            .into_iter()
+            .fold(MultiPolygon::empty(), |acc, ring| {
+                acc.xor(&MultiPolygon::new(vec![Polygon::new(ring, vec![])]))
+            })
+    }
+
+    fn project(&self, plane: &Plane) -> MultiPolygon {
+        let triangles: Vec<_> = self
+            .fetch_triangles()
+            .iter()
+            .filter_map(|t| {
+                let [a, b, c] = [t.0, t.1, t.2].map(|v| plane.project(to_vec3(&v)));
+                let area = (b - a).perp_dot(c - a);
+                if area.abs() < SECTION_PRECISION * SECTION_PRECISION {
+                    return None;
+                }
+                // Union requires counter-clockwise exteriors.
+                let points = if area > 0.0 { [a, b, c] } else { [a, c, b] };
+                Some(Polygon::new(
+                    LineString::from(points.iter().map(|p| (p.x, p.y)).collect::<Vec<_>>()),
+                    vec![],
+                ))
+            })
+            .collect();
+
+        geo::unary_union(&triangles)
+    }
+}
+
+impl Section for Geometry3D {
+    fn section(&self, plane: &Plane) -> MultiPolygon {
+        TriangleMesh::from(self).section(plane)
+    }
+
+    fn project(&self, plane: &Plane) -> MultiPolygon {
+        TriangleMesh::from(self).project(plane)
+    }
+}
+
+impl Section for Geometries3D {
+    fn section(&self, plane: &Plane) -> MultiPolygon {
+        TriangleMesh::from(self).section(plane)
+    }
+
+    fn project(&self, plane: &Plane) -> MultiPolygon {
+        TriangleMesh::from(self).project(plane)
+    }
+}
+
+#[test]
+fn test_cube_section() {
+    use geo::Area;
+
+    let cube: TriangleMesh = Manifold::cube(10.0, 20.0, 30.0).into();
+    let section = cube.section(&Plane {
+        p: Vec3::new(0.0, 0.0, 15.0),
+        n: Vec3::unit_z(),
+    });
+    assert!((section.unsigned_area() - 200.0).abs() < 1e-6);
+
+    let silhouette = cube.project(&Plane {
+        p: Vec3::new(0.0, 0.0, 0.0),
+        n: -Vec3::unit_y(),
+    });
+    assert!((silhouette.unsigned_area() - 300.0).abs() < 1e-6);
+}
@@ -0,0 +1,149 @@
+// Copyright © 2026 The µcad authors <info@microcad.xyz>
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+//! Frames along a sweep path.
+
+use cgmath::{InnerSpace, Vector4};
+
+use crate::*;
+
+/// Calculate rotation-minimizing frames along a path.
+///
+/// The frames are computed with the double reflection method (Wang et al. 2008) and returned as
+/// matrices which map the XY plane of a profile onto the plane perpendicular to the path.
+/// The Y axis of the first profile points upwards (+Z) if possible.
+///
+/// For a `closed` path, an additional frame at the start point is returned and the remaining
+/// rotation between last and first frame is distributed along the path.
+/// `twist` rotates the profile proportionally to the path length.
+pub fn sweep_frames(path: &[Vec3], closed: bool, twist: Angle) -> Vec<Mat4> {
+    // Remove consecutive duplicates, they have no tangent.
+    let mut points: Vec<Vec3> = Vec::with_capacity(path.len() + 1);
+    path.iter().for_each(|p| {
+        if points
+            .last()
+            .is_none_or(|last| (*p - *last).magnitude2() > Scalar::EPSILON)
+        {
+            points.push(*p);
+        }
+    });
+    if closed
+        && points.len() > 2
+        && (points[0] - points[points.len() - 1]).magnitude2() <= Scalar::EPSILON
+    {
+        points.pop();
+    }
+    if points.len() < 2 {
+        return Vec::new();
+    }
+
+    let n = points.len();
+    if closed {
+        points.push(points[0]);
+    }
+
+    // Tangents are the bisectors of neighboring segments.
+    let segment = |i: usize| (points[i + 1] - points[i]).normalize();
+    let tangents: Vec<Vec3> = (0..points.len())
+        .map(|i| {
+            let last = points.len() - 1;
+            match (i, closed) {
+                (0, false) => segment(0),
+                (i, false) if i == last => segment(last - 1),
+                (0, true) => (segment(last - 1) + segment(0)).normalize(),
+                (i, true) if i == last => (segment(last - 1) + segment(0)).normalize(),
+                (i, _) => (segment(i - 1) + segment(i)).normalize(),
+            }
+        })
+        .collect();
+
+    // Initial frame with the profile's Y axis pointing upwards.
+    let t0 = tangents[0];
+    let up = match t0.z.abs() > 1.0 - 1e-9 {
+        true => Vec3::unit_y(),
+        false => Vec3::unit_z(),
+    };
+    let s0 = (up - t0 * up.dot(t0)).normalize();
+    let mut normals = vec![s0.cross(t0)];
+
+    // Double reflection.
+    for i in 0..points.len() - 1 {
+        let r = normals[i];
+        let v1 = points[i + 1] - points[i];
+        let c1 = v1.dot(v1);
+        let r_l = r - v1 * (2.0 / c1 * v1.dot(r));
+        let t_l = tangents[i] - v1 * (2.0 / c1 * v1.dot(tangents[i]));
+        let v2 = tangents[i + 1] - t_l;
+        let c2 = v2.dot(v2);
+        normals.push(match c2 > Scalar::EPSILON {
+            true => r_l - v2 * (2.0 / c2 * v2.dot(r_l)),
+            false => r_l,
+        });
+    }
+
+    // Accumulated path length at each point.
+    let lengths: Vec<Scalar> = points
+        .windows(2)
+        .scan(0.0, |length, p| {
+            *length += (p[1] - p[0]).magnitude();
+            Some(*length)
+        })
+        .fold(vec![0.0], |mut lengths, length| {
+            lengths.push(length);
+            lengths
+        });
+    let total = lengths.last().copied().unwrap_or_default();
+
+    // Rotation which is needed to close the path without a seam.
+    let correction = match closed {
+        true => {
+            let (r_end, r_start) = (normals[n], normals[0]);
+            r_end
+                .cross(r_start)
+                .dot(tangents[0])
+                .atan2(r_end.dot(r_start))
+        }
+        false => 0.0,
+    };
+
+    points
+        .iter()
+        .zip(tangents.iter().zip(&normals))
+        .zip(&lengths)
+        .map(|((p, (t, r)), length)| {
+            let f = match total > 0.0 {
+                true => length / total,
+                false => 0.0,
+            };
+            let angle = correction * f + twist.0 * f;
+            let (t, r) = (*t, *r);
+            let s = t.cross(r);
+            let (sin, cos) = angle.sin_cos();
+            let (r, s) = (r * cos + s * sin, s * cos - r * sin);
+            Mat4::from_cols(
+                Vector4::new(r.x, r.y, r.z, 0.0),
+                Vector4::new(s.x, s.y, s.z, 0.0),
+                Vector4::new(t.x, t.y, t.z, 0.0),
+                Vector4::new(p.x, p.y, p.z, 1.0),
+            )
+        })
+        .collect()
+}
+
+#[test]
+fn test_sweep_frames_straight() {
+    use cgmath::Transform;
+
+    let frames = sweep_frames(
+        &[Vec3::new(0.0, 0.0, 0.0), Vec3::new(10.0, 0.0, 0.0)],
+        false,
+        Angle::from(cgmath::Deg(0.0)),
+    );
+    assert_eq!(frames.len(), 2);
+
+    // Profile Y axis points upwards, profile Z axis along the path.
+    let y = frames[0].transform_vector(Vec3::unit_y());
+    let z = frames[1].transform_vector(Vec3::unit_z());
+    assert!((y - Vec3::unit_z()).magnitude() < 1e-9);
+    assert!((z - Vec3::unit_x()).magnitude() < 1e-9);
+}
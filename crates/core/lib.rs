@@ -8,6 +8,7 @@ mod boolean_op;
 pub mod bounds;
 pub mod color;
 pub mod core_error;
+pub mod deviation;
 pub mod geo2d;
 pub mod geo3d;
 pub mod hash;
@@ -15,7 +16,10 @@ pub mod length;
 pub mod render;
 pub mod traits;
 pub mod triangle;
+pub mod validation;
 
+/// Primitive boolean type.
+pub type Bool = bool;
 /// Primitive integer type.
 pub type Integer = i64;
 /// Primitive floating point type.
@@ -47,10 +51,12 @@ pub use boolean_op::BooleanOp;
 pub use bounds::*;
 pub use color::*;
 pub use core_error::*;
+pub use deviation::*;
 pub use geo2d::*;
 pub use geo3d::*;
 pub use render::*;
 pub use triangle::*;
+pub use validation::*;
 
 /// Convert a Matrix4 to Matrix3.
 pub fn mat4_to_mat3(m: &Mat4) -> Mat3 {
@@ -17,6 +17,12 @@ pub trait DistributeGrid<T = Self> {
     fn distribute_grid(&self, rect: Rect, rows: Integer, columns: Integer) -> T;
 }
 
+/// Trait to calculate the Minkowski sum of two geometries.
+pub trait Minkowski<T = Self> {
+    /// Minkowski sum of `self` and `other`.
+    fn minkowski(&self, other: &Self) -> T;
+}
+
 /// Return total amount of memory in bytes.
 pub trait TotalMemory {
     /// Total amount of memory in bytes.
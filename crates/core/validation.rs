@@ -0,0 +1,537 @@
+// Copyright © 2026 The µcad authors <info@microcad.xyz>
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+//! Printability validation of geometries.
+
+use cgmath::InnerSpace;
+use geo::{Area, Centroid};
+use thiserror::Error;
+
+use crate::{hash::HashMap, *};
+
+/// Points closer than this are considered equal.
+const VALIDATION_PRECISION: Scalar = 1e-6;
+
+/// Maximum number of sections per axis to find small holes in 3D geometries.
+const MAX_SECTIONS: usize = 32;
+
+/// Parameters for geometry validation.
+#[derive(Debug, Clone, Default)]
+pub struct ValidationParameters {
+    /// Report walls thinner than this thickness (3D only).
+    pub min_thickness: Option<Scalar>,
+    /// Report holes with a diameter smaller than this.
+    pub min_hole: Option<Scalar>,
+}
+
+/// Printability issue of a geometry.
+#[derive(Debug, Clone, PartialEq, Error)]
+pub enum GeometryIssue {
+    /// Edges which are not shared by exactly two triangles.
+    #[error("{count} non-manifold edge(s), e.g. at {}", fmt_point(.at))]
+    NonManifoldEdges {
+        /// Number of edges.
+        count: usize,
+        /// Center of the first edge.
+        at: Vec3,
+    },
+
+    /// Triangles without area.
+    #[error("{count} degenerate triangle(s), e.g. at {}", fmt_point(.at))]
+    DegenerateTriangles {
+        /// Number of triangles.
+        count: usize,
+        /// Center of the first triangle.
+        at: Vec3,
+    },
+
+    /// Pairs of intersecting triangles.
+    #[error("{count} self-intersection(s), e.g. at {}", fmt_point(.at))]
+    SelfIntersections {
+        /// Number of intersecting triangle pairs.
+        count: usize,
+        /// First intersection point.
+        at: Vec3,
+    },
+
+    /// Geometry consists of several disconnected shells.
+    #[error("{count} disconnected shells")]
+    DisconnectedShells {
+        /// Number of shells.
+        count: usize,
+    },
+
+    /// Walls thinner than the minimum thickness.
+    #[error("{count} triangle(s) with wall thickness below minimum, thinnest wall is {thickness}mm at {}", fmt_point(.at))]
+    ThinWalls {
+        /// Number of triangles at thin walls.
+        count: usize,
+        /// Thickness of the thinnest wall.
+        thickness: Scalar,
+        /// Position of the thinnest wall.
+        at: Vec3,
+    },
+
+    /// Holes with a diameter smaller than the minimum hole diameter.
+    #[error("{count} hole(s) with diameter below minimum, smallest hole is {diameter}mm at {}", fmt_point(.at))]
+    SmallHoles {
+        /// Number of holes.
+        count: usize,
+        /// Diameter of the smallest hole.
+        diameter: Scalar,
+        /// Center of the smallest hole.
+        at: Vec3,
+    },
+}
+
+fn fmt_point(p: &Vec3) -> String {
+    format!("({}, {}, {})", p.x, p.y, p.z)
+}
+
+/// Validate a geometry for printability.
+pub trait ValidateGeometry {
+    /// Return all issues found in the geometry.
+    fn validate(&self, params: &ValidationParameters) -> Vec<GeometryIssue>;
+}
+
+/// Holes of a 2D geometry which are smaller than `min_hole`.
+///
+/// The diameter of a hole is estimated as `4 * area / perimeter`, which is exact for circles.
+/// Returns the diameter and the center of each small hole.
+fn small_holes(multi_polygon: &MultiPolygon, min_hole: Scalar) -> Vec<(Scalar, Vec2)> {
+    multi_polygon
+        .iter()
+        .flat_map(|polygon| polygon.interiors())
+        .filter_map(|ring| {
+            let polygon = Polygon::new(ring.clone(), vec![]);
+            let perimeter: Scalar = ring.lines().map(|line| line.dx().hypot(line.dy())).sum();
+            if perimeter < VALIDATION_PRECISION {
+                return None;
+            }
+            let diameter = 4.0 * polygon.unsigned_area() / perimeter;
+            let center = polygon.centroid()?;
+            (diameter < min_hole).then_some((diameter, Vec2::new(center.x(), center.y())))
+        })
+        .collect()
+}
+
+impl ValidateGeometry for MultiPolygon {
+    fn validate(&self, params: &ValidationParameters) -> Vec<GeometryIssue> {
+        let Some(min_hole) = params.min_hole else {
+            return Vec::new();
+        };
+        let holes = small_holes(self, min_hole);
+        holes
+            .iter()
+            .min_by(|a, b| a.0.total_cmp(&b.0))
+            .map(|(diameter, at)| GeometryIssue::SmallHoles {
+                count: holes.len(),
+                diameter: *diameter,
+                at: Vec3::new(at.x, at.y, 0.0),
+            })
+            .into_iter()
+            .collect()
+    }
+}
+
+/// Triangle of a welded mesh.
+struct Face {
+    indices: [usize; 3],
+    points: [Vec3; 3],
+    normal: Vec3,
+    bounds: Bounds3D,
+}
+
+impl Face {
+    fn centroid(&self) -> Vec3 {
+        (self.points[0] + self.points[1] + self.points[2]) / 3.0
+    }
+
+    fn shares_vertex(&self, other: &Face) -> bool {
+        self.indices.iter().any(|i| other.indices.contains(i))
+    }
+
+    /// Intersection of the segment `p`-`q` with this triangle (Möller–Trumbore).
+    fn intersect_segment(&self, p: Vec3, q: Vec3) -> Option<Scalar> {
+        let [a, b, c] = self.points;
+        let dir = q - p;
+        let (e1, e2) = (b - a, c - a);
+        let h = dir.cross(e2);
+        let det = e1.dot(h);
+        if det.abs() < VALIDATION_PRECISION * VALIDATION_PRECISION {
+            return None; // Segment is parallel to the triangle.
+        }
+        let s = p - a;
+        let u = s.dot(h) / det;
+        if !(0.0..=1.0).contains(&u) {
+            return None;
+        }
+        let r = s.cross(e1);
+        let v = dir.dot(r) / det;
+        if v < 0.0 || u + v > 1.0 {
+            return None;
+        }
+        let t = e2.dot(r) / det;
+        (0.0..=1.0).contains(&t).then_some(t)
+    }
+
+    /// Return a point where the triangles intersect, if any.
+    ///
+    /// Coplanar overlaps are not detected.
+    fn intersect(&self, other: &Face) -> Option<Vec3> {
+        let edges = |f: &Face| [(0, 1), (1, 2), (2, 0)].map(|(i, j)| (f.points[i], f.points[j]));
+        edges(self)
+            .into_iter()
+            .find_map(|(p, q)| other.intersect_segment(p, q).map(|t| p + (q - p) * t))
+            .or_else(|| {
+                edges(other)
+                    .into_iter()
+                    .find_map(|(p, q)| self.intersect_segment(p, q).map(|t| p + (q - p) * t))
+            })
+    }
+}
+
+/// Uniform grid of element indices to find elements (e.g. triangles) near a region.
+pub(crate) struct Grid {
+    min: Vec3,
+    cell_size: Scalar,
+    cells: HashMap<(i64, i64, i64), Vec<usize>>,
+}
+
+impl Grid {
+    /// Create a grid of elements given by their bounds, which all lie within `bounds`.
+    pub(crate) fn new<'a>(
+        elements: impl ExactSizeIterator<Item = &'a Bounds3D>,
+        bounds: &Bounds3D,
+    ) -> Self {
+        let size = bounds.max - bounds.min;
+        let extent = size.x.max(size.y).max(size.z);
+        let cells_per_axis = (elements.len() as Scalar).cbrt().ceil().max(1.0);
+        let mut grid = Self {
+            min: bounds.min,
+            cell_size: (extent / cells_per_axis).max(VALIDATION_PRECISION),
+            cells: HashMap::default(),
+        };
+        elements.enumerate().for_each(|(i, bounds)| {
+            grid.keys(bounds)
+                .into_iter()
+                .for_each(|key| grid.cells.entry(key).or_default().push(i))
+        });
+        grid
+    }
+
+    /// Edge length of the cells.
+    pub(crate) fn cell_size(&self) -> Scalar {
+        self.cell_size
+    }
+
+    /// Keys of all cells which overlap the bounds.
+    fn keys(&self, bounds: &Bounds3D) -> Vec<(i64, i64, i64)> {
+        let cell = |v: Vec3| ((v - self.min) / self.cell_size).map(|x| x.floor() as i64);
+        let (min, max) = (cell(bounds.min), cell(bounds.max));
+        (min.x..=max.x)
+            .flat_map(|x| {
+                (min.y..=max.y).flat_map(move |y| (min.z..=max.z).map(move |z| (x, y, z)))
+            })
+            .collect()
+    }
+
+    /// Indices of all elements whose cells overlap the bounds.
+    pub(crate) fn query(&self, bounds: &Bounds3D) -> Vec<usize> {
+        let mut indices: Vec<_> = self
+            .keys(bounds)
+            .into_iter()
+            .filter_map(|key| self.cells.get(&key))
+            .flatten()
+            .copied()
+            .collect();
+        indices.sort_unstable();
+        indices.dedup();
+        indices
+    }
+}
+
+fn segment_bounds(p: Vec3, q: Vec3) -> Bounds3D {
+    let mut bounds = Bounds3D::new(p, p);
+    bounds.extend_by_point(q);
+    bounds
+}
+
+fn find_root(parents: &mut [usize], mut i: usize) -> usize {
+    while parents[i] != i {
+        parents[i] = parents[parents[i]];
+        i = parents[i];
+    }
+    i
+}
+
+impl TriangleMesh {
+    /// Merge vertices at equal positions and return positions and triangle indices.
+    fn weld(&self) -> (Vec<Vec3>, Vec<[usize; 3]>) {
+        let key = |v: &Vec3| {
+            (
+                (v.x / VALIDATION_PRECISION).round() as i64,
+                (v.y / VALIDATION_PRECISION).round() as i64,
+                (v.z / VALIDATION_PRECISION).round() as i64,
+            )
+        };
+
+        let mut map: HashMap<(i64, i64, i64), usize> = HashMap::default();
+        let mut positions = Vec::new();
+        let remap: Vec<_> = self
+            .positions
+            .iter()
+            .map(|v| {
+                let v = Vec3::new(v.x as Scalar, v.y as Scalar, v.z as Scalar);
+                *map.entry(key(&v)).or_insert_with(|| {
+                    positions.push(v);
+                    positions.len() - 1
+                })
+            })
+            .collect();
+
+        let triangles = self
+            .triangle_indices
+            .iter()
+            .map(|t| [t.0, t.1, t.2].map(|i| remap[i as usize]))
+            .collect();
+        (positions, triangles)
+    }
+
+    fn non_manifold_edges(faces: &[Face], positions: &[Vec3]) -> Option<GeometryIssue> {
+        let edges = |face: &Face| {
+            let [a, b, c] = face.indices;
+            [(a, b), (b, c), (c, a)].map(|(i, j)| (i.min(j), i.max(j)))
+        };
+
+        let mut uses: HashMap<(usize, usize), usize> = HashMap::default();
+        faces
+            .iter()
+            .flat_map(edges)
+            .for_each(|edge| *uses.entry(edge).or_default() += 1);
+
+        let count = uses.values().filter(|n| **n != 2).count();
+        faces
+            .iter()
+            .flat_map(edges)
+            .find(|edge| uses[edge] != 2)
+            .map(|(i, j)| GeometryIssue::NonManifoldEdges {
+                count,
+                at: (positions[i] + positions[j]) / 2.0,
+            })
+    }
+
+    fn disconnected_shells(faces: &[Face], vertex_count: usize) -> Option<GeometryIssue> {
+        let mut parents: Vec<_> = (0..vertex_count).collect();
+        faces.iter().for_each(|face| {
+            let [a, b, c] = face.indices.map(|i| find_root(&mut parents, i));
+            parents[b] = a;
+            parents[c] = a;
+        });
+
+        let mut roots: Vec<_> = faces
+            .iter()
+            .map(|face| find_root(&mut parents, face.indices[0]))
+            .collect();
+        roots.sort_unstable();
+        roots.dedup();
+
+        (roots.len() > 1).then_some(GeometryIssue::DisconnectedShells { count: roots.len() })
+    }
+
+    fn self_intersections(faces: &[Face], grid: &Grid) -> Option<GeometryIssue> {
+        let mut count = 0;
+        let mut first = None;
+        faces.iter().enumerate().for_each(|(i, face)| {
+            grid.query(&face.bounds)
+                .into_iter()
+                .filter(|j| *j > i)
+                .map(|j| &faces[j])
+                .filter(|other| !face.shares_vertex(other))
+                .filter_map(|other| face.intersect(other))
+                .for_each(|at| {
+                    count += 1;
+                    first.get_or_insert(at);
+                })
+        });
+        first.map(|at| GeometryIssue::SelfIntersections { count, at })
+    }
+
+    fn thin_walls(faces: &[Face], grid: &Grid, min_thickness: Scalar) -> Option<GeometryIssue> {
+        let thicknesses: Vec<_> = faces
+            .iter()
+            .enumerate()
+            .filter_map(|(i, face)| {
+                // Cast a ray from the centroid against the outward normal and
+                // find the nearest opposite wall.
+                let p = face.centroid();
+                let q = p - face.normal * min_thickness;
+                grid.query(&segment_bounds(p, q))
+                    .into_iter()
+                    .filter(|j| *j != i)
+                    .map(|j| &faces[j])
+                    .filter(|other| other.normal.dot(face.normal) < 0.0)
+                    .filter_map(|other| other.intersect_segment(p, q))
+                    .map(|t| t * min_thickness)
+                    .filter(|thickness| *thickness > VALIDATION_PRECISION)
+                    .min_by(|a, b| a.total_cmp(b))
+                    .map(|thickness| (thickness, p))
+            })
+            .collect();
+
+        thicknesses
+            .iter()
+            .min_by(|a, b| a.0.total_cmp(&b.0))
+            .map(|(thickness, at)| GeometryIssue::ThinWalls {
+                count: thicknesses.len(),
+                thickness: *thickness,
+                at: *at,
+            })
+    }
+
+    fn small_holes(&self, bounds: &Bounds3D, min_hole: Scalar) -> Option<GeometryIssue> {
+        let size = bounds.max - bounds.min;
+        let mut count = 0;
+        let mut smallest: Option<(Scalar, Vec3)> = None;
+
+        for n in [Vec3::unit_x(), Vec3::unit_y(), Vec3::unit_z()] {
+            let extent = size.dot(n);
+            let sections = ((extent / min_hole).ceil() as usize).clamp(1, MAX_SECTIONS);
+            for i in 0..sections {
+                let offset = extent * (i as Scalar + 0.5) / sections as Scalar;
+                let plane = Plane {
+                    p: bounds.min + n * offset,
+                    n,
+                };
+                let (u, v) = plane.axes();
+                let holes = small_holes(&self.section(&plane), min_hole);
+                // A hole is cut by several sections, hence count the holes of the worst section.
+                count = count.max(holes.len());
+                holes.into_iter().for_each(|(diameter, center)| {
+                    if smallest.is_none_or(|(d, _)| diameter < d) {
+                        smallest = Some((diameter, plane.p + u * center.x + v * center.y));
+                    }
+                });
+            }
+        }
+
+        smallest.map(|(diameter, at)| GeometryIssue::SmallHoles {
+            count,
+            diameter,
+            at,
+        })
+    }
+}
+
+impl ValidateGeometry for TriangleMesh {
+    fn validate(&self, params: &ValidationParameters) -> Vec<GeometryIssue> {
+        let (positions, triangles) = self.weld();
+
+        let mut degenerate = Vec::new();
+        let faces: Vec<_> = triangles
+            .into_iter()
+            .filter_map(|indices| {
+                let points = indices.map(|i| positions[i]);
+                let [a, b, c] = points;
+                let cross = (b - a).cross(c - a);
+                if cross.magnitude() < VALIDATION_PRECISION * VALIDATION_PRECISION {
+                    degenerate.push((a + b + c) / 3.0);
+                    return None;
+                }
+                let mut bounds = Bounds3D::new(a, a);
+                bounds.extend_by_point(b);
+                bounds.extend_by_point(c);
+                Some(Face {
+                    indices,
+                    points,
+                    normal: cross.normalize(),
+                    bounds,
+                })
+            })
+            .collect();
+
+        let mut issues = Vec::new();
+        if let Some(at) = degenerate.first() {
+            issues.push(GeometryIssue::DegenerateTriangles {
+                count: degenerate.len(),
+                at: *at,
+            });
+        }
+        if faces.is_empty() {
+            return issues;
+        }
+
+        let bounds = self.calc_bounds_3d();
+        let grid = Grid::new(faces.iter().map(|face| &face.bounds), &bounds);
+
+        issues.extend(Self::non_manifold_edges(&faces, &positions));
+        issues.extend(Self::self_intersections(&faces, &grid));
+        issues.extend(Self::disconnected_shells(&faces, positions.len()));
+        if let Some(min_thickness) = params.min_thickness {
+            issues.extend(Self::thin_walls(&faces, &grid, min_thickness));
+        }
+        if let Some(min_hole) = params.min_hole {
+            issues.extend(self.small_holes(&bounds, min_hole));
+        }
+        issues
+    }
+}
+
+#[test]
+fn test_validate_cube() {
+    let cube: TriangleMesh = Manifold::cube(10.0, 10.0, 10.0).into();
+    assert!(cube.validate(&ValidationParameters::default()).is_empty());
+
+    let issues = cube.validate(&ValidationParameters {
+        min_thickness: Some(20.0),
+        min_hole: Some(1.0),
+    });
+    assert!(matches!(
+        issues.as_slice(),
+        [GeometryIssue::ThinWalls { thickness, .. }] if (thickness - 10.0).abs() < 1e-6
+    ));
+}
+
+#[test]
+fn test_validate_mesh_issues() {
+    let cube: TriangleMesh = Manifold::cube(10.0, 10.0, 10.0).into();
+
+    // Two overlapping cubes which have not been united.
+    let mut mesh = cube.clone();
+    mesh.append(&cube.transformed_3d(&Mat4::from_translation(Vec3::new(5.0, 5.0, 5.0))));
+    let issues = mesh.validate(&ValidationParameters::default());
+    assert!(
+        issues
+            .iter()
+            .any(|issue| matches!(issue, GeometryIssue::SelfIntersections { .. }))
+    );
+    assert!(issues.contains(&GeometryIssue::DisconnectedShells { count: 2 }));
+
+    // Open cube with one triangle removed.
+    let mut mesh = cube.clone();
+    mesh.triangle_indices.pop();
+    let issues = mesh.validate(&ValidationParameters::default());
+    assert!(matches!(
+        issues.as_slice(),
+        [GeometryIssue::NonManifoldEdges { count: 3, .. }]
+    ));
+}
+
+#[test]
+fn test_validate_small_holes() {
+    let plate = Manifold::cube(20.0, 20.0, 2.0);
+    let hole = TriangleMesh::cone(0.5, 0.5, 4.0, 64)
+        .transformed_3d(&Mat4::from_translation(Vec3::new(10.0, 10.0, -1.0)))
+        .to_manifold();
+    let mesh: TriangleMesh = manifold_boolean_op(&plate, &hole, &BooleanOp::Subtract).into();
+
+    let issues = mesh.validate(&ValidationParameters {
+        min_thickness: None,
+        min_hole: Some(2.0),
+    });
+    let [GeometryIssue::SmallHoles { diameter, at, .. }] = issues.as_slice() else {
+        panic!("unexpected issues: {issues:?}");
+    };
+    assert!((diameter - 1.0).abs() < 0.01);
+    assert!((at.x - 10.0).abs() < 0.1 && (at.y - 10.0).abs() < 0.1);
+}
@@ -192,6 +192,16 @@ impl ToMd for Symbol {
                 })
             });
 
+            // Assemblies
+            symbol_list(self, &mut md, "Assemblies", |symbol| {
+                symbol.with_def(|def| match def {
+                    SymbolDef::Workbench(workbench_definition) => {
+                        matches!(&workbench_definition.kind.value, WorkbenchKind::Assembly)
+                    }
+                    _ => false,
+                })
+            });
+
             // Operations
             symbol_list(self, &mut md, "Operations", |symbol| {
                 symbol.with_def(|def| match def {
@@ -18,6 +18,10 @@ pub trait Lower {
 pub struct ResolveParameters {
     pub search_paths: Vec<std::path::PathBuf>,
     pub no_builtin: bool,
+    /// Plugins which provide modules for `extern mod` declarations.
+    pub plugins: PluginRegistry,
+    /// Cache of parsed files from the search paths which can be shared by documents.
+    pub source_cache: Option<RcMut<SourceCache>>,
 }
 
 impl Default for ResolveParameters {
@@ -25,6 +29,8 @@ impl Default for ResolveParameters {
         Self {
             search_paths: microcad_builtin::dirs::default_search_paths(),
             no_builtin: false,
+            plugins: PluginRegistry::default(),
+            source_cache: None,
         }
     }
 }
@@ -34,9 +40,16 @@ pub trait Resolve {
     fn resolve(&mut self, params: impl Into<ResolveParameters>) -> Result<Symbol>;
 }
 
+/// Parameters for evaluation
+#[derive(Default, Clone)]
+pub struct EvalParameters {
+    /// Values which replace constants or default arguments of workbenches.
+    pub overrides: Overrides,
+}
+
 /// Resolve the IR into a symbol tree.
 pub trait Eval {
-    fn eval(&mut self) -> Result<Model>;
+    fn eval(&mut self, params: impl Into<EvalParameters>) -> Result<Model>;
 }
 
 /// Compile parameters
@@ -44,6 +57,8 @@ pub trait Eval {
 pub struct CompileParameters {
     /// Resolve parameters.
     pub resolve: ResolveParameters,
+    /// Evaluation parameters.
+    pub eval: EvalParameters,
 }
 
 /// Trait for compilation toolchain.
@@ -54,6 +69,6 @@ pub trait Compile: Parse + Lower + Resolve + Eval {
         self.parse()?;
         self.lower()?;
         self.resolve(parameters.resolve)?;
-        self.eval()
+        self.eval(parameters.eval)
     }
 }
@@ -16,6 +16,7 @@ use microcad_lang::{
 
 pub use microcad_lang::model::ExportCommand;
 
+#[derive(derive_more::Deref)]
 pub struct ExportTargets(Vec<(Model, ExportCommand)>);
 
 impl std::fmt::Display for ExportTargets {
@@ -26,6 +27,12 @@ impl std::fmt::Display for ExportTargets {
     }
 }
 
+impl FromIterator<(Model, ExportCommand)> for ExportTargets {
+    fn from_iter<T: IntoIterator<Item = (Model, ExportCommand)>>(iter: T) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
+
 #[derive(Debug)]
 pub struct ExportResult {
     pub model: Model,
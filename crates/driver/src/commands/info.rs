@@ -0,0 +1,193 @@
+// Copyright © 2026 The µcad authors <info@microcad.xyz>
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+//! Command to report model tree and geometry statistics.
+
+use geo::Area;
+use microcad_core::{CalcBounds2D, CalcBounds3D, Scalar, traits::VertexCount};
+use microcad_lang_base::FormatTree;
+use serde::Serialize;
+
+use crate::{
+    Result,
+    commands::{Compile, CompileParameters, Export, ExportParameters, Render, RenderParameters},
+    geometry::WorldGeometry,
+    prelude::Model,
+};
+
+/// Info parameters.
+pub struct InfoParameters {
+    /// Compile parameters.
+    pub compile: CompileParameters,
+    /// Render parameters.
+    pub render: RenderParameters,
+    /// Export parameters to find the exported models.
+    pub export: ExportParameters,
+}
+
+/// Bounding box.
+#[derive(Debug, Clone, Serialize)]
+pub struct BoundsInfo {
+    /// Minimum corner.
+    pub min: Vec<Scalar>,
+    /// Maximum corner.
+    pub max: Vec<Scalar>,
+}
+
+/// Geometry statistics of an exported model.
+#[derive(Debug, Clone, Serialize)]
+pub struct ModelInfo {
+    /// Export target file.
+    pub target: std::path::PathBuf,
+    /// Output type, e.g. `2D` or `3D`.
+    pub output_type: String,
+    /// Bounding box in world space (if there is any geometry).
+    pub bounds: Option<BoundsInfo>,
+    /// Area in mm² (2D only).
+    pub area: Option<Scalar>,
+    /// Volume in mm³ (3D only).
+    pub volume: Option<Scalar>,
+    /// Number of vertices.
+    pub vertices: usize,
+    /// Number of triangles (3D only).
+    pub triangles: Option<usize>,
+    /// Render time of the model in seconds.
+    pub render_time: Option<f64>,
+}
+
+impl ModelInfo {
+    /// Collect statistics of a rendered model.
+    fn new(model: &Model, target: std::path::PathBuf) -> Self {
+        let mut info = Self {
+            target,
+            output_type: model.deduce_output_type().to_string(),
+            bounds: None,
+            area: None,
+            volume: None,
+            vertices: 0,
+            triangles: None,
+            render_time: model
+                .borrow()
+                .output
+                .as_ref()
+                .and_then(|output| output.render_time)
+                .map(|render_time| render_time.as_secs_f64()),
+        };
+
+        match WorldGeometry::from_model(model) {
+            Some(WorldGeometry::Geometry2D(multi_polygon)) => {
+                let bounds = multi_polygon.calc_bounds_2d();
+                if bounds.is_valid() {
+                    info.bounds = Some(BoundsInfo {
+                        min: vec![bounds.min.x, bounds.min.y],
+                        max: vec![bounds.max.x, bounds.max.y],
+                    });
+                }
+                info.area = Some(multi_polygon.unsigned_area());
+                info.vertices = multi_polygon.vertex_count();
+            }
+            Some(WorldGeometry::Geometry3D(mesh)) => {
+                let bounds = mesh.calc_bounds_3d();
+                if bounds.is_valid() {
+                    info.bounds = Some(BoundsInfo {
+                        min: vec![bounds.min.x, bounds.min.y, bounds.min.z],
+                        max: vec![bounds.max.x, bounds.max.y, bounds.max.z],
+                    });
+                }
+                info.volume = Some(mesh.volume());
+                info.vertices = mesh.vertex_count();
+                info.triangles = Some(mesh.triangle_indices.len());
+            }
+            None => (),
+        }
+        info
+    }
+}
+
+impl std::fmt::Display for ModelInfo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let corner = |v: &Vec<Scalar>| {
+            v.iter()
+                .map(|x| x.to_string())
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+
+        writeln!(f, "{}:", self.target.display())?;
+        writeln!(f, "  Output type : {}", self.output_type)?;
+        match &self.bounds {
+            Some(bounds) => writeln!(
+                f,
+                "  Bounds      : [({}), ({})]",
+                corner(&bounds.min),
+                corner(&bounds.max)
+            )?,
+            None => writeln!(f, "  Bounds      : [no bounds]")?,
+        }
+        if let Some(area) = self.area {
+            writeln!(f, "  Area        : {area}mm²")?;
+        }
+        if let Some(volume) = self.volume {
+            writeln!(f, "  Volume      : {volume}mm³")?;
+        }
+        writeln!(f, "  Vertices    : {}", self.vertices)?;
+        if let Some(triangles) = self.triangles {
+            writeln!(f, "  Triangles   : {triangles}")?;
+        }
+        if let Some(render_time) = self.render_time {
+            writeln!(f, "  Render time : {render_time:.3}s")?;
+        }
+        Ok(())
+    }
+}
+
+/// Model tree and statistics of a document.
+#[derive(Debug, Clone, Serialize)]
+pub struct DocumentInfo {
+    /// Rendered model tree.
+    pub tree: String,
+    /// Statistics of each exported model.
+    pub models: Vec<ModelInfo>,
+    /// Compile time in seconds.
+    pub compile_time: f64,
+    /// Render time of the whole document in seconds.
+    pub render_time: f64,
+}
+
+impl std::fmt::Display for DocumentInfo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "Model tree:")?;
+        write!(f, "{}", self.tree)?;
+        self.models.iter().try_for_each(|model| {
+            writeln!(f)?;
+            write!(f, "{model}")
+        })?;
+        writeln!(f)?;
+        writeln!(f, "Compile time : {:.3}s", self.compile_time)?;
+        writeln!(f, "Render time  : {:.3}s", self.render_time)
+    }
+}
+
+/// Compile and render a document and collect statistics of the exported models.
+pub trait Info: Compile + Render + Export {
+    fn info(&mut self, params: InfoParameters) -> Result<DocumentInfo> {
+        let start = std::time::Instant::now();
+        self.compile(params.compile)?;
+        let compile_time = start.elapsed().as_secs_f64();
+
+        let start = std::time::Instant::now();
+        let model = self.render(params.render)?;
+        let render_time = start.elapsed().as_secs_f64();
+
+        Ok(DocumentInfo {
+            tree: FormatTree(&model).to_string(),
+            models: self
+                .get_export_targets(params.export)?
+                .iter()
+                .map(|(model, export)| ModelInfo::new(model, export.filename.clone()))
+                .collect(),
+            compile_time,
+            render_time,
+        })
+    }
+}
@@ -6,7 +6,10 @@ use crate::Result;
 mod doc_gen;
 mod export;
 mod format;
+mod info;
 mod print_diagnostics;
+mod test;
+mod validate;
 
 pub mod compile;
 pub mod render;
@@ -15,8 +18,11 @@ pub use compile::{Compile, CompileParameters};
 pub use doc_gen::*;
 pub use export::*;
 pub use format::*;
+pub use info::*;
 pub use print_diagnostics::*;
 pub use render::*;
+pub use test::*;
+pub use validate::*;
 
 /// Load something from a file
 pub trait LoadFromFile {
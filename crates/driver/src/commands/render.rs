@@ -8,14 +8,30 @@ pub struct RenderParameters {
     pub resolution: RenderResolution,
     pub cache: Option<RcMut<RenderCache>>,
     pub progress_tx: Option<ProgressTx>,
+    pub cancellation: Option<CancellationToken>,
 }
 
 impl RenderParameters {
     pub fn with_cache(self, cache: RcMut<RenderCache>) -> Self {
         Self {
-            resolution: self.resolution,
             cache: Some(cache),
-            progress_tx: self.progress_tx,
+            ..self
+        }
+    }
+
+    /// Send progress events to `progress_tx` while rendering.
+    pub fn with_progress(self, progress_tx: ProgressTx) -> Self {
+        Self {
+            progress_tx: Some(progress_tx),
+            ..self
+        }
+    }
+
+    /// Stop rendering with [`RenderError::Cancelled`] when `cancellation` is cancelled.
+    pub fn with_cancellation(self, cancellation: CancellationToken) -> Self {
+        Self {
+            cancellation: Some(cancellation),
+            ..self
         }
     }
 
@@ -31,6 +47,7 @@ impl From<RenderResolution> for RenderParameters {
             resolution,
             cache: None,
             progress_tx: None,
+            cancellation: None,
         }
     }
 }
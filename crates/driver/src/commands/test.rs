@@ -0,0 +1,211 @@
+// Copyright © 2026 The µcad authors <info@microcad.xyz>
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+//! Command to run tests in source files and markdown code blocks.
+
+use crate::{GoldenParameters, Result, commands::CompileParameters};
+
+/// Test parameters
+#[derive(Default, Clone)]
+pub struct TestParameters {
+    /// Compile parameters.
+    pub compile: CompileParameters,
+    /// Compare the models of tests against reference geometries.
+    pub golden: Option<GoldenParameters>,
+}
+
+impl From<CompileParameters> for TestParameters {
+    fn from(compile: CompileParameters) -> Self {
+        Self {
+            compile,
+            golden: None,
+        }
+    }
+}
+
+/// Outcome of a single test.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TestOutcome {
+    /// The test succeeded.
+    Passed,
+    /// The test failed with errors, e.g. a failed assertion.
+    Failed,
+    /// The test has not been run, e.g. markdown code blocks marked with `#todo`.
+    Skipped,
+}
+
+/// Result of a single test.
+#[derive(Debug, Clone)]
+pub struct TestResult {
+    /// Name of the test, e.g. `bracket.µcad::holes` or `README.md#example`.
+    pub name: String,
+    /// Outcome of the test.
+    pub outcome: TestOutcome,
+    /// Rendered diagnostics of the test.
+    pub message: String,
+    /// Time the test took.
+    pub duration: std::time::Duration,
+}
+
+/// Results of several tests.
+#[derive(Debug, Clone, Default, derive_more::Deref, derive_more::DerefMut)]
+pub struct TestResults(Vec<TestResult>);
+
+impl TestResults {
+    /// Add a test result.
+    pub fn push(&mut self, result: TestResult) {
+        self.0.push(result)
+    }
+
+    /// Number of tests with the given outcome.
+    pub fn count(&self, outcome: TestOutcome) -> usize {
+        self.0.iter().filter(|r| r.outcome == outcome).count()
+    }
+
+    /// Return `true` if no test has failed.
+    pub fn success(&self) -> bool {
+        self.count(TestOutcome::Failed) == 0
+    }
+
+    /// Write results in *Test Anything Protocol* (TAP) version 13.
+    pub fn write_tap(&self, f: &mut impl std::fmt::Write) -> std::fmt::Result {
+        writeln!(f, "TAP version 13")?;
+        writeln!(f, "1..{}", self.0.len())?;
+        self.0.iter().enumerate().try_for_each(|(n, result)| {
+            let n = n + 1;
+            match result.outcome {
+                TestOutcome::Passed => writeln!(f, "ok {n} - {}", result.name),
+                TestOutcome::Skipped => writeln!(f, "ok {n} - {} # SKIP", result.name),
+                TestOutcome::Failed => {
+                    writeln!(f, "not ok {n} - {}", result.name)?;
+                    result
+                        .message
+                        .lines()
+                        .try_for_each(|line| writeln!(f, "# {line}"))
+                }
+            }
+        })
+    }
+
+    /// Write results as JUnit XML.
+    pub fn write_junit(&self, f: &mut impl std::fmt::Write) -> std::fmt::Result {
+        let time = |duration: std::time::Duration| format!("{:.3}", duration.as_secs_f64());
+
+        writeln!(f, r#"<?xml version="1.0" encoding="UTF-8"?>"#)?;
+        writeln!(
+            f,
+            r#"<testsuite name="microcad" tests="{}" failures="{}" skipped="{}" time="{}">"#,
+            self.0.len(),
+            self.count(TestOutcome::Failed),
+            self.count(TestOutcome::Skipped),
+            time(self.0.iter().map(|r| r.duration).sum()),
+        )?;
+        self.0.iter().try_for_each(|result| {
+            write!(
+                f,
+                r#"  <testcase name="{}" time="{}""#,
+                xml_escape(&result.name),
+                time(result.duration)
+            )?;
+            match result.outcome {
+                TestOutcome::Passed => writeln!(f, "/>"),
+                TestOutcome::Skipped => writeln!(f, ">\n    <skipped/>\n  </testcase>"),
+                TestOutcome::Failed => writeln!(
+                    f,
+                    ">\n    <failure>{}</failure>\n  </testcase>",
+                    xml_escape(&result.message)
+                ),
+            }
+        })?;
+        writeln!(f, "</testsuite>")
+    }
+}
+
+impl std::fmt::Display for TestResults {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} passed, {} failed, {} skipped",
+            self.count(TestOutcome::Passed),
+            self.count(TestOutcome::Failed),
+            self.count(TestOutcome::Skipped)
+        )
+    }
+}
+
+impl Extend<TestResult> for TestResults {
+    fn extend<T: IntoIterator<Item = TestResult>>(&mut self, iter: T) {
+        self.0.extend(iter)
+    }
+}
+
+impl IntoIterator for TestResults {
+    type Item = TestResult;
+    type IntoIter = std::vec::IntoIter<TestResult>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+/// Escape special characters for XML.
+fn xml_escape(s: &str) -> String {
+    s.chars().fold(String::with_capacity(s.len()), |mut s, c| {
+        match c {
+            '&' => s.push_str("&amp;"),
+            '<' => s.push_str("&lt;"),
+            '>' => s.push_str("&gt;"),
+            '"' => s.push_str("&quot;"),
+            '\'' => s.push_str("&apos;"),
+            c => s.push(c),
+        }
+        s
+    })
+}
+
+/// Run tests in a document.
+///
+/// Each source file is a test which fails if its evaluation fails.
+/// Additionally, each workbench or function marked with `#[test]` is called without arguments.
+/// In markdown, each named code block is a test.
+/// With [`GoldenParameters`], the model of each test is rendered and compared against a reference.
+pub trait Test {
+    fn test(&mut self, params: impl Into<TestParameters>) -> Result<TestResults>;
+}
+
+#[test]
+fn test_report() {
+    let results = TestResults(vec![
+        TestResult {
+            name: "a.µcad".into(),
+            outcome: TestOutcome::Passed,
+            message: String::new(),
+            duration: Default::default(),
+        },
+        TestResult {
+            name: "a.µcad::<fails>".into(),
+            outcome: TestOutcome::Failed,
+            message: "error: assertion failed".into(),
+            duration: Default::default(),
+        },
+        TestResult {
+            name: "b.md#todo".into(),
+            outcome: TestOutcome::Skipped,
+            message: String::new(),
+            duration: Default::default(),
+        },
+    ]);
+    assert!(!results.success());
+
+    let mut tap = String::new();
+    results.write_tap(&mut tap).expect("TAP output");
+    assert_eq!(
+        tap,
+        "TAP version 13\n1..3\nok 1 - a.µcad\nnot ok 2 - a.µcad::<fails>\n# error: assertion failed\nok 3 - b.md#todo # SKIP\n"
+    );
+
+    let mut junit = String::new();
+    results.write_junit(&mut junit).expect("JUnit output");
+    assert!(junit.contains(r#"tests="3" failures="1" skipped="1""#));
+    assert!(junit.contains(r#"<testcase name="a.µcad::&lt;fails&gt;""#));
+}
@@ -0,0 +1,53 @@
+// Copyright © 2026 The µcad authors <info@microcad.xyz>
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+//! Command to validate the rendered geometry for printability.
+
+use microcad_core::{ValidateGeometry, ValidationParameters};
+use microcad_lang_base::PushDiag;
+
+use crate::{
+    Result,
+    commands::{Compile, CompileParameters, Render, RenderParameters},
+    document::CaptureDiags,
+    geometry::WorldGeometry,
+};
+
+/// Validate parameters.
+pub struct ValidateParameters {
+    /// Compile parameters.
+    pub compile: CompileParameters,
+    /// Render parameters.
+    pub render: RenderParameters,
+    /// Geometry validation parameters.
+    pub validation: ValidationParameters,
+}
+
+/// Compile and render a document and validate the geometry of each model.
+///
+/// Each issue is reported as a warning which refers to the model which produced the geometry.
+pub trait Validate: Compile + Render + CaptureDiags {
+    /// Return the number of geometry issues.
+    fn validate(&mut self, params: ValidateParameters) -> Result<usize> {
+        self.compile(params.compile)?;
+        let model = self.render(params.render)?;
+
+        let mut issues = Vec::new();
+        WorldGeometry::for_each(&model, &mut |model, geometry| {
+            let found = match geometry {
+                WorldGeometry::Geometry2D(multi_polygon) => {
+                    multi_polygon.validate(&params.validation)
+                }
+                WorldGeometry::Geometry3D(mesh) => mesh.validate(&params.validation),
+            };
+            issues.extend(found.into_iter().map(|issue| (model.clone(), issue)));
+        });
+
+        let count = issues.len();
+        issues.into_iter().try_for_each(|(model, issue)| {
+            self.diags_mut()
+                .warning(&model, miette::miette!("Geometry issue: {issue}"))
+        })?;
+        Ok(count)
+    }
+}
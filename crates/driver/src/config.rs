@@ -3,7 +3,7 @@
 
 //! µcad driver config.
 
-use microcad_core::RenderResolution;
+use microcad_core::{RenderResolution, Scalar};
 use miette::IntoDiagnostic;
 use serde::Deserialize;
 
@@ -69,11 +69,14 @@ impl DriverConfig {
 
 /// Export settings.
 #[derive(Deserialize, Clone, Debug)]
+#[serde(default)]
 pub struct ExportConfig {
     /// Default sketch exporter.
     pub sketch: String,
     /// Default part exporter.
     pub part: String,
+    /// Default render resolution in mm.
+    pub resolution: Scalar,
 }
 
 impl Default for ExportConfig {
@@ -81,13 +84,15 @@ impl Default for ExportConfig {
         Self {
             sketch: "svg".into(),
             part: "stl".into(),
+            resolution: RenderResolution::high().linear,
         }
     }
 }
 
 impl ExportConfig {
+    /// Default render resolution.
     pub fn render_resolution(&self) -> RenderResolution {
-        todo!()
+        RenderResolution::new(self.resolution)
     }
 }
 
@@ -1,15 +1,19 @@
 // Copyright © 2026 The µcad authors <info@microcad.xyz>
 // SPDX-License-Identifier: AGPL-3.0-or-later
 
-use microcad_lang_base::{Diagnostics, ResourceLocation, Url};
+use std::path::{Path, PathBuf};
+
+use microcad_lang_base::{DiagRenderOptions, Diagnostics, ResourceLocation, SrcRef, Url};
 use miette::{Diagnostic, IntoDiagnostic};
 use thiserror::Error;
 
 use crate::{
     Result, commands,
     document::{self, CaptureDiags, TryFilePath},
+    prelude::{Model, RenderError, Symbol},
 };
-use microcad_lang_markdown::{Markdown, MarkdownError};
+use microcad_lang::model::{Element, builder::ModelBuilder};
+use microcad_lang_markdown::{CodeBlock, Markdown, MarkdownError};
 
 #[derive(Error, Debug, Diagnostic)]
 pub enum MarkdownItemError {
@@ -25,6 +29,8 @@ pub enum MarkdownItemError {
 pub struct MarkdownDocument {
     url: Url,
     markdown: Option<Markdown>,
+    /// Compiled code blocks.
+    sources: CodeBlockSources,
     diagnostics: Diagnostics,
 }
 
@@ -33,11 +39,254 @@ impl MarkdownDocument {
         Self {
             url,
             markdown: None,
+            sources: Default::default(),
             diagnostics: Default::default(),
         }
     }
 }
 
+/// A named code block of a markdown file which is compiled as a source.
+struct CodeBlockSource {
+    /// Name of the code block.
+    name: String,
+    /// Path of the markdown file.
+    path: PathBuf,
+    source: document::Source,
+}
+
+impl CodeBlockSource {
+    /// Base name of the exported files: `<markdown file stem>.<code block name>`.
+    fn export_name(&self) -> String {
+        format!(
+            "{}.{}",
+            self.path.file_stem().unwrap_or_default().to_string_lossy(),
+            self.name
+        )
+    }
+
+    /// Export targets of the code block named after the code block.
+    ///
+    /// A single target is named `<export name>.<ext>`, several targets are named
+    /// `<export name>.<file stem of the target>.<ext>`.
+    /// The files are written into the output directory or next to the markdown file.
+    fn export_targets(
+        &self,
+        params: &commands::ExportParameters,
+    ) -> Result<Vec<(Model, commands::ExportCommand)>> {
+        use commands::Export;
+
+        let dir = match &params.output_path {
+            Some(output_path) => output_path.clone(),
+            None => self.path.parent().unwrap_or(Path::new(".")).to_path_buf(),
+        };
+        let targets = self.source.get_export_targets(commands::ExportParameters {
+            input_path: dir.join(format!("{}.µcad", self.export_name())),
+            output_path: None,
+            config: params.config.clone(),
+        })?;
+
+        let count = targets.len();
+        Ok(targets
+            .iter()
+            .cloned()
+            .map(|(model, mut command)| {
+                let mut name = self.export_name();
+                if count > 1 {
+                    name.push('.');
+                    name.push_str(
+                        &command
+                            .filename
+                            .file_stem()
+                            .unwrap_or_default()
+                            .to_string_lossy(),
+                    );
+                }
+                if let Some(ext) = command.filename.extension() {
+                    name.push('.');
+                    name.push_str(&ext.to_string_lossy());
+                }
+                command.filename = dir.join(name);
+                (model, command)
+            })
+            .collect())
+    }
+}
+
+/// Named code blocks of markdown files which are compiled as sources.
+///
+/// Code blocks marked with `#todo`, `#ignore` or `#fail` are skipped.
+/// Each code block keeps its line offset, so diagnostics refer to lines of the markdown file.
+#[derive(Default)]
+pub(super) struct CodeBlockSources(Vec<CodeBlockSource>);
+
+impl CodeBlockSources {
+    pub(super) fn new<'a>(
+        code_blocks: impl IntoIterator<Item = (PathBuf, &'a CodeBlock)>,
+    ) -> Result<Self> {
+        code_blocks
+            .into_iter()
+            .filter(|(_, code_block)| {
+                !matches!(
+                    code_block.fragment().as_deref(),
+                    Some("todo" | "ignore" | "fail")
+                )
+            })
+            .filter_map(|(path, code_block)| {
+                code_block
+                    .name()
+                    .clone()
+                    .map(|name| (path, name, code_block))
+            })
+            .map(|(path, name, code_block)| {
+                let url = Url::from_file_path(&path)
+                    .map_err(|_| miette::miette!("No file path: {path}", path = path.display()))?;
+                Ok(CodeBlockSource {
+                    name,
+                    source: document::Source::from_source(microcad_lang_base::Source::new(
+                        url,
+                        code_block.line_offset() as u32,
+                        code_block.code().to_string(),
+                    )),
+                    path,
+                })
+            })
+            .collect::<Result<_>>()
+            .map(Self)
+    }
+
+    /// Run a compile step for all code blocks.
+    ///
+    /// Returns an error if the step failed for any code block.
+    fn run(
+        &mut self,
+        step: &str,
+        mut f: impl FnMut(&mut document::Source) -> Result<Option<Model>>,
+    ) -> Result<Vec<Model>> {
+        let mut models = Vec::new();
+        let mut failed = 0;
+        for code_block in &mut self.0 {
+            match f(&mut code_block.source) {
+                Ok(model) => models.extend(model),
+                Err(err)
+                    if matches!(
+                        err.downcast_ref::<RenderError>(),
+                        Some(RenderError::Cancelled)
+                    ) =>
+                {
+                    return Err(err);
+                }
+                Err(err) => {
+                    log::debug!("{step} failed for code block `{}`: {err}", code_block.name);
+                    failed += 1;
+                }
+            }
+        }
+        match failed {
+            0 => Ok(models),
+            n => Err(miette::miette!("{step} failed for {n} code block(s)")),
+        }
+    }
+
+    /// Group the models of all code blocks.
+    fn group(models: Vec<Model>) -> Model {
+        ModelBuilder::new(Element::Group, SrcRef::none())
+            .add_children(models.into())
+            .build()
+    }
+
+    pub(super) fn parse(&mut self) -> Result {
+        use commands::compile::Parse;
+        self.run("Parsing", |source| source.parse().map(|_| None))
+            .map(|_| ())
+    }
+
+    pub(super) fn lower(&mut self) -> Result {
+        use commands::compile::Lower;
+        self.run("Lowering", |source| source.lower().map(|_| None))
+            .map(|_| ())
+    }
+
+    /// Resolve all code blocks.
+    ///
+    /// There is no common symbol table of the code blocks, so an empty symbol is returned.
+    pub(super) fn resolve(
+        &mut self,
+        params: impl Into<commands::compile::ResolveParameters>,
+    ) -> Result<Symbol> {
+        use commands::compile::Resolve;
+        let params = params.into();
+        self.run("Resolving", |source| {
+            source.resolve(params.clone()).map(|_| None)
+        })?;
+        Ok(Symbol::default())
+    }
+
+    /// Evaluate all code blocks and return a group of their models.
+    pub(super) fn eval(
+        &mut self,
+        params: impl Into<commands::compile::EvalParameters>,
+    ) -> Result<Model> {
+        use commands::compile::Eval;
+        let params = params.into();
+        self.run("Evaluation", |source| source.eval(params.clone()).map(Some))
+            .map(Self::group)
+    }
+
+    /// Render all code blocks and return a group of their models.
+    pub(super) fn render(
+        &mut self,
+        params: impl Into<commands::RenderParameters>,
+    ) -> Result<Model> {
+        use commands::Render;
+        let params = params.into();
+        self.run("Rendering", |source| {
+            source.render(params.clone()).map(Some)
+        })
+        .map(Self::group)
+    }
+
+    pub(super) fn export_targets(
+        &self,
+        params: impl Into<commands::ExportParameters>,
+    ) -> Result<commands::ExportTargets> {
+        let params = params.into();
+        self.0
+            .iter()
+            .map(|code_block| code_block.export_targets(&params))
+            .collect::<Result<Vec<_>>>()
+            .map(|targets| targets.into_iter().flatten().collect())
+    }
+
+    /// Diagnostics of all code blocks.
+    pub(super) fn diagnostics(&self) -> Diagnostics {
+        self.0
+            .iter()
+            .map(|code_block| code_block.source.diags().clone())
+            .collect()
+    }
+
+    pub(super) fn print_diagnostics(
+        &self,
+        f: &mut dyn std::fmt::Write,
+        options: &DiagRenderOptions,
+    ) -> std::fmt::Result {
+        use commands::PrintDiagnostics;
+        self.0
+            .iter()
+            .filter(|code_block| code_block.source.diags().iter().next().is_some())
+            .try_for_each(|code_block| code_block.source.print_diagnostics(f, options))
+    }
+}
+
+impl MarkdownDocument {
+    /// Run a compile step on the code blocks and take over their diagnostics.
+    fn compile_step<T>(&mut self, f: impl FnOnce(&mut CodeBlockSources) -> Result<T>) -> Result<T> {
+        let result = f(&mut self.sources);
+        self.diagnostics = self.sources.diagnostics();
+        result
+    }
+}
+
 impl ResourceLocation for MarkdownDocument {
     fn url(&self) -> &Url {
         &self.url
@@ -63,6 +312,67 @@ impl commands::LoadFromFile for document::Markdown {
     }
 }
 
+impl commands::compile::Parse for document::Markdown {
+    fn parse(&mut self) -> Result {
+        let path = self.try_file_path()?;
+        self.sources = match &self.markdown {
+            Some(markdown) => CodeBlockSources::new(
+                markdown
+                    .code_blocks()
+                    .map(|code_block| (path.clone(), code_block)),
+            )?,
+            None => return Err(MarkdownItemError::NotLoaded.into()),
+        };
+        self.compile_step(CodeBlockSources::parse)
+    }
+}
+
+impl commands::compile::Lower for document::Markdown {
+    fn lower(&mut self) -> Result {
+        self.compile_step(CodeBlockSources::lower)
+    }
+}
+
+impl commands::compile::Resolve for document::Markdown {
+    fn resolve(
+        &mut self,
+        params: impl Into<commands::compile::ResolveParameters>,
+    ) -> Result<Symbol> {
+        self.compile_step(|sources| sources.resolve(params))
+    }
+}
+
+impl commands::compile::Eval for document::Markdown {
+    fn eval(&mut self, params: impl Into<commands::compile::EvalParameters>) -> Result<Model> {
+        self.compile_step(|sources| sources.eval(params))
+    }
+}
+
+impl commands::Render for document::Markdown {
+    fn render(&mut self, params: impl Into<commands::RenderParameters>) -> Result<Model> {
+        self.compile_step(|sources| sources.render(params))
+    }
+}
+
+impl commands::Export for document::Markdown {
+    fn get_export_targets(
+        &self,
+        params: impl Into<commands::ExportParameters>,
+    ) -> Result<commands::ExportTargets> {
+        self.sources.export_targets(params)
+    }
+}
+
+impl commands::PrintDiagnostics for document::Markdown {
+    fn print_diagnostics(
+        &self,
+        f: &mut dyn std::fmt::Write,
+        options: &DiagRenderOptions,
+    ) -> std::fmt::Result {
+        self.sources.print_diagnostics(f, options)
+    }
+}
+
 impl commands::Format for document::Markdown {
     fn format(&mut self, params: &commands::FormatParameters) -> Result<bool> {
         let mut formatted = false;
@@ -105,3 +415,92 @@ impl commands::Sync for document::Markdown {
         }
     }
 }
+
+/// Run a named code block as test.
+///
+/// Code blocks marked with `#todo` or `#ignore` are skipped and code blocks marked with `#fail`
+/// pass only if they fail.
+pub(super) fn test_code_block(
+    url: &Url,
+    path: &std::path::Path,
+    code_block: &CodeBlock,
+    params: &commands::TestParameters,
+) -> Result<commands::TestResults> {
+    use commands::{TestOutcome, TestResult};
+
+    let name = format!(
+        "{}#{}",
+        path.display(),
+        code_block.name().as_deref().unwrap_or_default()
+    );
+
+    match code_block.fragment().as_deref() {
+        Some("todo" | "ignore") => {
+            let mut results = commands::TestResults::default();
+            results.push(TestResult {
+                name,
+                outcome: TestOutcome::Skipped,
+                message: String::new(),
+                duration: Default::default(),
+            });
+            Ok(results)
+        }
+        fragment => {
+            let mut source = document::Source::from_source(microcad_lang_base::Source::new(
+                url.clone(),
+                code_block.line_offset() as u32,
+                code_block.code().to_string(),
+            ));
+            let reference = params.golden.as_ref().map(|golden| {
+                golden.reference(
+                    path,
+                    &format!(
+                        "{}.{}",
+                        path.file_stem().unwrap_or_default().to_string_lossy(),
+                        code_block.name().as_deref().unwrap_or_default()
+                    ),
+                )
+            });
+            let mut results = source.run_tests(&name, reference, params.clone())?;
+            if fragment == Some("fail")
+                && let Some(result) = results.first_mut()
+            {
+                match result.outcome {
+                    TestOutcome::Failed => {
+                        result.outcome = TestOutcome::Passed;
+                        result.message.clear();
+                    }
+                    _ => {
+                        result.outcome = TestOutcome::Failed;
+                        result.message = "Code block is marked to fail but succeeded".into();
+                    }
+                }
+            }
+            Ok(results)
+        }
+    }
+}
+
+impl commands::Test for document::Markdown {
+    fn test(
+        &mut self,
+        params: impl Into<commands::TestParameters>,
+    ) -> Result<commands::TestResults> {
+        let params = params.into();
+        let path = self.try_file_path()?;
+
+        match &self.markdown {
+            Some(markdown) => markdown
+                .code_blocks()
+                .filter(|code_block| code_block.name().is_some())
+                .try_fold(
+                    commands::TestResults::default(),
+                    |mut results, code_block| {
+                        results.extend(test_code_block(&self.url, &path, code_block, &params)?);
+                        Ok(results)
+                    },
+                ),
+            None => Err(MarkdownItemError::NotLoaded.into()),
+        }
+    }
+}
@@ -1,14 +1,15 @@
 // Copyright © 2026 The µcad authors <info@microcad.xyz>
 // SPDX-License-Identifier: AGPL-3.0-or-later
 
-use microcad_lang_base::{Diagnostics, ResourceLocation, Url};
+use microcad_lang_base::{DiagRenderOptions, Diagnostics, ResourceLocation, Url};
 use microcad_lang_markdown::{MdBook, MdBookError};
 use miette::{Diagnostic, IntoDiagnostic};
 use thiserror::Error;
 
 use crate::{
     Result, commands,
-    document::{self, CaptureDiags, TryFilePath},
+    document::{self, CaptureDiags, TryFilePath, markdown::CodeBlockSources},
+    prelude::{Model, Symbol},
 };
 
 #[derive(Error, Debug, Diagnostic)]
@@ -26,6 +27,8 @@ pub enum MdBookUnitError {
 pub struct MdBookDocument {
     url: Url,
     mdbook: Option<MdBook>,
+    /// Compiled code blocks of all chapters.
+    sources: CodeBlockSources,
     diags: Diagnostics,
 }
 
@@ -34,9 +37,17 @@ impl MdBookDocument {
         Self {
             url,
             mdbook: None,
+            sources: Default::default(),
             diags: Default::default(),
         }
     }
+
+    /// Run a compile step on the code blocks and take over their diagnostics.
+    fn compile_step<T>(&mut self, f: impl FnOnce(&mut CodeBlockSources) -> Result<T>) -> Result<T> {
+        let result = f(&mut self.sources);
+        self.diags = self.sources.diagnostics();
+        result
+    }
 }
 
 impl ResourceLocation for MdBookDocument {
@@ -64,6 +75,66 @@ impl commands::LoadFromFile for document::MdBook {
     }
 }
 
+impl commands::compile::Parse for document::MdBook {
+    fn parse(&mut self) -> Result {
+        self.sources = match &self.mdbook {
+            Some(mdbook) => CodeBlockSources::new(
+                mdbook
+                    .code_blocks()
+                    .map(|(md_file, code_block)| (mdbook.abs_md_file(&md_file), code_block)),
+            )?,
+            None => return Err(MdBookUnitError::NotLoaded.into()),
+        };
+        self.compile_step(CodeBlockSources::parse)
+    }
+}
+
+impl commands::compile::Lower for document::MdBook {
+    fn lower(&mut self) -> Result {
+        self.compile_step(CodeBlockSources::lower)
+    }
+}
+
+impl commands::compile::Resolve for document::MdBook {
+    fn resolve(
+        &mut self,
+        params: impl Into<commands::compile::ResolveParameters>,
+    ) -> Result<Symbol> {
+        self.compile_step(|sources| sources.resolve(params))
+    }
+}
+
+impl commands::compile::Eval for document::MdBook {
+    fn eval(&mut self, params: impl Into<commands::compile::EvalParameters>) -> Result<Model> {
+        self.compile_step(|sources| sources.eval(params))
+    }
+}
+
+impl commands::Render for document::MdBook {
+    fn render(&mut self, params: impl Into<commands::RenderParameters>) -> Result<Model> {
+        self.compile_step(|sources| sources.render(params))
+    }
+}
+
+impl commands::Export for document::MdBook {
+    fn get_export_targets(
+        &self,
+        params: impl Into<commands::ExportParameters>,
+    ) -> Result<commands::ExportTargets> {
+        self.sources.export_targets(params)
+    }
+}
+
+impl commands::PrintDiagnostics for document::MdBook {
+    fn print_diagnostics(
+        &self,
+        f: &mut dyn std::fmt::Write,
+        options: &DiagRenderOptions,
+    ) -> std::fmt::Result {
+        self.sources.print_diagnostics(f, options)
+    }
+}
+
 impl commands::Format for document::MdBook {
     fn format(&mut self, params: &commands::FormatParameters) -> Result<bool> {
         let mut formatted = false;
@@ -110,3 +181,32 @@ impl commands::Sync for document::MdBook {
         }
     }
 }
+
+impl commands::Test for document::MdBook {
+    fn test(
+        &mut self,
+        params: impl Into<commands::TestParameters>,
+    ) -> Result<commands::TestResults> {
+        let params = params.into();
+
+        match &self.mdbook {
+            Some(mdbook) => mdbook
+                .code_blocks()
+                .filter(|(_, code_block)| code_block.name().is_some())
+                .try_fold(
+                    commands::TestResults::default(),
+                    |mut results, (md_file, code_block)| {
+                        let path = mdbook.abs_md_file(&md_file);
+                        let url = Url::from_file_path(&path).map_err(|_| {
+                            miette::miette!("No file path: {path}", path = path.display())
+                        })?;
+                        results.extend(super::markdown::test_code_block(
+                            &url, &path, code_block, &params,
+                        )?);
+                        Ok(results)
+                    },
+                ),
+            None => Err(MdBookUnitError::NotLoaded.into()),
+        }
+    }
+}
@@ -127,6 +127,16 @@ impl Document {
         document.load_from_file()?;
         Ok(document)
     }
+
+    /// Files the document depends on.
+    ///
+    /// For sources these are all files loaded by the most recent resolve.
+    pub fn dependencies(&self) -> Vec<std::path::PathBuf> {
+        match self {
+            Document::Source(source) => source.dependencies().to_vec(),
+            _ => self.url().to_file_path().into_iter().collect(),
+        }
+    }
 }
 
 impl CaptureDiags for Document {
@@ -175,7 +185,9 @@ impl commands::compile::Parse for Document {
     fn parse(&mut self) -> Result {
         match self {
             Document::Source(source) => source.parse(),
-            _ => unimplemented!(),
+            Document::Markdown(markdown) => markdown.parse(),
+            Document::MdBook(mdbook) => mdbook.parse(),
+            Document::Builtin(_) => unimplemented!(),
         }
     }
 }
@@ -184,7 +196,9 @@ impl commands::compile::Lower for Document {
     fn lower(&mut self) -> Result {
         match self {
             Document::Source(source) => source.lower(),
-            _ => unimplemented!(),
+            Document::Markdown(markdown) => markdown.lower(),
+            Document::MdBook(mdbook) => mdbook.lower(),
+            Document::Builtin(_) => unimplemented!(),
         }
     }
 }
@@ -196,16 +210,20 @@ impl commands::compile::Resolve for Document {
     ) -> Result<Symbol> {
         match self {
             Document::Source(source) => source.resolve(parameters),
-            _ => unimplemented!(),
+            Document::Markdown(markdown) => markdown.resolve(parameters),
+            Document::MdBook(mdbook) => mdbook.resolve(parameters),
+            Document::Builtin(_) => unimplemented!(),
         }
     }
 }
 
 impl commands::compile::Eval for Document {
-    fn eval(&mut self) -> Result<Model> {
+    fn eval(&mut self, params: impl Into<commands::compile::EvalParameters>) -> Result<Model> {
         match self {
-            Document::Source(source) => source.eval(),
-            _ => unimplemented!(),
+            Document::Source(source) => source.eval(params),
+            Document::Markdown(markdown) => markdown.eval(params),
+            Document::MdBook(mdbook) => mdbook.eval(params),
+            Document::Builtin(_) => unimplemented!(),
         }
     }
 }
@@ -214,13 +232,19 @@ impl commands::Render for Document {
     fn render(&mut self, params: impl Into<commands::RenderParameters>) -> Result<Model> {
         match self {
             Document::Source(source) => source.render(params),
-            _ => unimplemented!(),
+            Document::Markdown(markdown) => markdown.render(params),
+            Document::MdBook(mdbook) => mdbook.render(params),
+            Document::Builtin(_) => unimplemented!(),
         }
     }
 }
 
 impl commands::Compile for Document {}
 
+impl commands::Info for Document {}
+
+impl commands::Validate for Document {}
+
 impl commands::Export for Document {
     fn get_export_targets(
         &self,
@@ -228,8 +252,8 @@ impl commands::Export for Document {
     ) -> Result<commands::ExportTargets> {
         match self {
             Document::Source(source) => source.get_export_targets(params),
-            Document::Markdown(_) => todo!(),
-            Document::MdBook(_) => todo!(),
+            Document::Markdown(markdown) => markdown.get_export_targets(params),
+            Document::MdBook(mdbook) => mdbook.get_export_targets(params),
             Document::Builtin(_) => todo!(),
         }
     }
@@ -264,8 +288,10 @@ impl GetSymbol for Document {
     ) -> Result<Symbol> {
         match self {
             Document::Source(asset) => asset.get_symbol(params),
-            Document::Markdown(_) => todo!(),
-            Document::MdBook(_) => todo!(),
+            Document::Markdown(_) | Document::MdBook(_) => Err(miette::miette!(
+                "Markdown document {} has no symbol",
+                self.url()
+            )),
             Document::Builtin(asset) => asset.get_symbol(params),
         }
     }
@@ -275,8 +301,10 @@ impl commands::DocGen for Document {
     fn doc_gen(&mut self, params: impl Into<commands::DocGenParameters>) -> Result {
         match self {
             Document::Source(asset) => asset.doc_gen(params),
-            Document::Markdown(_) => todo!(),
-            Document::MdBook(_) => todo!(),
+            Document::Markdown(_) | Document::MdBook(_) => Err(miette::miette!(
+                "Cannot generate documentation for markdown document {}",
+                self.url()
+            )),
             Document::Builtin(asset) => asset.doc_gen(params),
         }
     }
@@ -290,9 +318,23 @@ impl commands::PrintDiagnostics for Document {
     ) -> std::fmt::Result {
         match self {
             Document::Source(i) => i.print_diagnostics(f, options),
-            Document::Markdown(_) => todo!(),
-            Document::MdBook(_) => todo!(),
+            Document::Markdown(i) => i.print_diagnostics(f, options),
+            Document::MdBook(i) => i.print_diagnostics(f, options),
             Document::Builtin(_) => todo!(),
         }
     }
 }
+
+impl commands::Test for Document {
+    fn test(
+        &mut self,
+        params: impl Into<commands::TestParameters>,
+    ) -> Result<commands::TestResults> {
+        match self {
+            Document::Source(source) => source.test(params),
+            Document::Markdown(markdown) => markdown.test(params),
+            Document::MdBook(mdbook) => mdbook.test(params),
+            Document::Builtin(_) => unimplemented!(),
+        }
+    }
+}
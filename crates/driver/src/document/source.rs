@@ -43,6 +43,8 @@ pub struct Source {
     resolve_context: Option<ResolveContext>,
     eval_context: Option<EvalContext>,
     model: Option<Model>,
+    /// Files loaded by the most recent resolve.
+    dependencies: Vec<std::path::PathBuf>,
 }
 
 impl Source {
@@ -56,6 +58,7 @@ impl Source {
             resolve_context: None,
             eval_context: None,
             model: None,
+            dependencies: Vec::new(),
         }
     }
 
@@ -73,6 +76,11 @@ impl Source {
         self_
     }
 
+    /// Files loaded by the most recent resolve, including the document's own file.
+    pub fn dependencies(&self) -> &[std::path::PathBuf] {
+        &self.dependencies
+    }
+
     pub fn from_file(path: impl AsRef<std::path::Path>) -> Result<Self> {
         let mut s = Self::new(crate::locate::to_url(
             path.as_ref().as_os_str().to_str().unwrap(),
@@ -222,15 +230,27 @@ impl commands::compile::Resolve for document::Source {
                 self.eval_context = None;
                 self.model = None;
 
-                if let Ok(resolve_context) = ResolveContext::create(
+                let source_cache = parameters
+                    .source_cache
+                    .unwrap_or_else(|| RcMut::new(SourceCache::default()));
+                if let Ok(resolve_context) = ResolveContext::create_with_cache(
                     ir_source.clone(),
                     parameters.search_paths,
                     match parameters.no_builtin {
                         true => None,
                         false => Some(microcad_builtin::builtin_module()),
-                    },
+                    }
+                    .into_iter()
+                    .chain(parameters.plugins.module()),
                     DiagHandler::default(),
+                    &mut source_cache.borrow_mut(),
                 ) {
+                    self.dependencies = resolve_context
+                        .source_paths()
+                        .into_iter()
+                        .map(|path| path.canonicalize().unwrap_or(path))
+                        .collect();
+
                     self.diagnostics
                         .append(resolve_context.diag.diagnostics.clone());
 
@@ -251,7 +271,7 @@ impl commands::compile::Resolve for document::Source {
 }
 
 impl commands::compile::Eval for document::Source {
-    fn eval(&mut self) -> Result<Model> {
+    fn eval(&mut self, params: impl Into<commands::compile::EvalParameters>) -> Result<Model> {
         if self.resolve_context.is_none() {
             return Err(SourceError::InvalidState(self.url.clone()).into());
         }
@@ -270,6 +290,7 @@ impl commands::compile::Eval for document::Source {
             microcad_builtin::builtin_exporters(),
             microcad_builtin::builtin_importers(),
         );
+        eval_context.set_overrides(params.into().overrides);
 
         match eval_context.eval() {
             Ok(model) => {
@@ -313,10 +334,21 @@ impl commands::Render for document::Source {
             &model,
             parameters.resolution,
             parameters.cache,
-            None,
+            parameters.progress_tx,
+            parameters.cancellation,
         )) {
             use crate::prelude::RenderWithContext;
-            self.model = self.capture_diags(model.render_with_context(&mut render_context));
+            let result = model.render_with_context(&mut render_context);
+            self.diags_mut()
+                .append(std::mem::take(&mut render_context.diagnostics));
+            match result {
+                // A cancelled render is no error of the source, so keep the model to render it again.
+                Err(RenderError::Cancelled) => {
+                    self.model = Some(model);
+                    return Err(RenderError::Cancelled.into());
+                }
+                result => self.model = self.capture_diags(result),
+            }
         }
 
         if let Some(model) = &self.model {
@@ -329,6 +361,10 @@ impl commands::Render for document::Source {
 
 impl commands::Compile for document::Source {}
 
+impl commands::Info for document::Source {}
+
+impl commands::Validate for document::Source {}
+
 impl document::GetSymbol for document::Source {
     fn get_symbol(
         &mut self,
@@ -364,6 +400,114 @@ impl commands::PrintDiagnostics for document::Source {
     }
 }
 
+impl document::Source {
+    /// Run tests and prefix all test names with `name`.
+    ///
+    /// References of golden tests are named after `reference` (if given).
+    pub(crate) fn run_tests(
+        &mut self,
+        name: &str,
+        reference: Option<std::path::PathBuf>,
+        params: impl Into<commands::TestParameters>,
+    ) -> Result<commands::TestResults> {
+        use commands::{Compile, PrintDiagnostics, TestOutcome, TestResult};
+
+        let params = params.into();
+        let options = DiagRenderOptions {
+            color: false,
+            ..Default::default()
+        };
+        let mut results = commands::TestResults::default();
+
+        // Compare a model against its reference if golden tests are enabled.
+        let compare = |model: &Model, reference: Option<std::path::PathBuf>| -> Result {
+            match (&params.golden, reference) {
+                (Some(golden), Some(reference)) => golden.compare(model, &reference),
+                _ => Ok(()),
+            }
+        };
+
+        let start = std::time::Instant::now();
+        let (outcome, message) = match self
+            .compile(params.compile.clone())
+            .and_then(|model| compare(&model, reference.clone()))
+        {
+            Ok(_) => (TestOutcome::Passed, String::new()),
+            Err(err) => (
+                TestOutcome::Failed,
+                format!("{err}\n{}", self.diagnostics_string(&options)),
+            ),
+        };
+        results.push(TestResult {
+            name: name.to_string(),
+            outcome,
+            message,
+            duration: start.elapsed(),
+        });
+
+        if let Some(eval_context) = &mut self.eval_context {
+            for test in eval_context.tests() {
+                let start = std::time::Instant::now();
+                let run = eval_context
+                    .run_test(&test)
+                    .map_err(|err| miette::miette!("{err}"))?;
+                let mut message = String::new();
+                run.diagnostics
+                    .pretty_print(&mut message, &*eval_context, &options)
+                    .into_diagnostic()?;
+                let mut outcome = match run.diagnostics.has_errors() {
+                    true => TestOutcome::Failed,
+                    false => TestOutcome::Passed,
+                };
+                if let (TestOutcome::Passed, Some(model)) = (outcome, &run.model) {
+                    let reference = reference.as_ref().map(|reference| {
+                        let mut reference = reference.clone().into_os_string();
+                        reference.push(format!(".{}", test.name.replace("::", ".")));
+                        reference.into()
+                    });
+                    if let Err(err) = compare(model, reference) {
+                        outcome = TestOutcome::Failed;
+                        message = err.to_string();
+                    }
+                }
+                results.push(TestResult {
+                    name: format!("{name}::{}", test.name),
+                    outcome,
+                    message,
+                    duration: start.elapsed(),
+                });
+                self.diagnostics.append(run.diagnostics);
+            }
+        }
+
+        Ok(results)
+    }
+}
+
+impl commands::Test for document::Source {
+    fn test(
+        &mut self,
+        params: impl Into<commands::TestParameters>,
+    ) -> Result<commands::TestResults> {
+        let params = params.into();
+        match self.try_file_path() {
+            Ok(path) => {
+                let reference = params.golden.as_ref().map(|golden| {
+                    golden.reference(
+                        &path,
+                        &path.file_stem().unwrap_or_default().to_string_lossy(),
+                    )
+                });
+                self.run_tests(&path.display().to_string(), reference, params)
+            }
+            Err(_) => {
+                let name = self.url.to_string();
+                self.run_tests(&name, None, params)
+            }
+        }
+    }
+}
+
 impl commands::Export for document::Source {
     fn get_export_targets(
         &self,
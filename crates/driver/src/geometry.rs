@@ -0,0 +1,72 @@
+// Copyright © 2026 The µcad authors <info@microcad.xyz>
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+//! World space geometry of rendered models.
+
+use geo::BooleanOps;
+use microcad_core::{
+    BooleanOp, MultiPolygon, Transformed2D, Transformed3D, TriangleMesh, manifold_boolean_op,
+    mat4_to_mat3,
+};
+
+use crate::prelude::*;
+
+/// Merged geometry of a rendered model in world space.
+pub(crate) enum WorldGeometry {
+    /// 2D geometry.
+    Geometry2D(MultiPolygon),
+    /// 3D geometry.
+    Geometry3D(TriangleMesh),
+}
+
+impl WorldGeometry {
+    /// Merge the geometry of a rendered model and all its children into world space.
+    ///
+    /// If there is 3D geometry, 2D geometry is ignored.
+    pub(crate) fn from_model(model: &Model) -> Option<Self> {
+        let mut multi_polygons = Vec::new();
+        let mut meshes = Vec::new();
+        Self::for_each(model, &mut |_, geometry| match geometry {
+            WorldGeometry::Geometry2D(multi_polygon) => multi_polygons.push(multi_polygon),
+            WorldGeometry::Geometry3D(mesh) => meshes.push(mesh),
+        });
+
+        match meshes.is_empty() {
+            false => meshes
+                .iter()
+                .map(TriangleMesh::to_manifold)
+                .reduce(|lhs, rhs| manifold_boolean_op(&lhs, &rhs, &BooleanOp::Union))
+                .map(|manifold| WorldGeometry::Geometry3D(manifold.into())),
+            true => multi_polygons
+                .into_iter()
+                .reduce(|lhs, rhs| lhs.union(&rhs))
+                .map(WorldGeometry::Geometry2D),
+        }
+    }
+
+    /// Call `f` with each model which has a geometry output and its geometry in world space.
+    ///
+    /// Children of these models are not visited, because their geometry is part of the output.
+    pub(crate) fn for_each(model: &Model, f: &mut impl FnMut(&Model, Self)) {
+        let model_ = model.borrow();
+        if !matches!(model_.element(), Element::Group | Element::Multiplicity) {
+            let output = model_.output();
+            if let (Some(geometry), Some(mat)) = (&output.geometry, output.world_matrix) {
+                let geometry = match geometry {
+                    GeometryOutput::Geometry2D(geometry) => WorldGeometry::Geometry2D(
+                        geometry
+                            .transformed_2d(&mat4_to_mat3(&mat))
+                            .inner
+                            .to_multi_polygon(),
+                    ),
+                    GeometryOutput::Geometry3D(geometry) => {
+                        WorldGeometry::Geometry3D(geometry.transformed_3d(&mat).inner.into())
+                    }
+                };
+                f(model, geometry);
+                return;
+            }
+        }
+        model_.children().for_each(|model| Self::for_each(model, f));
+    }
+}
@@ -0,0 +1,192 @@
+// Copyright © 2026 The µcad authors <info@microcad.xyz>
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+//! Compare rendered models against stored reference geometries (golden files).
+
+use std::path::{Path, PathBuf};
+
+use microcad_core::{
+    CalcBounds3D, CalcDeviation, Deviation, MultiPolygon, Scalar, Triangle, TriangleMesh,
+};
+use microcad_export::stl::{StlWriter, WriteStl};
+use miette::IntoDiagnostic;
+use wkt::{ToWkt, TryFromWkt};
+
+use crate::{Result, geometry::WorldGeometry, prelude::*};
+
+/// Parameters to compare rendered models against reference geometries.
+///
+/// 2D geometries are stored as WKT `MULTIPOLYGON` and 3D geometries as ASCII STL.
+#[derive(Clone)]
+pub struct GoldenParameters {
+    /// Directory of the reference files (default: `golden` next to the tested file).
+    pub dir: Option<PathBuf>,
+    /// Write the rendered geometries as new references instead of comparing them.
+    pub bless: bool,
+    /// Render resolution.
+    pub resolution: RenderResolution,
+    /// Maximum area (2D) or volume (3D) of the symmetric difference.
+    pub difference_tolerance: Scalar,
+    /// Maximum Hausdorff distance in mm.
+    pub distance_tolerance: Scalar,
+}
+
+impl Default for GoldenParameters {
+    fn default() -> Self {
+        Self {
+            dir: None,
+            bless: false,
+            resolution: RenderResolution::default(),
+            difference_tolerance: 0.01,
+            distance_tolerance: 0.01,
+        }
+    }
+}
+
+impl GoldenParameters {
+    /// Path of the reference for test `name` of `file` (without extension).
+    pub fn reference(&self, file: &Path, name: &str) -> PathBuf {
+        self.dir
+            .clone()
+            .unwrap_or_else(|| file.parent().unwrap_or(Path::new(".")).join("golden"))
+            .join(name)
+    }
+
+    /// Render `model` and compare it against the reference at `reference` (without extension).
+    ///
+    /// Models without geometry are not compared.
+    pub fn compare(&self, model: &Model, reference: &Path) -> Result {
+        let mut render_context =
+            RenderContext::new(model, self.resolution.clone(), None, None, None)
+                .map_err(|err| miette::miette!("{err}"))?;
+        let model = model
+            .render_with_context(&mut render_context)
+            .map_err(|err| miette::miette!("{err}"))?;
+
+        match WorldGeometry::from_model(&model) {
+            Some(WorldGeometry::Geometry2D(multi_polygon)) => {
+                let path = with_suffix(reference, "wkt");
+                match self.bless {
+                    true => write(&path, multi_polygon.wkt_string()),
+                    false => self.check(
+                        multi_polygon.deviation(
+                            &MultiPolygon::try_from_wkt_str(&read(&path)?)
+                                .map_err(|err| miette::miette!("{}: {err}", path.display()))?,
+                        ),
+                        &path,
+                        "mm²",
+                    ),
+                }
+            }
+            Some(WorldGeometry::Geometry3D(mesh)) => {
+                let path = with_suffix(reference, "stl");
+                match self.bless {
+                    true => {
+                        let mut buffer = Vec::new();
+                        mesh.write_stl(&mut StlWriter::new(&mut buffer).into_diagnostic()?)
+                            .into_diagnostic()?;
+                        write(&path, String::from_utf8_lossy(&buffer).to_string())
+                    }
+                    false => self.check(mesh.deviation(&read_stl(&read(&path)?)?), &path, "mm³"),
+                }
+            }
+            None => Ok(()),
+        }
+    }
+
+    /// Fail if the deviation exceeds the tolerances.
+    fn check<B: std::fmt::Display>(
+        &self,
+        deviation: Deviation<B>,
+        path: &Path,
+        unit: &str,
+    ) -> Result {
+        if deviation.is_within(self.difference_tolerance, self.distance_tolerance) {
+            return Ok(());
+        }
+        Err(miette::miette!(
+            "Geometry deviates from reference {path}:\n  symmetric difference: {difference}{unit} (tolerance: {difference_tolerance}{unit})\n  Hausdorff distance: {distance}mm (tolerance: {distance_tolerance}mm)\n  deviation region: {region}",
+            path = path.display(),
+            difference = deviation.difference,
+            difference_tolerance = self.difference_tolerance,
+            distance = deviation.distance,
+            distance_tolerance = self.distance_tolerance,
+            region = match &deviation.region {
+                Some(region) => region.to_string(),
+                None => "[no bounds]".into(),
+            }
+        ))
+    }
+}
+
+/// Append `.suffix` to a path.
+fn with_suffix(path: &Path, suffix: &str) -> PathBuf {
+    let mut path = path.as_os_str().to_owned();
+    path.push(".");
+    path.push(suffix);
+    path.into()
+}
+
+fn read(path: &Path) -> Result<String> {
+    std::fs::read_to_string(path).map_err(|err| {
+        miette::miette!(
+            "Cannot read reference {}: {err} (use `--bless` to create it)",
+            path.display()
+        )
+    })
+}
+
+fn write(path: &Path, content: String) -> Result {
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir).into_diagnostic()?;
+    }
+    std::fs::write(path, content).into_diagnostic()
+}
+
+/// Read a triangle mesh from ASCII STL.
+fn read_stl(stl: &str) -> Result<TriangleMesh> {
+    let positions = stl
+        .lines()
+        .filter_map(|line| line.trim().strip_prefix("vertex"))
+        .map(|coords| {
+            match coords
+                .split_whitespace()
+                .map(str::parse::<f32>)
+                .collect::<std::result::Result<Vec<_>, _>>()
+                .as_deref()
+            {
+                Ok([x, y, z]) => Ok([*x, *y, *z].into()),
+                _ => Err(miette::miette!("Invalid STL vertex `{}`", coords.trim())),
+            }
+        })
+        .collect::<Result<Vec<_>>>()?;
+    if positions.len() % 3 != 0 {
+        return Err(miette::miette!("Incomplete STL triangle"));
+    }
+
+    let mut mesh = TriangleMesh {
+        triangle_indices: (0..positions.len() as u32 / 3)
+            .map(|n| Triangle(n * 3, n * 3 + 1, n * 3 + 2))
+            .collect(),
+        positions,
+        normals: None,
+    };
+    let bounds = mesh.calc_bounds_3d();
+    if bounds.is_valid() {
+        mesh.repair(&bounds);
+    }
+    Ok(mesh)
+}
+
+#[test]
+fn test_read_stl() {
+    let mut buffer = Vec::new();
+    let cube = TriangleMesh::from(microcad_core::Manifold::cube(1.0, 2.0, 3.0));
+    cube.write_stl(&mut StlWriter::new(&mut buffer).expect("STL writer"))
+        .expect("STL output");
+
+    let mesh = read_stl(&String::from_utf8_lossy(&buffer)).expect("STL mesh");
+    assert_eq!(mesh.positions.len(), 8);
+    assert_eq!(mesh.triangle_indices.len(), 12);
+    assert!((mesh.volume() - 6.0).abs() < 1e-6);
+}
@@ -6,9 +6,13 @@
 pub mod commands;
 mod config;
 pub mod document;
+mod geometry;
+mod golden;
 pub mod locate;
 pub mod prelude;
+pub mod project;
 mod session;
+mod variants;
 mod watcher;
 
 use microcad_lang::value::Value;
@@ -25,20 +29,23 @@ pub fn report(s: &str) -> Report {
 }
 
 pub use config::DriverConfig;
+pub use golden::GoldenParameters;
+pub use project::Project;
+pub use variants::{Variant, Variants};
 
 /// Parse a value from a string containing a literal.
 pub fn value_from_str(s: &str) -> Result<Value> {
-    use mu::lower::Lower;
-    use mu::parse::Parse;
-    use prelude as mu;
+    s.parse::<prelude::ir::Literal>()
+        .map_err(|err| err.into())
+        .map(|lit| lit.value().clone())
+}
 
-    let parse_context = prelude::parse::ParseContext::new(s);
-    mu::ir::Literal::lower(
-        &mu::ast::Literal::parse(&parse_context)?,
-        &mut mu::lower::LowerContext::new(s),
-    )
-    .map_err(|err| err.into())
-    .map(|lit| lit.value().clone())
+/// Parse an override for a constant or default argument from a string like `name=value`.
+pub fn override_from_str(s: &str) -> Result<(String, Value)> {
+    match s.split_once('=') {
+        Some((name, value)) => Ok((name.trim().to_string(), value_from_str(value.trim())?)),
+        None => Err(miette::miette!("Expected `name=value`, got `{s}`")),
+    }
 }
 
 /// Install standard library (if it is not installed already).
@@ -47,3 +54,60 @@ pub fn install_std() -> Result {
         .map_err(|err| miette::miette!("Could not load standard library: {err}"))
         .map(|_| ())
 }
+
+/// A plugin which has been loaded from a dynamic library with [`load_plugin`].
+///
+/// The library is unloaded when the plugin is dropped.
+/// The symbols of the plugin refer to code of the library, so they must not be used afterwards.
+pub struct PluginLibrary {
+    plugin: std::mem::ManuallyDrop<Box<dyn prelude::builtin::Plugin>>,
+    _library: libloading::Library,
+}
+
+impl prelude::builtin::Plugin for PluginLibrary {
+    fn id(&self) -> prelude::Identifier {
+        self.plugin.id()
+    }
+
+    fn module(&self) -> prelude::Symbol {
+        self.plugin.module()
+    }
+}
+
+impl Drop for PluginLibrary {
+    fn drop(&mut self) {
+        // SAFETY: The plugin is dropped only once and before its library is unloaded.
+        unsafe { std::mem::ManuallyDrop::drop(&mut self.plugin) }
+    }
+}
+
+/// Load a plugin from a dynamic library which exports a plugin with `declare_plugin!`.
+///
+/// Libraries which have been built with another compiler or µcad version are rejected.
+pub fn load_plugin(path: impl AsRef<std::path::Path>) -> Result<PluginLibrary> {
+    use microcad_lang::builtin::{PLUGIN_ENTRY, PluginEntry};
+
+    let path = path.as_ref();
+    // SAFETY: Loading a library runs its initialization code, which we have to trust like the plugin itself.
+    let library = unsafe { libloading::Library::new(path) }
+        .map_err(|err| miette::miette!("Could not load plugin {}: {err}", path.display()))?;
+    // SAFETY: The entry function has the signature `PluginEntry` if it has been exported with `declare_plugin!`.
+    let descriptor = unsafe {
+        library
+            .get::<PluginEntry>(PLUGIN_ENTRY.as_bytes())
+            .map(|entry| entry())
+    }
+    .map_err(|err| {
+        miette::miette!(
+            "{} is no µcad plugin (missing `{PLUGIN_ENTRY}`): {err}",
+            path.display()
+        )
+    })?;
+    // SAFETY: The descriptor has been returned by the entry function and the library is still loaded.
+    let plugin = unsafe { descriptor.plugin() }
+        .map_err(|err| miette::miette!("Could not load plugin {}: {err}", path.display()))?;
+    Ok(PluginLibrary {
+        plugin: std::mem::ManuallyDrop::new(plugin),
+        _library: library,
+    })
+}
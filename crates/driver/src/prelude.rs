@@ -10,7 +10,7 @@ pub mod core {
     pub use microcad_core::*;
 }
 
-pub use core::{Color, Scalar};
+pub use core::{Color, Scalar, ValidationParameters};
 
 pub mod base {
     pub use microcad_lang_base::*;
@@ -26,7 +26,7 @@ pub mod builtin {
     pub use microcad_lang::builtin::*;
 }
 
-pub use builtin::Builtin;
+pub use builtin::{Builtin, Plugin, PluginRegistry};
 
 pub mod parse {
     pub use microcad_lang_parse::*;
@@ -48,19 +48,24 @@ pub mod eval {
     pub use microcad_lang::eval::*;
 }
 
-pub use eval::{ParameterValue, ParameterValueList};
+pub use eval::{Overrides, ParameterValue, ParameterValueList};
 
 pub use microcad_lang::model::{Creator, Element, Model, OutputType};
 pub use microcad_lang::render::{
-    GeometryOutput, ProgressTx, RenderCache, RenderContext, RenderResolution, RenderWithContext,
+    CancellationToken, GeometryOutput, ProgressTx, RenderCache, RenderContext, RenderError,
+    RenderProgress, RenderResolution, RenderWithContext,
 };
+pub use microcad_lang::resolve::SourceCache;
 pub use microcad_lang::ty::Type;
 
 pub use microcad_lang::symbol::{Symbol, SymbolDef};
 
 pub use crate::config::DriverConfig;
 pub use crate::document::Document;
+pub use crate::golden::GoldenParameters;
+pub use crate::project::Project;
 pub use crate::session::Session;
+pub use crate::variants::{Variant, Variants};
 pub use crate::watcher::Watcher;
 
 pub use crate::{Report, Result, report};
@@ -70,10 +75,15 @@ pub use crate::document;
 
 pub use crate::install_std;
 pub use crate::locate;
+pub use crate::override_from_str;
+pub use crate::project;
+pub use crate::{PluginLibrary, load_plugin};
 
 pub use crate::commands::{
-    CompileParameters, DocGen, DocGenParameters, Export, ExportCommand, ExportParameters, Format,
-    FormatParameters, PrintDiagnosticsParameters, RenderParameters, Sync, compile::Parse,
+    CompileParameters, DocGen, DocGenParameters, DocumentInfo, Export, ExportCommand,
+    ExportParameters, Format, FormatParameters, InfoParameters, ModelInfo,
+    PrintDiagnosticsParameters, RenderParameters, Sync, TestOutcome, TestParameters, TestResult,
+    TestResults, ValidateParameters, compile::EvalParameters, compile::Parse,
     compile::ResolveParameters,
 };
 
@@ -84,8 +94,8 @@ pub mod traits {
     pub use microcad_lang::{doc::Doc, ty::Ty};
 
     pub use super::commands::{
-        Compile, DocGen, Export, Format, LoadFromFile, PrintDiagnostics, Render, Sync,
-        compile::Eval, compile::Lower, compile::Parse, compile::Resolve,
+        Compile, DocGen, Export, Format, Info, LoadFromFile, PrintDiagnostics, Render, Sync, Test,
+        Validate, compile::Eval, compile::Lower, compile::Parse, compile::Resolve,
     };
     pub use super::document::{CaptureDiags, GetCode, GetSymbol};
 }
@@ -0,0 +1,229 @@
+// Copyright © 2026 The µcad authors <info@microcad.xyz>
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+//! Project manifest `microcad.toml`.
+
+use std::path::{Path, PathBuf};
+
+use miette::Diagnostic;
+use serde::Deserialize;
+use thiserror::Error;
+
+use microcad_core::Scalar;
+
+use crate::DriverConfig;
+
+/// File name of the project manifest.
+pub const PROJECT_MANIFEST: &str = "microcad.toml";
+
+/// Project error.
+#[derive(Error, Debug, Diagnostic)]
+pub enum ProjectError {
+    /// An IO error while reading the project manifest.
+    #[error("I/O error while reading {0}: {1}")]
+    Io(PathBuf, std::io::Error),
+
+    /// A TOML parse error in the project manifest.
+    #[error("Failed to parse {0}: {1}")]
+    Toml(PathBuf, toml::de::Error),
+
+    /// The manifest of a dependency could not be loaded.
+    #[error("Dependency `{name}`: {error}")]
+    Manifest {
+        /// Name of the dependency.
+        name: String,
+        /// Manifest error.
+        error: microcad_std::ManifestError,
+    },
+
+    /// The directory of a dependency is not named like the dependency.
+    #[error("Dependency `{name}` must be a directory named `{name}`: {path}")]
+    NameMismatch {
+        /// Name of the dependency.
+        name: String,
+        /// Directory of the dependency.
+        path: PathBuf,
+    },
+
+    /// The version of a dependency does not match the requirement.
+    #[error("Dependency `{name}` requires version {requirement} but found {version} in {path}")]
+    VersionMismatch {
+        /// Name of the dependency.
+        name: String,
+        /// Directory of the dependency.
+        path: PathBuf,
+        /// Required version.
+        requirement: semver::VersionReq,
+        /// Version in the dependency's manifest.
+        version: semver::Version,
+    },
+}
+
+/// Project descriptor in `[project]`.
+#[derive(Deserialize, Debug)]
+pub struct ProjectInfo {
+    /// Name of the project.
+    pub name: String,
+    /// Version of the project.
+    #[serde(default)]
+    pub version: Option<semver::Version>,
+    /// Search paths relative to the project directory.
+    #[serde(default)]
+    pub search_paths: Vec<PathBuf>,
+}
+
+/// Dependency on a local µcad library.
+///
+/// The library is a directory named like the dependency which contains a `manifest.toml`.
+#[derive(Deserialize, Debug)]
+pub struct Dependency {
+    /// Directory of the library relative to the project directory.
+    pub path: PathBuf,
+    /// Version requirement, e.g. `^1.2`.
+    #[serde(default)]
+    pub version: Option<semver::VersionReq>,
+}
+
+/// Export settings in `[export]`.
+///
+/// Each given setting replaces the one of the driver config, missing ones are kept.
+#[derive(Deserialize, Debug, Default)]
+pub struct ProjectExport {
+    /// Default sketch exporter.
+    pub sketch: Option<String>,
+    /// Default part exporter.
+    pub part: Option<String>,
+    /// Default render resolution in mm.
+    pub resolution: Option<Scalar>,
+}
+
+/// Project manifest.
+///
+/// ```toml
+/// [project]
+/// name = "bracket"
+/// search_paths = ["lib"]
+///
+/// [export]
+/// sketch = "svg"
+/// part = "stl"
+/// resolution = 0.1
+///
+/// [dependencies]
+/// fasteners = { path = "vendor/fasteners", version = "^1.2" }
+/// ```
+#[derive(Deserialize, Debug)]
+pub struct ProjectManifest {
+    /// Project descriptor.
+    pub project: ProjectInfo,
+    /// Export settings which override the settings of the driver config.
+    #[serde(default)]
+    pub export: ProjectExport,
+    /// Dependencies by name.
+    #[serde(default)]
+    pub dependencies: std::collections::BTreeMap<String, Dependency>,
+}
+
+/// A project loaded from its manifest.
+#[derive(Debug)]
+pub struct Project {
+    /// Directory of the project manifest.
+    pub dir: PathBuf,
+    /// The parsed manifest.
+    pub manifest: ProjectManifest,
+}
+
+impl Project {
+    /// Find the project manifest in `dir` or any of its parent directories.
+    pub fn find(dir: impl AsRef<Path>) -> Option<PathBuf> {
+        dir.as_ref()
+            .ancestors()
+            .map(|dir| dir.join(PROJECT_MANIFEST))
+            .find(|path| path.is_file())
+    }
+
+    /// Load a project manifest file.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, ProjectError> {
+        let path = path.as_ref();
+        let content = std::fs::read_to_string(path)
+            .map_err(|err| ProjectError::Io(path.to_path_buf(), err))?;
+        Ok(Self {
+            dir: path.parent().unwrap_or(Path::new(".")).to_path_buf(),
+            manifest: toml::from_str(&content)
+                .map_err(|err| ProjectError::Toml(path.to_path_buf(), err))?,
+        })
+    }
+
+    /// Return the search paths of the project followed by the ones of its dependencies.
+    ///
+    /// Fails if a dependency has no manifest or does not meet its version requirement.
+    pub fn search_paths(&self) -> Result<Vec<PathBuf>, ProjectError> {
+        let mut search_paths: Vec<_> = self
+            .manifest
+            .project
+            .search_paths
+            .iter()
+            .map(|path| self.dir.join(path))
+            .collect();
+
+        self.manifest
+            .dependencies
+            .iter()
+            .try_for_each(|(name, dependency)| {
+                let path = self.dir.join(&dependency.path);
+                if path.file_name() != Some(std::ffi::OsStr::new(name)) {
+                    return Err(ProjectError::NameMismatch {
+                        name: name.clone(),
+                        path,
+                    });
+                }
+
+                let manifest = microcad_std::Manifest::load(&path).map_err(|error| {
+                    ProjectError::Manifest {
+                        name: name.clone(),
+                        error,
+                    }
+                })?;
+                if let Some(requirement) = &dependency.version
+                    && !requirement.matches(&manifest.library.version)
+                {
+                    return Err(ProjectError::VersionMismatch {
+                        name: name.clone(),
+                        path,
+                        requirement: requirement.clone(),
+                        version: manifest.library.version,
+                    });
+                }
+
+                // Libraries are found by their directory name within the search path.
+                let search_path = path.parent().unwrap_or(Path::new(".")).to_path_buf();
+                if !search_paths.contains(&search_path) {
+                    search_paths.push(search_path);
+                }
+                Ok(())
+            })?;
+
+        Ok(search_paths)
+    }
+
+    /// Apply project settings to a driver config.
+    ///
+    /// The project's search paths precede the ones of the config.
+    pub fn apply(&self, config: &mut DriverConfig) -> Result<(), ProjectError> {
+        let mut search_paths = self.search_paths()?;
+        search_paths.append(&mut config.search_paths);
+        config.search_paths = search_paths;
+
+        let export = &self.manifest.export;
+        if let Some(sketch) = &export.sketch {
+            config.export.sketch = sketch.clone();
+        }
+        if let Some(part) = &export.part {
+            config.export.part = part.clone();
+        }
+        if let Some(resolution) = export.resolution {
+            config.export.resolution = resolution;
+        }
+        Ok(())
+    }
+}
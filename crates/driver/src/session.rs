@@ -1,17 +1,36 @@
 // Copyright © 2026 The µcad authors <info@microcad.xyz>
 // SPDX-License-Identifier: AGPL-3.0-or-later
 
-use microcad_lang_base::HashMap;
+use microcad_lang_base::ResourceLocation;
 
-use crate::prelude::*;
+use crate::{
+    commands::{Compile, LoadFromFile},
+    prelude::*,
+};
 
-#[derive(Default)]
+/// Several open documents which share resources.
+///
+/// All documents share the parsed files of the search paths, the resolved standard library and the render cache.
+/// Each document gets its own copy of the resolved standard library,
+/// because evaluation stores values and usage in the symbols.
 pub struct Session {
     pub documents: HashMap<Url, Document>,
 
     pub render_cache: Option<RcMut<RenderCache>>,
 
+    /// Parsed files of the search paths.
+    pub source_cache: RcMut<SourceCache>,
+
     pub config: DriverConfig,
+
+    /// Documents which need to be compiled.
+    outdated: HashSet<Url>,
+}
+
+impl Default for Session {
+    fn default() -> Self {
+        Self::new(DriverConfig::default())
+    }
 }
 
 impl Session {
@@ -19,15 +38,105 @@ impl Session {
         Self {
             documents: HashMap::default(),
             render_cache: Some(RcMut::new(RenderCache::new())),
+            source_cache: RcMut::new(SourceCache::default()),
             config,
+            outdated: HashSet::default(),
+        }
+    }
+
+    /// Load a document and add it to the session.
+    ///
+    /// If the document is already open, it is returned unchanged.
+    pub fn add_document(&mut self, url: Url) -> Result<&mut Document> {
+        if !self.documents.contains_key(&url) {
+            let document = Document::load(url.clone())?;
+            self.documents.insert(url.clone(), document);
+            self.outdated.insert(url.clone());
         }
+        Ok(self.documents.get_mut(&url).expect("Document"))
+    }
+
+    /// Add a document which has already been loaded, e.g. from unsaved code.
+    ///
+    /// An open document with the same URL is replaced.
+    pub fn insert_document(&mut self, document: Document) -> &mut Document {
+        let url = document.url().clone();
+        self.outdated.insert(url.clone());
+        self.documents.insert(url.clone(), document);
+        self.documents.get_mut(&url).expect("Document")
+    }
+
+    /// Remove a document from the session.
+    pub fn remove_document(&mut self, url: &Url) -> Option<Document> {
+        self.outdated.remove(url);
+        self.documents.remove(url)
     }
 
-    pub fn add_document(&mut self, _url: Url) -> &mut Document {
-        todo!()
+    /// Return `true` if the document needs to be compiled.
+    pub fn is_outdated(&self, url: &Url) -> bool {
+        self.outdated.contains(url)
     }
 
-    pub fn remove_document(&mut self, _url: Url) -> Document {
-        todo!()
+    /// Compile parameters which use the search paths of the config and the shared source cache.
+    pub fn compile_parameters(&self) -> CompileParameters {
+        CompileParameters {
+            resolve: ResolveParameters {
+                search_paths: self.config.search_paths.clone(),
+                source_cache: Some(self.source_cache.clone()),
+                ..Default::default()
+            },
+            ..Default::default()
+        }
+    }
+
+    /// Render parameters which use the resolution of the export config and the shared render cache.
+    pub fn render_parameters(&self) -> RenderParameters {
+        RenderParameters {
+            resolution: self.config.export.render_resolution(),
+            cache: self.render_cache.clone(),
+            ..Default::default()
+        }
+    }
+
+    /// Notify the session that a file has changed.
+    ///
+    /// Documents which depend on the file will be compiled with the next call of [`Session::compile`].
+    /// Documents of the file itself are loaded again.
+    /// Returns the URLs of the affected documents.
+    pub fn invalidate(&mut self, path: impl AsRef<std::path::Path>) -> Result<Vec<Url>> {
+        let path = path.as_ref();
+        let path = path.canonicalize().unwrap_or(path.to_path_buf());
+        self.source_cache.borrow_mut().remove(&path);
+
+        let mut affected = Vec::new();
+        for (url, document) in self.documents.iter_mut() {
+            if url.to_file_path().is_ok_and(|file_path| file_path == path) {
+                document.load_from_file()?;
+            } else if !document.dependencies().contains(&path) {
+                continue;
+            }
+            self.outdated.insert(url.clone());
+            affected.push(url.clone());
+        }
+        affected.sort();
+        Ok(affected)
+    }
+
+    /// Compile all outdated documents.
+    ///
+    /// Returns the URLs and results of the compiled documents.
+    /// Diagnostics are kept in each document.
+    pub fn compile(&mut self) -> Vec<(Url, Result<Model>)> {
+        let mut outdated: Vec<_> = self.outdated.drain().collect();
+        outdated.sort();
+
+        let parameters = self.compile_parameters();
+        outdated
+            .into_iter()
+            .filter_map(|url| {
+                let document = self.documents.get_mut(&url)?;
+                Some((url, document.compile(parameters.clone())))
+            })
+            .collect()
     }
 }
@@ -0,0 +1,134 @@
+// Copyright © 2026 The µcad authors <info@microcad.xyz>
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+//! Parameter sets to export several variants of a design.
+
+use microcad_import::toml::TomlImporter;
+use microcad_lang::value::Value;
+use miette::IntoDiagnostic;
+
+use crate::Result;
+
+/// Key of the output filename template within a variant table.
+const OUTPUT_KEY: &str = "output";
+
+/// A named parameter set.
+#[derive(Debug)]
+pub struct Variant {
+    /// Name of the variant (the name of its table).
+    pub name: String,
+    /// Output file name with all placeholders replaced.
+    pub output: std::path::PathBuf,
+    /// Values which replace constants or default arguments.
+    pub overrides: Vec<(String, Value)>,
+}
+
+/// Parameter sets loaded from a TOML file.
+///
+/// Each table defines a variant with an `output` file name template and overrides:
+///
+/// ```toml
+/// [small]
+/// output = "bracket_{Bracket::width}.stl"
+/// "Bracket::width" = "4mm"
+/// HOLES = 2
+/// ```
+///
+/// String values are parsed as µcad literals, so units can be given and strings must be quoted.
+/// Placeholders like `{Bracket::width}` in the template are replaced with the value of the override.
+/// Variants are kept in the order of the file.
+#[derive(Debug, derive_more::Deref)]
+pub struct Variants(Vec<Variant>);
+
+impl Variants {
+    /// Load variants from a TOML file.
+    pub fn load(path: impl AsRef<std::path::Path>) -> Result<Self> {
+        let content = std::fs::read_to_string(path).into_diagnostic()?;
+        content.parse()
+    }
+}
+
+impl std::str::FromStr for Variants {
+    type Err = miette::Report;
+
+    fn from_str(s: &str) -> Result<Self> {
+        toml::from_str::<toml::Table>(s)
+            .into_diagnostic()?
+            .iter()
+            .map(|(name, table)| match table {
+                toml::Value::Table(table) => Variant::from_table(name, table),
+                _ => Err(miette::miette!("Variant `{name}` must be a table")),
+            })
+            .collect::<Result<_>>()
+            .map(Self)
+    }
+}
+
+impl Variant {
+    fn from_table(name: &str, table: &toml::Table) -> Result<Self> {
+        let overrides = table
+            .iter()
+            .filter(|(key, _)| key.as_str() != OUTPUT_KEY)
+            .map(|(key, value)| {
+                let value = match value {
+                    toml::Value::String(s) => crate::value_from_str(s).map_err(|err| {
+                        miette::miette!("Invalid value for `{key}` in variant `{name}`: {err}")
+                    })?,
+                    value => TomlImporter::toml_to_value(value).map_err(|err| {
+                        miette::miette!("Invalid value for `{key}` in variant `{name}`: {err}")
+                    })?,
+                };
+                Ok((key.clone(), value))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let output = match table.get(OUTPUT_KEY) {
+            Some(toml::Value::String(template)) => overrides
+                .iter()
+                .fold(template.clone(), |output, (key, value)| {
+                    output.replace(&format!("{{{key}}}"), &value.to_string())
+                }),
+            _ => {
+                return Err(miette::miette!(
+                    "Variant `{name}` needs an `{OUTPUT_KEY}` file name"
+                ));
+            }
+        };
+
+        Ok(Self {
+            name: name.to_string(),
+            output: output.into(),
+            overrides,
+        })
+    }
+}
+
+#[test]
+fn test_variants() {
+    let variants: Variants = r#"
+        [small]
+        output = "bracket_{Bracket::width}_{HOLES}.stl"
+        "Bracket::width" = "4mm"
+        HOLES = 2
+
+        [large]
+        output = "bracket_large.stl"
+        "Bracket::width" = "8mm"
+    "#
+    .parse()
+    .expect("valid variants");
+
+    let names: Vec<_> = variants.iter().map(|v| v.name.as_str()).collect();
+    assert_eq!(names, ["small", "large"]);
+
+    let small = &variants[0];
+    assert_eq!(small.output, std::path::PathBuf::from("bracket_4mm_2.stl"));
+    assert_eq!(small.overrides.len(), 2);
+
+    assert!("[small]\nwidth = \"4mm\"".parse::<Variants>().is_err());
+    assert!(
+        "[small]\noutput = \"a.stl\"\nwidth = \"[foo]\""
+            .parse::<Variants>()
+            .is_err()
+    );
+}
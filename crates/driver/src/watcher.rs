@@ -3,9 +3,15 @@
 
 //! µcad CLI watcher. Most parts proudly taken from [typst](https://github.com/typst/typst/blob/main/crates/typst-cli/src/watch.rs)
 
-use std::{iter, path::PathBuf, sync::mpsc::Receiver, time::Duration};
+use microcad_lang::render::CancellationToken;
 use miette::IntoDiagnostic;
 use notify::{Event, RecommendedWatcher};
+use std::{
+    iter,
+    path::PathBuf,
+    sync::{Arc, Mutex, mpsc::Receiver},
+    time::Duration,
+};
 
 /// Watches file system activity.
 pub struct Watcher {
@@ -20,6 +26,8 @@ pub struct Watcher {
     /// A set of files that should be watched, but don't exist. We manually poll
     /// for those.
     missing: std::collections::HashSet<PathBuf>,
+    /// Token which is cancelled by the next relevant event.
+    cancellation: Arc<Mutex<CancellationToken>>,
 }
 
 impl Watcher {
@@ -46,16 +54,40 @@ impl Watcher {
         // this only affects a tiny number of systems. Most do not use the
         // [`notify::PollWatcher`].
         let config = notify::Config::default().with_poll_interval(Self::POLL_INTERVAL);
-        let watcher = RecommendedWatcher::new(tx, config).into_diagnostic()?;
+
+        // Cancel a running render as soon as a relevant event arrives.
+        let cancellation = Arc::new(Mutex::new(CancellationToken::default()));
+        let handler = {
+            let cancellation = cancellation.clone();
+            move |event: notify::Result<Event>| {
+                if let Ok(event) = &event
+                    && Self::is_relevant_event_kind(&event.kind)
+                {
+                    cancellation.lock().expect("No poisoned lock").cancel();
+                }
+                tx.send(event).ok();
+            }
+        };
+        let watcher = RecommendedWatcher::new(handler, config).into_diagnostic()?;
 
         Ok(Self {
             rx,
             watcher,
             watched: std::collections::HashMap::new(),
             missing: std::collections::HashSet::new(),
+            cancellation,
         })
     }
 
+    /// Return a new token which is cancelled by the next change to a watched path.
+    ///
+    /// Pass it to the render parameters to stop rendering outdated sources.
+    pub fn cancellation_token(&self) -> CancellationToken {
+        let token = CancellationToken::default();
+        *self.cancellation.lock().expect("No poisoned lock") = token.clone();
+        token
+    }
+
     /// Update the watching to watch exactly the listed files.
     ///
     /// Files that are not yet watched will be watched. Files that are already
@@ -85,7 +117,9 @@ impl Watcher {
 
             // Watch the path if it's not already watched.
             if !self.watched.contains_key(&path) {
-                self.watcher.watch(&path, RecursiveMode::NonRecursive).into_diagnostic()?;
+                self.watcher
+                    .watch(&path, RecursiveMode::NonRecursive)
+                    .into_diagnostic()?;
             }
 
             // Mark the file as "seen" so that we don't unwatch it.
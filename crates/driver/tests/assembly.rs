@@ -0,0 +1,74 @@
+// Copyright © 2026 The µcad authors <info@microcad.xyz>
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+mod common;
+
+use microcad_driver::prelude as mu;
+
+use miette::IntoDiagnostic;
+use mu::traits::*;
+
+const SOURCE: &str = r#"
+#[bom(name = "Base plate", stock = "PETG")]
+part Plate(size: Length) {
+    __builtin::geo3d::Cone(radius_bottom = size, radius_top = size, height = 2mm);
+}
+
+#[bom(name = "M3x10 DIN 912", stock = "steel")]
+part Screw() {
+    __builtin::geo3d::Cone(radius_bottom = 1.5mm, radius_top = 1.5mm, height = 10mm);
+}
+
+assembly Fixture() {
+    Plate(40mm);
+    Screw().__builtin::ops::translate(x = [-10mm, 10mm], y = 0mm, z = 2mm);
+}
+
+Fixture();
+"#;
+
+/// Compile and render the assembly and export it into `output`.
+fn export(output: &str) -> mu::Result<String> {
+    let (dir, file_path) = common::write_file("fixture.µcad", SOURCE)?;
+    let mut document = common::compile(&file_path)?;
+    document.render(mu::RenderParameters::default())?;
+
+    let output_path = dir.path().join(output);
+    document.export(mu::ExportParameters {
+        input_path: file_path,
+        output_path: Some(output_path.clone()),
+        config: Default::default(),
+    })?;
+    std::fs::read_to_string(output_path).into_diagnostic()
+}
+
+#[test]
+fn test_assembly_bom() -> mu::Result {
+    let csv = export("fixture.csv")?;
+    assert_eq!(
+        csv.lines().collect::<Vec<_>>(),
+        [
+            "name,parameters,count,material",
+            "Base plate,(size=40mm),1,PETG",
+            "M3x10 DIN 912,(),2,steel",
+        ]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_assembly_stl() -> mu::Result {
+    let stl = export("fixture.stl")?;
+    let solids: Vec<_> = stl
+        .lines()
+        .filter(|line| line.starts_with("solid "))
+        .collect();
+    assert_eq!(solids, ["solid Plate_0", "solid Screw_1", "solid Screw_2"]);
+    assert_eq!(
+        stl.lines()
+            .filter(|line| line.starts_with("endsolid "))
+            .count(),
+        3
+    );
+    Ok(())
+}
@@ -0,0 +1,50 @@
+// Copyright © 2026 The µcad authors <info@microcad.xyz>
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+//! Helpers shared by the driver tests.
+
+#![allow(dead_code)]
+
+use microcad_driver::prelude as mu;
+
+use miette::IntoDiagnostic;
+use mu::traits::*;
+
+/// Write `content` into a file `name` in a new temporary directory.
+///
+/// Returns the directory, which is removed when dropped, and the path of the file.
+pub fn write_file(
+    name: &str,
+    content: &str,
+) -> mu::Result<(tempfile::TempDir, std::path::PathBuf)> {
+    let dir = tempfile::tempdir().into_diagnostic()?;
+    let file_path = dir.path().join(name);
+    std::fs::write(&file_path, content).into_diagnostic()?;
+    Ok((dir, file_path))
+}
+
+/// Compile parameters without search paths, so only built-in symbols are available.
+pub fn compile_parameters() -> mu::CompileParameters {
+    mu::CompileParameters {
+        resolve: mu::ResolveParameters {
+            search_paths: vec![],
+            ..Default::default()
+        },
+        ..Default::default()
+    }
+}
+
+/// Load a document from `file_path` and compile it with [`compile_parameters`].
+pub fn compile(file_path: &std::path::Path) -> mu::Result<mu::Document> {
+    let mut document = mu::Document::from_file(file_path)?;
+    document.compile(compile_parameters())?;
+    Ok(document)
+}
+
+/// Return the diagnostics of a document without colors.
+pub fn diagnostics(document: &mu::Document) -> String {
+    document.diagnostics_string(&mu::PrintDiagnosticsParameters {
+        color: false,
+        unicode: false,
+    })
+}
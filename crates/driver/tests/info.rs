@@ -0,0 +1,40 @@
+// Copyright © 2026 The µcad authors <info@microcad.xyz>
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+mod common;
+
+use microcad_driver::prelude as mu;
+
+use mu::traits::*;
+
+const SOURCE: &str = r#"
+__builtin::geo2d::Rect(width = 10mm, height = 20mm, x = 0mm, y = 0mm);
+__builtin::geo2d::Rect(width = 10mm, height = 10mm, x = 10mm, y = 0mm);
+"#;
+
+#[test]
+fn test_info() -> mu::Result {
+    let (_dir, file_path) = common::write_file("plate.µcad", SOURCE)?;
+    let info = mu::Document::from_file(&file_path)?.info(mu::InfoParameters {
+        compile: common::compile_parameters(),
+        render: Default::default(),
+        export: mu::ExportParameters {
+            input_path: file_path.clone(),
+            output_path: None,
+            config: Default::default(),
+        },
+    })?;
+
+    assert_eq!(info.models.len(), 1);
+    let model = &info.models[0];
+    assert_eq!(model.target, file_path.with_extension("svg"));
+    assert_eq!(model.output_type, "2D");
+    assert!((model.area.expect("area") - 300.0).abs() < 1e-9);
+    assert!(model.volume.is_none());
+    assert!(model.render_time.expect("render time") <= info.render_time);
+
+    let bounds = model.bounds.as_ref().expect("bounds");
+    assert_eq!(bounds.min, vec![0.0, 0.0]);
+    assert_eq!(bounds.max, vec![20.0, 20.0]);
+    Ok(())
+}
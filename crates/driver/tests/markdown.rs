@@ -0,0 +1,95 @@
+// Copyright © 2026 The µcad authors <info@microcad.xyz>
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+mod common;
+
+use microcad_driver::prelude as mu;
+
+use mu::traits::*;
+
+const DESIGN: &str = r#"# Design
+
+A square:
+
+```µcad,square
+__builtin::geo2d::Rect(width = 10mm, height = 10mm, x = 0mm, y = 0mm);
+```
+
+A disc:
+
+```µcad,disc
+__builtin::geo3d::Cone(radius_bottom = 10mm, radius_top = 10mm, height = 1mm);
+```
+
+```µcad,broken#fail
+undefined_symbol();
+```
+"#;
+
+const BROKEN: &str = r#"# Broken
+
+```µcad,broken
+undefined_symbol();
+```
+"#;
+
+#[test]
+fn test_markdown_export_targets() -> mu::Result {
+    let (_dir, file_path) = common::write_file("design.md", DESIGN)?;
+    let mut document = common::compile(&file_path)?;
+    document.render(mu::RenderParameters::default())?;
+
+    let targets = document.get_export_targets(mu::ExportParameters {
+        input_path: file_path.clone(),
+        output_path: None,
+        config: Default::default(),
+    })?;
+    let mut names: Vec<_> = targets
+        .iter()
+        .map(|(_, command)| {
+            assert_eq!(command.filename.parent(), file_path.parent());
+            command
+                .filename
+                .file_stem()
+                .unwrap_or_default()
+                .to_string_lossy()
+                .to_string()
+        })
+        .collect();
+    names.sort();
+    assert_eq!(names, ["design.disc", "design.square"]);
+    Ok(())
+}
+
+#[test]
+fn test_markdown_diagnostics() -> mu::Result {
+    let (_dir, file_path) = common::write_file("broken.md", BROKEN)?;
+    let mut document = mu::Document::from_file(&file_path)?;
+    assert!(document.compile(common::compile_parameters()).is_err());
+    assert!(document.diags().has_errors());
+
+    // `undefined_symbol();` is in line 4 of the markdown file.
+    assert!(common::diagnostics(&document).contains("broken.md:4:"));
+    Ok(())
+}
+
+#[test]
+fn test_markdown_unsupported() -> mu::Result {
+    let (_dir, file_path) = common::write_file("design.md", DESIGN)?;
+    let mut document = mu::Document::from_file(&file_path)?;
+    assert!(
+        document
+            .get_symbol(mu::ResolveParameters::default())
+            .is_err()
+    );
+    assert!(
+        document
+            .doc_gen(mu::DocGenParameters {
+                generator_id: Default::default(),
+                output_path: None,
+                resolve_parameters: mu::ResolveParameters::default(),
+            })
+            .is_err()
+    );
+    Ok(())
+}
@@ -0,0 +1,60 @@
+// Copyright © 2026 The µcad authors <info@microcad.xyz>
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+mod common;
+
+use microcad_driver::prelude as mu;
+
+use mu::traits::*;
+
+const SOURCE: &str = r#"
+const SIZE = 10mm;
+
+sketch Plate(width = 1mm) {
+    __builtin::debug::assert_eq([width, 4mm]);
+    __builtin::geo2d::Circle(radius = SIZE);
+}
+
+__builtin::debug::assert_eq([SIZE, 20mm]);
+Plate();
+"#;
+
+/// Compile [`SOURCE`] with overrides given as `name=value`.
+fn compile(overrides: &[&str]) -> mu::Result {
+    let (_dir, file_path) = common::write_file("plate.µcad", SOURCE)?;
+    mu::Document::from_file(&file_path)?
+        .compile(mu::CompileParameters {
+            eval: mu::EvalParameters {
+                overrides: overrides
+                    .iter()
+                    .map(|s| mu::override_from_str(s))
+                    .collect::<mu::Result<_>>()?,
+            },
+            ..common::compile_parameters()
+        })
+        .map(|_| ())
+}
+
+#[test]
+fn test_overrides() {
+    assert!(compile(&["SIZE=20mm", "Plate::width=4mm"]).is_ok());
+
+    // Assertions fail without overrides.
+    assert!(compile(&[]).is_err());
+
+    // Types must match the declaration.
+    assert!(compile(&["SIZE=20", "Plate::width=4mm"]).is_err());
+    assert!(compile(&["SIZE=20mm", "Plate::width=4°"]).is_err());
+}
+
+#[test]
+fn test_override_from_str() {
+    let (name, value) = mu::override_from_str("Plate::width = 4mm").expect("valid override");
+    assert_eq!(name, "Plate::width");
+    assert_eq!(
+        value,
+        microcad_driver::value_from_str("4mm").expect("valid value")
+    );
+
+    assert!(mu::override_from_str("width").is_err());
+}
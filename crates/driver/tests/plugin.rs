@@ -0,0 +1,106 @@
+// Copyright © 2026 The µcad authors <info@microcad.xyz>
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+mod common;
+
+use microcad_driver::prelude as mu;
+
+use microcad_lang::{
+    builtin::{ModuleBuilder, PluginAbiError, PluginDescriptor},
+    parameter,
+    value::{Value, ValueAccess},
+};
+use mu::traits::*;
+
+/// Plugin with a function which doubles a length.
+struct Scale;
+
+impl mu::Plugin for Scale {
+    fn id(&self) -> mu::Identifier {
+        mu::Identifier::no_ref("scale")
+    }
+
+    fn module(&self) -> mu::Symbol {
+        ModuleBuilder::new(self.id())
+            .plugin_fn(
+                "double",
+                [parameter!(x: Length)].into_iter().collect(),
+                |args, _| Ok((args.get_value("x")?.clone() * Value::Integer(2))?),
+            )
+            .build()
+    }
+}
+
+fn compile(source: &str) -> mu::Result {
+    let (_dir, file_path) = common::write_file("plugin.µcad", source)?;
+    let parameters = common::compile_parameters();
+    mu::Document::from_file(&file_path)?
+        .compile(mu::CompileParameters {
+            resolve: mu::ResolveParameters {
+                plugins: mu::PluginRegistry::default().insert(Scale),
+                ..parameters.resolve
+            },
+            ..parameters
+        })
+        .map(|_| ())
+}
+
+#[test]
+fn test_plugin() {
+    assert!(
+        compile(
+            r#"
+extern mod scale;
+__builtin::debug::assert_eq([scale::double(x = 2mm), 4mm]);
+"#
+        )
+        .is_ok()
+    );
+
+    // Arguments are type checked against the parameters of the plugin function.
+    assert!(
+        compile(
+            r#"
+extern mod scale;
+scale::double(x = 2°);
+"#
+        )
+        .is_err()
+    );
+}
+
+#[test]
+fn test_load_plugin_error() {
+    assert!(mu::load_plugin("no_such_plugin.so").is_err());
+}
+
+extern "C" fn create_scale() -> *mut std::ffi::c_void {
+    let plugin: Box<dyn mu::Plugin> = Box::new(Scale);
+    Box::into_raw(Box::new(plugin)).cast()
+}
+
+#[test]
+fn test_plugin_descriptor() {
+    // SAFETY: `create_scale` returns a boxed plugin like `declare_plugin!`.
+    let plugin = unsafe { PluginDescriptor::new(create_scale).plugin() }.expect("plugin");
+    assert_eq!(plugin.id(), mu::Identifier::no_ref("scale"));
+
+    // Plugins of other versions are rejected before they are created.
+    let descriptor = PluginDescriptor {
+        abi_version: 0,
+        ..PluginDescriptor::new(create_scale)
+    };
+    assert!(matches!(
+        unsafe { descriptor.plugin() },
+        Err(PluginAbiError::AbiVersion { found: 0 })
+    ));
+
+    let descriptor = PluginDescriptor {
+        rustc_version: c"rustc 1.0.0".as_ptr(),
+        ..PluginDescriptor::new(create_scale)
+    };
+    assert!(matches!(
+        unsafe { descriptor.plugin() },
+        Err(PluginAbiError::RustcVersion { .. })
+    ));
+}
@@ -0,0 +1,90 @@
+// Copyright © 2026 The µcad authors <info@microcad.xyz>
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+use microcad_driver::prelude as mu;
+
+use miette::IntoDiagnostic;
+use mu::traits::*;
+
+/// Create a project with a vendored library `fasteners` in version `1.2.3`.
+fn project(requirement: &str) -> mu::Result<tempfile::TempDir> {
+    let dir = tempfile::tempdir().into_diagnostic()?;
+    let library = dir.path().join("vendor").join("fasteners");
+    std::fs::create_dir_all(&library).into_diagnostic()?;
+    std::fs::write(
+        library.join("manifest.toml"),
+        "[library]\nversion = \"1.2.3\"\n",
+    )
+    .into_diagnostic()?;
+    std::fs::write(
+        library.join("mod.µcad"),
+        "pub fn size() {\n    return 3;\n}\n",
+    )
+    .into_diagnostic()?;
+
+    std::fs::write(
+        dir.path().join(mu::project::PROJECT_MANIFEST),
+        format!(
+            r#"[project]
+name = "bracket"
+search_paths = ["lib"]
+
+[export]
+resolution = 0.5
+
+[dependencies]
+fasteners = {{ path = "vendor/fasteners", version = "{requirement}" }}
+"#
+        ),
+    )
+    .into_diagnostic()?;
+    std::fs::create_dir_all(dir.path().join("src")).into_diagnostic()?;
+    Ok(dir)
+}
+
+#[test]
+fn test_project() {
+    let dir = project("^1.2").expect("project");
+
+    let path = mu::Project::find(dir.path().join("src")).expect("project manifest");
+    let project = mu::Project::load(path).expect("project");
+    assert_eq!(project.manifest.project.name, "bracket");
+
+    let mut config = mu::DriverConfig::default();
+    config.export.part = "obj".into();
+    project.apply(&mut config).expect("valid dependencies");
+    assert_eq!(config.search_paths[0], dir.path().join("lib"));
+    assert_eq!(config.search_paths[1], dir.path().join("vendor"));
+    assert_eq!(config.export.render_resolution().linear, 0.5);
+    // Settings which are missing in the project are kept.
+    assert_eq!(config.export.part, "obj");
+
+    // Use the library.
+    let file_path = dir.path().join("src").join("main.µcad");
+    std::fs::write(
+        &file_path,
+        "__builtin::debug::assert_eq([fasteners::size(), 3]);\n",
+    )
+    .expect("source file");
+    mu::Document::from_file(&file_path)
+        .expect("document")
+        .compile(mu::CompileParameters {
+            resolve: mu::ResolveParameters {
+                search_paths: project.search_paths().expect("search paths"),
+                ..Default::default()
+            },
+            ..Default::default()
+        })
+        .expect("compiled");
+}
+
+#[test]
+fn test_project_version_mismatch() {
+    let dir = project("^2").expect("project");
+    let project =
+        mu::Project::load(dir.path().join(mu::project::PROJECT_MANIFEST)).expect("project");
+    assert!(matches!(
+        project.search_paths(),
+        Err(mu::project::ProjectError::VersionMismatch { .. })
+    ));
+}
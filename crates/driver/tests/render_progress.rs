@@ -0,0 +1,52 @@
+// Copyright © 2026 The µcad authors <info@microcad.xyz>
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+mod common;
+
+use microcad_driver::prelude as mu;
+
+use mu::traits::*;
+
+const SOURCE: &str = r#"
+{
+    __builtin::geo2d::Rect(width = 10mm, height = 10mm, x = 0mm, y = 0mm);
+    __builtin::geo2d::Rect(width = 10mm, height = 10mm, x = 5mm, y = 0mm);
+}.__builtin::ops::union();
+"#;
+
+#[test]
+fn test_render_progress() -> mu::Result {
+    let (_dir, file_path) = common::write_file("union.µcad", SOURCE)?;
+
+    let (tx, rx) = std::sync::mpsc::channel();
+    common::compile(&file_path)?.render(mu::RenderParameters::default().with_progress(tx))?;
+
+    let events: Vec<_> = rx.iter().collect();
+    assert!(events.iter().any(|event| matches!(
+        event,
+        mu::RenderProgress::BooleanOp {
+            op: mu::core::BooleanOp::Union,
+            operands: 2
+        }
+    )));
+    let last = events.iter().rev().find_map(mu::RenderProgress::percent);
+    assert_eq!(last, Some(100.0));
+    Ok(())
+}
+
+#[test]
+fn test_render_cancellation() -> mu::Result {
+    let (_dir, file_path) = common::write_file("union.µcad", SOURCE)?;
+
+    let cancellation = mu::CancellationToken::default();
+    cancellation.cancel();
+
+    let err = common::compile(&file_path)?
+        .render(mu::RenderParameters::default().with_cancellation(cancellation))
+        .expect_err("Render must be cancelled");
+    assert!(matches!(
+        err.downcast_ref::<mu::RenderError>(),
+        Some(mu::RenderError::Cancelled)
+    ));
+    Ok(())
+}
@@ -0,0 +1,86 @@
+// Copyright © 2026 The µcad authors <info@microcad.xyz>
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+mod common;
+
+use microcad_driver::prelude as mu;
+
+use common::diagnostics;
+use mu::traits::*;
+
+/// Compile and render `source` and return the document.
+fn render(source: &str) -> mu::Result<mu::Document> {
+    let (_dir, file_path) = common::write_file("warnings.µcad", source)?;
+    let mut document = common::compile(&file_path)?;
+    document.render(mu::RenderParameters::default())?;
+    Ok(document)
+}
+
+#[test]
+fn test_loft_heights_warning() -> mu::Result {
+    let document = render(
+        r#"
+{
+    __builtin::geo2d::Rect(width = 10mm, height = 10mm, x = 0mm, y = 0mm);
+    __builtin::geo2d::Rect(width = 5mm, height = 5mm, x = 0mm, y = 0mm);
+}.__builtin::ops::loft(heights = [0mm]);
+"#,
+    )?;
+    assert_eq!(document.diags().warning_count(), 1);
+    assert!(diagnostics(&document).contains("Loft has 2 profiles but 1 heights"));
+    Ok(())
+}
+
+#[test]
+fn test_sweep_path_warning() -> mu::Result {
+    // There is no path child.
+    let document = render(
+        r#"
+__builtin::geo2d::Rect(width = 1mm, height = 1mm, x = 0mm, y = 0mm)
+    .__builtin::ops::sweep(closed = false, twist = 0°);
+"#,
+    )?;
+    assert_eq!(document.diags().warning_count(), 1);
+    assert!(diagnostics(&document).contains("Sweep path needs at least two points"));
+
+    // Explicit points are checked at the call.
+    assert!(
+        render(
+            r#"
+__builtin::geo2d::Rect(width = 1mm, height = 1mm, x = 0mm, y = 0mm)
+    .__builtin::ops::sweep(points = [(x = 0mm, y = 0mm, z = 0mm)], closed = false, twist = 0°);
+"#,
+        )
+        .is_err()
+    );
+    Ok(())
+}
+
+#[test]
+fn test_extrude_edge_warning() -> mu::Result {
+    let document = render(
+        r#"
+__builtin::geo2d::Rect(width = 10mm, height = 10mm, x = 0mm, y = 0mm)
+    .__builtin::ops::extrude(
+        height = 5mm, scale_x = 1.0, scale_y = 1.0, twist = 0°,
+        bottom_fillet = 0mm, bottom_chamfer = 0mm, top_fillet = 1mm, top_chamfer = 1mm
+    );
+"#,
+    )?;
+    assert_eq!(document.diags().warning_count(), 1);
+    assert!(diagnostics(&document).contains("Extrude edge has a fillet and a chamfer"));
+    Ok(())
+}
+
+#[test]
+fn test_join_warning() -> mu::Result {
+    let document = render(
+        r#"
+__builtin::geo2d::Polyline(points = [(x = 0mm, y = 0mm), (x = 10mm, y = 0mm)], closed = false)
+    .__builtin::ops::join();
+"#,
+    )?;
+    assert_eq!(document.diags().warning_count(), 1);
+    assert!(diagnostics(&document).contains("Joined paths need at least three points"));
+    Ok(())
+}
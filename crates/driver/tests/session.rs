@@ -0,0 +1,121 @@
+// Copyright © 2026 The µcad authors <info@microcad.xyz>
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+use microcad_driver::prelude as mu;
+
+use mu::traits::*;
+
+use miette::IntoDiagnostic;
+
+const LIBRARY: &str = "pub const SIZE = 10mm;\n";
+
+const SQUARE: &str =
+    "__builtin::geo2d::Rect(width = sizes::SIZE, height = sizes::SIZE, x = 0mm, y = 0mm);\n";
+
+const CIRCLE: &str = "__builtin::geo2d::Circle(radius = sizes::SIZE);\n";
+
+/// Standard library with a warning about the implicit return of `Dot`.
+const STD: &str = r#"
+pub const SIZE = 10mm;
+
+pub sketch Dot() {
+    __builtin::geo2d::Circle(radius = SIZE)
+}
+"#;
+
+const DOT: &str = "std::Dot();\n";
+
+fn url(path: &std::path::Path) -> mu::Result<mu::Url> {
+    let path = path.canonicalize().into_diagnostic()?;
+    mu::Url::from_file_path(&path).map_err(|_| mu::report("invalid file path"))
+}
+
+#[test]
+fn test_session() -> mu::Result {
+    let dir = tempfile::tempdir().into_diagnostic()?;
+    let lib_path = dir.path().join("lib");
+    std::fs::create_dir(&lib_path).into_diagnostic()?;
+    let library = lib_path.join("sizes.µcad");
+    std::fs::write(&library, LIBRARY).into_diagnostic()?;
+
+    let square = dir.path().join("square.µcad");
+    std::fs::write(&square, SQUARE).into_diagnostic()?;
+    let circle = dir.path().join("circle.µcad");
+    std::fs::write(&circle, CIRCLE).into_diagnostic()?;
+
+    let mut session = mu::Session::new(mu::DriverConfig {
+        search_paths: vec![lib_path],
+        ..Default::default()
+    });
+    let (square, circle) = (url(&square)?, url(&circle)?);
+    session.add_document(square.clone())?;
+    session.add_document(circle.clone())?;
+
+    let results = session.compile();
+    assert_eq!(results.len(), 2);
+    assert!(results.iter().all(|(_, result)| result.is_ok()));
+    assert_eq!(session.source_cache.borrow().len(), 1);
+    assert!(!session.is_outdated(&square));
+
+    // Changing a document only affects the document itself.
+    std::fs::write(square.to_file_path().unwrap(), SQUARE).into_diagnostic()?;
+    assert_eq!(
+        session.invalidate(square.to_file_path().unwrap())?,
+        vec![square.clone()]
+    );
+    assert!(session.is_outdated(&square));
+    assert!(!session.is_outdated(&circle));
+    assert_eq!(session.compile().len(), 1);
+
+    // Changing the library affects all documents.
+    let mut expected = vec![square, circle.clone()];
+    expected.sort();
+    assert_eq!(session.invalidate(&library)?, expected);
+    assert_eq!(session.source_cache.borrow().len(), 0);
+    assert_eq!(session.compile().len(), 2);
+
+    // Unsaved code replaces the open document.
+    let code = mu::document::Source::from_source(mu::base::Source {
+        url: circle.clone(),
+        line_offset: 0,
+        code: mu::Hashed::new(SQUARE.to_string()),
+    });
+    session.insert_document(Box::new(code).into());
+    assert!(session.is_outdated(&circle));
+    assert_eq!(session.documents.len(), 2);
+    let results = session.compile();
+    assert_eq!(results.len(), 1);
+    assert!(results[0].1.is_ok());
+
+    Ok(())
+}
+
+#[test]
+fn test_session_std() -> mu::Result {
+    let dir = tempfile::tempdir().into_diagnostic()?;
+    let lib_path = dir.path().join("lib");
+    std::fs::create_dir(&lib_path).into_diagnostic()?;
+    std::fs::write(lib_path.join("std.µcad"), STD).into_diagnostic()?;
+
+    let mut session = mu::Session::new(mu::DriverConfig {
+        search_paths: vec![lib_path],
+        ..Default::default()
+    });
+    let mut urls = Vec::new();
+    for name in ["a.µcad", "b.µcad"] {
+        let path = dir.path().join(name);
+        std::fs::write(&path, DOT).into_diagnostic()?;
+        urls.push(url(&path)?);
+        session.add_document(urls.last().unwrap().clone())?;
+    }
+
+    // The second document takes the standard library and its warning from the cache.
+    let results = session.compile();
+    assert!(results.iter().all(|(_, result)| result.is_ok()));
+    assert_eq!(session.source_cache.borrow().len(), 1);
+    for url in &urls {
+        assert_eq!(session.documents[url].diags().warning_count(), 1);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,135 @@
+// Copyright © 2026 The µcad authors <info@microcad.xyz>
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+mod common;
+
+use microcad_driver::prelude as mu;
+
+use miette::IntoDiagnostic;
+use mu::traits::*;
+
+const SOURCE: &str = r#"
+fn double(n: Integer) {
+    return n * 2;
+}
+
+#[test]
+fn test_double() {
+    __builtin::debug::assert_eq([double(2), 4]);
+}
+
+#[test]
+fn test_double_fails() {
+    __builtin::debug::assert_eq([double(2), 5]);
+}
+
+#[test]
+sketch TestCircle() {
+    __builtin::geo2d::Circle(radius = 1mm);
+}
+
+double(1);
+"#;
+
+const MARKDOWN: &str = r#"# Tests
+
+```µcad,ok
+__builtin::debug::assert_eq([1 + 1, 2]);
+```
+
+```µcad,fails#fail
+__builtin::debug::assert_eq([1 + 1, 3]);
+```
+
+```µcad,later#todo
+unknown();
+```
+
+```µcad
+// unnamed code blocks are no tests
+unknown();
+```
+"#;
+
+/// Write `content` into a file `name` and run its tests.
+fn test(name: &str, content: &str) -> mu::Result<Vec<(String, mu::TestOutcome)>> {
+    let (_dir, file_path) = common::write_file(name, content)?;
+    let results = mu::Document::from_file(&file_path)?.test(common::compile_parameters())?;
+
+    Ok(results
+        .iter()
+        .map(|result| {
+            let (_, name) = result.name.rsplit_once(['/', '\\']).expect("absolute path");
+            (name.to_string(), result.outcome)
+        })
+        .collect())
+}
+
+#[test]
+fn test_source_tests() {
+    use mu::TestOutcome::*;
+    assert_eq!(
+        test("double.µcad", SOURCE).expect("tests"),
+        vec![
+            ("double.µcad".into(), Passed),
+            ("double.µcad::test_double".into(), Passed),
+            ("double.µcad::test_double_fails".into(), Failed),
+            ("double.µcad::TestCircle".into(), Passed),
+        ]
+    );
+}
+
+#[test]
+fn test_markdown_tests() {
+    use mu::TestOutcome::*;
+    assert_eq!(
+        test("README.md", MARKDOWN).expect("tests"),
+        vec![
+            ("README.md#ok".into(), Passed),
+            ("README.md#fails".into(), Passed),
+            ("README.md#later".into(), Skipped),
+        ]
+    );
+}
+
+/// Run the tests of a sketch with the given radius against references in `dir`.
+fn golden(dir: &std::path::Path, radius: &str, bless: bool) -> mu::Result<mu::TestResults> {
+    let file_path = dir.join("circle.µcad");
+    std::fs::write(
+        &file_path,
+        format!("#[test]\nsketch TestCircle() {{\n    __builtin::geo2d::Circle(radius = {radius});\n}}\n"),
+    )
+    .into_diagnostic()?;
+
+    mu::Document::from_file(&file_path)?.test(mu::TestParameters {
+        compile: common::compile_parameters(),
+        golden: Some(mu::GoldenParameters {
+            bless,
+            ..Default::default()
+        }),
+    })
+}
+
+#[test]
+fn test_golden() {
+    let dir = tempfile::tempdir().expect("temporary directory");
+
+    // no reference yet
+    let results = golden(dir.path(), "10mm", false).expect("tests");
+    assert_eq!(results[1].outcome, mu::TestOutcome::Failed);
+
+    let results = golden(dir.path(), "10mm", true).expect("tests");
+    assert!(results.success());
+    assert!(dir.path().join("golden/circle.TestCircle.wkt").exists());
+
+    let results = golden(dir.path(), "10mm", false).expect("tests");
+    assert!(results.success());
+
+    let results = golden(dir.path(), "11mm", false).expect("tests");
+    assert_eq!(results[1].outcome, mu::TestOutcome::Failed);
+    assert!(
+        results[1]
+            .message
+            .contains("Geometry deviates from reference")
+    );
+}
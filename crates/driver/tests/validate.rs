@@ -0,0 +1,47 @@
+// Copyright © 2026 The µcad authors <info@microcad.xyz>
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+mod common;
+
+use microcad_driver::prelude as mu;
+
+use mu::traits::*;
+
+const SOURCE: &str = r#"
+__builtin::geo3d::Cone(radius_bottom = 10mm, radius_top = 10mm, height = 0.5mm);
+"#;
+
+fn validate(
+    file_path: &std::path::Path,
+    validation: mu::ValidationParameters,
+) -> mu::Result<(usize, mu::Document)> {
+    let mut document = mu::Document::from_file(file_path)?;
+    let count = document.validate(mu::ValidateParameters {
+        compile: common::compile_parameters(),
+        render: Default::default(),
+        validation,
+    })?;
+    Ok((count, document))
+}
+
+#[test]
+fn test_validate() -> mu::Result {
+    let (_dir, file_path) = common::write_file("disc.µcad", SOURCE)?;
+
+    let (count, document) = validate(&file_path, Default::default())?;
+    assert_eq!(count, 0);
+    assert_eq!(document.diags().warning_count(), 0);
+
+    let (count, document) = validate(
+        &file_path,
+        mu::ValidationParameters {
+            min_thickness: Some(1.0),
+            min_hole: None,
+        },
+    )?;
+    assert_eq!(count, 1);
+    assert_eq!(document.diags().warning_count(), 1);
+
+    assert!(common::diagnostics(&document).contains("wall thickness below minimum"));
+    Ok(())
+}
@@ -0,0 +1,48 @@
+// Copyright © 2026 The µcad authors <info@microcad.xyz>
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+//! Bill of materials (BOM) exporter.
+
+use microcad_lang::{
+    builtin::{ExportError, Exporter, FileIoInterface},
+    eval::ParameterValueList,
+    model::{Bom, Model},
+    parameter,
+    value::Value,
+};
+
+use microcad_lang_base::Id;
+
+/// Exports the bill of materials of all assemblies in a model as CSV.
+pub struct BomExporter;
+
+impl Exporter for BomExporter {
+    /// Parts can be described with `#[bom(name = "M3x10 DIN 912", stock = "steel")]`.
+    fn model_parameters(&self) -> ParameterValueList {
+        [
+            parameter!(name: String = String::new()),
+            parameter!(stock: String = String::new()),
+        ]
+        .into_iter()
+        .collect()
+    }
+
+    fn export(&self, model: &Model, filename: &std::path::Path) -> Result<Value, ExportError> {
+        log::debug!("Exporting bill of materials into {filename:?}");
+        let bom = Bom::new(model);
+        log::trace!("Bill of materials:\n{bom}");
+        let f = std::fs::File::create(filename)?;
+        bom.write_csv(&mut std::io::BufWriter::new(f))?;
+        Ok(Value::None)
+    }
+}
+
+impl FileIoInterface for BomExporter {
+    fn id(&self) -> Id {
+        Id::new("bom")
+    }
+
+    fn file_extensions(&self) -> Vec<Id> {
+        vec![Id::new("csv")]
+    }
+}
@@ -19,8 +19,21 @@ pub struct StlExporter;
 impl Exporter for StlExporter {
     fn export(&self, model: &Model, filename: &std::path::Path) -> Result<Value, ExportError> {
         let mut f = std::fs::File::create(filename)?;
-        let mut writer = StlWriter::new(&mut f)?;
-        model.write_stl(&mut writer)?;
+        let parts = model.assembly_parts();
+        if parts.is_empty() {
+            let mut writer = StlWriter::new(&mut f)?;
+            model.write_stl(&mut writer)?;
+        } else {
+            // Write each part of an assembly as a separate solid.
+            parts.iter().enumerate().try_for_each(|(n, part)| {
+                let name = match part.part_name() {
+                    Some(name) => format!("{name}_{n}"),
+                    None => format!("part_{n}"),
+                };
+                let mut writer = StlWriter::with_name(&mut f, &name)?;
+                part.write_stl(&mut writer)
+            })?;
+        }
         Ok(Value::None)
     }
 
@@ -8,14 +8,25 @@ use microcad_core::*;
 /// Write into STL file
 pub struct StlWriter<'a> {
     writer: &'a mut dyn std::io::Write,
+    name: String,
 }
 
 impl<'a> StlWriter<'a> {
     /// Create new STL writer
-    pub fn new(mut w: &'a mut dyn std::io::Write) -> std::io::Result<Self> {
-        writeln!(&mut w, "solid")?;
+    pub fn new(w: &'a mut dyn std::io::Write) -> std::io::Result<Self> {
+        Self::with_name(w, "")
+    }
+
+    /// Create new STL writer for a named solid.
+    ///
+    /// Several named solids can be written into the same file one after another.
+    pub fn with_name(mut w: &'a mut dyn std::io::Write, name: &str) -> std::io::Result<Self> {
+        writeln!(&mut w, "{}", format!("solid {name}").trim_end())?;
 
-        Ok(Self { writer: w })
+        Ok(Self {
+            writer: w,
+            name: name.to_string(),
+        })
     }
 
     /// Write triangle
@@ -46,6 +57,11 @@ impl<'a> StlWriter<'a> {
 
 impl Drop for StlWriter<'_> {
     fn drop(&mut self) {
-        writeln!(self.writer, "endsolid").expect("No error");
+        writeln!(
+            self.writer,
+            "{}",
+            format!("endsolid {}", self.name).trim_end()
+        )
+        .expect("No error");
     }
 }
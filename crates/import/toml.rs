@@ -10,30 +10,36 @@ use microcad_lang_base::{Id, SrcRef};
 pub struct TomlImporter;
 
 impl TomlImporter {
-    fn toml_to_value(toml: &toml::Value) -> Value {
-        match toml {
+    /// Convert a TOML value into a µcad value.
+    ///
+    /// Returns an error for values without a µcad counterpart (e.g. dates).
+    pub fn toml_to_value(toml: &toml::Value) -> Result<Value, ImportError> {
+        Ok(match toml {
             toml::Value::String(s) => Value::String(s.clone()),
             toml::Value::Integer(i) => Value::Integer(*i),
             toml::Value::Float(f) => (*f).into(),
             toml::Value::Boolean(b) => Value::Bool(*b),
-            toml::Value::Datetime(_) => todo!(),
-            toml::Value::Array(values) => {
-                let mut list = Vec::new();
-                for toml_value in values {
-                    list.push(Self::toml_to_value(toml_value));
-                }
-                Value::Array(Array::from_values(
-                    ValueList::new(list),
-                    microcad_lang::ty::Type::Invalid, // TODO get common type here.
-                ))
+            toml::Value::Datetime(datetime) => {
+                return Err(ImportError::UnsupportedValueType(format!(
+                    "TOML datetime `{datetime}`"
+                )));
             }
+            toml::Value::Array(values) => Value::Array(Array::from_values(
+                ValueList::new(
+                    values
+                        .iter()
+                        .map(Self::toml_to_value)
+                        .collect::<Result<_, _>>()?,
+                ),
+                microcad_lang::ty::Type::Invalid, // TODO get common type here.
+            )),
             toml::Value::Table(map) => Value::Tuple(Box::new(Tuple::new_named(
                 map.iter()
-                    .map(|(k, v)| (Identifier::no_ref(k), Self::toml_to_value(v)))
-                    .collect(),
+                    .map(|(k, v)| Ok((Identifier::no_ref(k), Self::toml_to_value(v)?)))
+                    .collect::<Result<_, ImportError>>()?,
                 SrcRef::none(),
             ))),
-        }
+        })
     }
 }
 
@@ -45,10 +51,10 @@ impl Importer for TomlImporter {
         let filename = args.get::<String>("filename");
         let content = std::fs::read_to_string(filename)?;
 
-        Ok(Self::toml_to_value(
+        Self::toml_to_value(
             &toml::from_str::<toml::Value>(&content)
                 .map_err(|err| ImportError::CustomError(miette::Report::from_err(err)))?,
-        ))
+        )
     }
 }
 
@@ -98,6 +98,8 @@ pub enum WorkbenchKind {
     Part,
     /// `op`
     Op,
+    /// `assembly`
+    Assembly,
 }
 
 impl std::fmt::Display for WorkbenchKind {
@@ -109,6 +111,7 @@ impl std::fmt::Display for WorkbenchKind {
                 WorkbenchKind::Sketch => "sketch",
                 WorkbenchKind::Part => "part",
                 WorkbenchKind::Op => "op",
+                WorkbenchKind::Assembly => "assembly",
             }
         )
     }
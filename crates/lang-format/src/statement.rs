@@ -80,6 +80,17 @@ impl Format for ast::def::FileModule {
     }
 }
 
+impl Format for ast::def::ExternModule {
+    fn format(&self, f: &FormatConfig) -> Node {
+        let vis = self.vis.as_ref().map(|vis| vis.value.clone());
+        node!(f, self.extras =>
+            self.doc
+            self.attr
+            vis "extern mod " self.id
+        )
+    }
+}
+
 impl Format for ast::def::Function {
     fn format(&self, f: &FormatConfig) -> Node {
         let return_type = match &self.return_type {
@@ -138,6 +149,16 @@ impl Format for ast::def::Constant {
     }
 }
 
+impl Format for ast::def::Unit {
+    fn format(&self, f: &FormatConfig) -> Node {
+        node!(f, self.extras =>
+            self.doc
+            self.attr
+            "unit " self.id " = " self.value
+        )
+    }
+}
+
 impl Format for ast::Init {
     fn format(&self, f: &FormatConfig) -> Node {
         node!(f, self.extras =>
@@ -254,6 +275,8 @@ impl Format for ast::Statement {
             Self::Workbench(workbench_definition) => workbench_definition.format(f),
             Self::InlineModule(inline_module) => inline_module.format(f),
             Self::FileModule(file_module) => file_module.format(f),
+            Self::ExternModule(extern_module) => extern_module.format(f),
+            Self::Unit(unit) => unit.format(f),
             Self::Function(function_definition) => function_definition.format(f),
             Self::InnerDocComment(comment) => comment.format(f),
             Self::Use(use_statement) => use_statement.format(f),
@@ -13,7 +13,8 @@
 //! * An `id` (except Use definitions)
 
 use crate::ast::{
-    Attribute, Body, DocBlock, Expression, Identifier, ItemExtras, ParameterList, Span, Type,
+    Attribute, Body, DocBlock, Expression, Identifier, ItemExtras, Literal, ParameterList, Span,
+    Type,
 };
 
 use microcad_lang_base::Spanned;
@@ -70,6 +71,32 @@ pub struct FileModule {
     pub id: Identifier,
 }
 
+/// A declaration of a module which is provided by a plugin
+#[derive(Debug, PartialEq)]
+#[allow(missing_docs)]
+pub struct ExternModule {
+    pub span: Span,
+    pub keyword_span: Span,
+    pub extras: ItemExtras,
+    pub doc: DocBlock,
+    pub attr: Vec<Attribute>,
+    pub vis: Option<Spanned<Visibility>>,
+    pub id: Identifier,
+}
+
+/// A definition of a unit: `unit U = 44.45mm;`
+#[derive(Debug, PartialEq)]
+#[allow(missing_docs)]
+pub struct Unit {
+    pub span: Span,
+    pub keyword_span: Span,
+    pub extras: ItemExtras,
+    pub doc: DocBlock,
+    pub attr: Vec<Attribute>,
+    pub id: Identifier,
+    pub value: Literal,
+}
+
 /// A definition of a function
 #[derive(Debug, PartialEq)]
 #[allow(missing_docs)]
@@ -21,6 +21,10 @@ pub enum Statement {
     InlineModule(def::InlineModule),
     /// File Module: `mod foo;`
     FileModule(def::FileModule),
+    /// Extern Module: `extern mod foo;`
+    ExternModule(def::ExternModule),
+    /// Unit definition: `unit U = 44.45mm;`
+    Unit(def::Unit),
     /// Function statement: `fn bar() { ... }`
     Function(def::Function),
     /// Use statement: `use foo::bar;`
@@ -54,6 +58,8 @@ impl Statement {
             Workbench(st) => st.span.clone(),
             InlineModule(st) => st.span.clone(),
             FileModule(st) => st.span.clone(),
+            ExternModule(st) => st.span.clone(),
+            Unit(st) => st.span.clone(),
             Function(st) => st.span.clone(),
             Use(st) => st.span.clone(),
             Const(st) => st.span.clone(),
@@ -83,6 +89,8 @@ impl Statement {
             Statement::Const(_) => true,
             Statement::Return(_) => true,
             Statement::FileModule(_) => true,
+            Statement::ExternModule(_) => true,
+            Statement::Unit(_) => true,
             Statement::LocalAssignment(_) => true,
             Statement::Property(_) => true,
             Statement::Expression(e) => {
@@ -131,14 +131,11 @@ fn parser<'tokens>()
     let reserved_keyword = select_ref! {
         token @ (
             Token::KeywordPlugin |
-            Token::KeywordAssembly |
             Token::KeywordMaterial |
-            Token::KeywordUnit |
             Token::KeywordEnum |
             Token::KeywordStruct |
             Token::KeywordMatch |
-            Token::KeywordType |
-            Token::KeywordExtern
+            Token::KeywordType
         ) => token.kind(),
     }
     .boxed();
@@ -148,6 +145,9 @@ fn parser<'tokens>()
             Token::KeywordPart |
             Token::KeywordSketch |
             Token::KeywordOp |
+            Token::KeywordAssembly |
+            Token::KeywordExtern |
+            Token::KeywordUnit |
             Token::KeywordFn |
             Token::KeywordIf |
             Token::KeywordElse |
@@ -852,6 +852,59 @@ fn parser<'tokens>()
             })
             .boxed();
 
+        let extern_module = doc_block
+            .clone()
+            .then(outer_attribute_parser.clone())
+            .then(visibility.then_whitespace().or_not())
+            .then(just(Token::KeywordExtern).map_with(|_, e| e.span()))
+            .then_whitespace()
+            .then_ignore(just(Token::KeywordMod))
+            .then_whitespace()
+            .then(
+                identifier_parser.clone().recover_with(via_parser(
+                    recovery_expect_any_except(&[Token::SigilSemiColon])
+                        .map_with(|_, e| ast::Identifier::dummy(e.span())),
+                )),
+            )
+            .with_extras()
+            .map_with(|(((((doc, attr), vis), keyword_span), id), extras), e| {
+                ast::Statement::ExternModule(ast::def::ExternModule {
+                    span: e.span(),
+                    keyword_span,
+                    extras,
+                    doc,
+                    attr,
+                    vis,
+                    id,
+                })
+            })
+            .boxed();
+
+        let unit_definition = doc_block
+            .clone()
+            .then(outer_attribute_parser.clone())
+            .then(just(Token::KeywordUnit).map_with(|_, e| e.span()))
+            .then_whitespace()
+            .then(identifier_parser.clone())
+            .then_maybe_whitespace()
+            .then_ignore(just(Token::OperatorAssignment))
+            .then_maybe_whitespace()
+            .then(ast::Literal::parser())
+            .with_extras()
+            .map_with(|(((((doc, attr), keyword_span), id), value), extras), e| {
+                ast::Statement::Unit(ast::def::Unit {
+                    span: e.span(),
+                    keyword_span,
+                    extras,
+                    doc,
+                    attr,
+                    id,
+                    value,
+                })
+            })
+            .labelled("unit definition")
+            .boxed();
+
         let use_part = identifier_parser
             .clone()
             .map(ast::def::UseStatementPart::Identifier)
@@ -911,6 +964,7 @@ fn parser<'tokens>()
             Token::KeywordSketch => ast::def::WorkbenchKind::Sketch,
             Token::KeywordPart => ast::def::WorkbenchKind::Part,
             Token::KeywordOp => ast::def::WorkbenchKind::Op,
+            Token::KeywordAssembly => ast::def::WorkbenchKind::Assembly,
         }
         .boxed();
 
@@ -1109,6 +1163,8 @@ fn parser<'tokens>()
             .or(const_assignment)
             .or(pub_assignment)
             .or(file_module)
+            .or(extern_module)
+            .or(unit_definition)
             .or(property_assignment)
             .or(local_assignment)
             .or(expression)
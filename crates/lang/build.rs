@@ -0,0 +1,18 @@
+// Copyright © 2026 The µcad authors <info@microcad.xyz>
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+//! Build script for microcad lang
+
+fn main() {
+    // Plugin libraries are only compatible if they have been built with the same compiler.
+    let rustc = std::env::var("RUSTC").unwrap_or("rustc".to_string());
+    let output = std::process::Command::new(rustc)
+        .arg("--version")
+        .output()
+        .expect("rustc version");
+    println!(
+        "cargo:rustc-env=MICROCAD_RUSTC_VERSION={}",
+        String::from_utf8_lossy(&output.stdout).trim()
+    );
+    println!("cargo:rerun-if-env-changed=RUSTC");
+}
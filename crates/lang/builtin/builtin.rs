@@ -27,7 +27,7 @@ pub struct BuiltinFunction {
     pub parameters: ParameterValueList,
 
     /// Functor to evaluate this function
-    pub f: &'static BuiltinFn,
+    pub f: std::rc::Rc<BuiltinFn>,
 
     /// Functor which returns documentation of this function
     pub doc: Option<ir::DocBlock>,
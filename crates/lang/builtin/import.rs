@@ -33,6 +33,10 @@ pub enum ImportError {
     #[error("Multiple importers for file extension: {0:?}")]
     MultipleImportersForFileExtension(Vec<Id>),
 
+    /// A value of the file cannot be converted into a µcad value.
+    #[error("Unsupported value type: {0}")]
+    UnsupportedValueType(String),
+
     /// Custom error.
     #[error("{0}")]
     #[diagnostic(transparent)]
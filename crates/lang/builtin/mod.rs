@@ -11,6 +11,7 @@ pub mod file_io;
 pub mod import;
 pub mod module_builder;
 pub mod operation;
+pub mod plugin;
 pub mod workpiece;
 
 pub use builtin::*;
@@ -18,6 +19,7 @@ pub use export::*;
 pub use file_io::*;
 pub use import::*;
 pub use module_builder::*;
+pub use plugin::*;
 pub use workpiece::*;
 
 use microcad_core::*;
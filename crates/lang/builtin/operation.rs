@@ -15,6 +15,11 @@ impl Operation for BooleanOp {
             let model_ = model.borrow();
             let geometries: Geometries2D = model_.children.render_with_context(context)?;
 
+            context.check_cancelled()?;
+            context.send_progress(|| RenderProgress::BooleanOp {
+                op: *self,
+                operands: geometries.len(),
+            });
             Ok(Geometry2D::MultiPolygon(geometries.boolean_op(self)))
         })
     }
@@ -25,6 +30,11 @@ impl Operation for BooleanOp {
             let model_ = model.borrow();
             let geometries: Geometries3D = model_.children.render_with_context(context)?;
 
+            context.check_cancelled()?;
+            context.send_progress(|| RenderProgress::BooleanOp {
+                op: *self,
+                operands: geometries.len(),
+            });
             Ok(Geometry3D::Manifold(geometries.boolean_op(self)))
         })
     }
@@ -93,6 +103,23 @@ impl BuiltinWorkbenchDefinition for Intersect {
     }
 }
 
+/// Symmetric difference operation.
+pub struct Xor;
+
+impl BuiltinWorkbenchDefinition for Xor {
+    fn id() -> &'static str {
+        "xor"
+    }
+
+    fn kind() -> BuiltinWorkbenchKind {
+        BuiltinWorkbenchKind::Operation
+    }
+
+    fn workpiece_function() -> &'static BuiltinWorkpieceFn {
+        &|_| Ok(BuiltinWorkpieceOutput::Operation(Box::new(BooleanOp::Xor)))
+    }
+}
+
 /// An operation that repeats a geometry n-1 times.
 pub struct Multiply;
 
@@ -156,7 +183,7 @@ impl From<BooleanOp> for BuiltinWorkpiece {
             BooleanOp::Intersect => {
                 Intersect::workpiece(Creator::new(Intersect::symbol(), Tuple::default()))
             }
-            BooleanOp::Complement => unimplemented!(),
+            BooleanOp::Xor => Xor::workpiece(Creator::new(Xor::symbol(), Tuple::default())),
         }
     }
 }
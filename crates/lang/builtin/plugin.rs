@@ -0,0 +1,253 @@
+// Copyright © 2026 The µcad authors <info@microcad.xyz>
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+//! Plugins provide built-in symbols from outside of the builtin library.
+
+use std::rc::Rc;
+
+use microcad_lang_base::{Identifier, PushDiag};
+use thiserror::Error;
+
+use crate::{builtin::*, eval::*, symbol::Symbol, value::*};
+
+/// Name of the root module which contains the modules of all plugins.
+///
+/// `extern mod foo;` is an alias for `__plugin::foo`.
+pub const PLUGIN_MODULE: &str = "__plugin";
+
+/// Name of the function which a plugin library exports to describe its plugin.
+///
+/// Use [`declare_plugin!`](crate::declare_plugin) to export it.
+pub const PLUGIN_ENTRY: &str = "microcad_plugin";
+
+/// Version of [`PluginDescriptor`].
+///
+/// Increment it if the descriptor changes.
+pub const PLUGIN_ABI_VERSION: u32 = 1;
+
+/// µcad version a plugin library must have been built with (NUL-terminated).
+pub const PLUGIN_MICROCAD_VERSION: &str = concat!(env!("CARGO_PKG_VERSION"), "\0");
+
+/// Compiler version a plugin library must have been built with (NUL-terminated).
+pub const PLUGIN_RUSTC_VERSION: &str = concat!(env!("MICROCAD_RUSTC_VERSION"), "\0");
+
+/// Signature of the function a plugin library exports as [`PLUGIN_ENTRY`].
+pub type PluginEntry = unsafe extern "C" fn() -> PluginDescriptor;
+
+/// Description of a plugin library with a C layout.
+///
+/// The plugin itself is a Rust trait object whose layout is only stable for the same compiler and µcad version.
+/// So the application must compare the versions before it creates the plugin with [`PluginDescriptor::plugin`].
+#[repr(C)]
+pub struct PluginDescriptor {
+    /// [`PLUGIN_ABI_VERSION`] of the plugin library.
+    ///
+    /// It is the first field, so it can be read from descriptors of all versions.
+    pub abi_version: u32,
+    /// [`PLUGIN_MICROCAD_VERSION`] of the plugin library.
+    pub microcad_version: *const std::ffi::c_char,
+    /// [`PLUGIN_RUSTC_VERSION`] of the plugin library.
+    pub rustc_version: *const std::ffi::c_char,
+    /// Create the plugin as `Box<Box<dyn Plugin>>` converted into a raw pointer.
+    pub create: unsafe extern "C" fn() -> *mut std::ffi::c_void,
+}
+
+/// Error when a plugin library is not compatible with the application.
+#[derive(Debug, Error)]
+pub enum PluginAbiError {
+    /// The descriptor has another version.
+    #[error("Plugin interface version {found} is not supported (expected {PLUGIN_ABI_VERSION})")]
+    AbiVersion {
+        /// Version of the plugin library.
+        found: u32,
+    },
+    /// The plugin has been built with another µcad version.
+    #[error("Plugin has been built for µcad {found} (expected {expected})")]
+    MicrocadVersion {
+        /// Version of the plugin library.
+        found: String,
+        /// Version of the application.
+        expected: String,
+    },
+    /// The plugin has been built with another compiler.
+    #[error("Plugin has been built with {found} (expected {expected})")]
+    RustcVersion {
+        /// Compiler of the plugin library.
+        found: String,
+        /// Compiler of the application.
+        expected: String,
+    },
+}
+
+impl PluginDescriptor {
+    /// Descriptor of a plugin library which has been built with this µcad version.
+    pub fn new(create: unsafe extern "C" fn() -> *mut std::ffi::c_void) -> Self {
+        Self {
+            abi_version: PLUGIN_ABI_VERSION,
+            microcad_version: PLUGIN_MICROCAD_VERSION.as_ptr().cast(),
+            rustc_version: PLUGIN_RUSTC_VERSION.as_ptr().cast(),
+            create,
+        }
+    }
+
+    /// Check the versions and create the plugin.
+    ///
+    /// # Safety
+    ///
+    /// The descriptor must have been returned by the [`PLUGIN_ENTRY`] of a library
+    /// which has been exported with [`declare_plugin!`](crate::declare_plugin) and is still loaded.
+    pub unsafe fn plugin(&self) -> Result<Box<dyn Plugin>, PluginAbiError> {
+        if self.abi_version != PLUGIN_ABI_VERSION {
+            return Err(PluginAbiError::AbiVersion {
+                found: self.abi_version,
+            });
+        }
+
+        fn version(s: &str) -> String {
+            s.trim_end_matches('\0').to_string()
+        }
+        // SAFETY: Both versions are NUL-terminated strings in the plugin library.
+        let (microcad_version, rustc_version) = unsafe {
+            (
+                std::ffi::CStr::from_ptr(self.microcad_version)
+                    .to_string_lossy()
+                    .into_owned(),
+                std::ffi::CStr::from_ptr(self.rustc_version)
+                    .to_string_lossy()
+                    .into_owned(),
+            )
+        };
+        if microcad_version != version(PLUGIN_MICROCAD_VERSION) {
+            return Err(PluginAbiError::MicrocadVersion {
+                found: microcad_version,
+                expected: version(PLUGIN_MICROCAD_VERSION),
+            });
+        }
+        if rustc_version != version(PLUGIN_RUSTC_VERSION) {
+            return Err(PluginAbiError::RustcVersion {
+                found: rustc_version,
+                expected: version(PLUGIN_RUSTC_VERSION),
+            });
+        }
+
+        // SAFETY: The plugin has been built with the same compiler and µcad version,
+        // so the trait object has the same layout.
+        Ok(unsafe { *Box::from_raw((self.create)().cast::<Box<dyn Plugin>>()) })
+    }
+}
+
+/// Function of a plugin which gets the arguments matched to its parameters.
+pub type PluginFn = dyn Fn(&Tuple, &mut EvalContext) -> EvalResult<Value>;
+
+/// A plugin provides functions, sketches and operations within its own namespace.
+///
+/// The namespace must be declared with `extern mod <id>;` before the symbols can be used:
+///
+/// ```ucad
+/// extern mod profiles;
+///
+/// profiles::t_slot(size = 20mm);
+/// ```
+pub trait Plugin {
+    /// Namespace of the plugin.
+    fn id(&self) -> Identifier;
+
+    /// Build the module symbol which contains all symbols of the plugin.
+    ///
+    /// Use a [`ModuleBuilder`] with [`ModuleBuilder::plugin_fn`] to add functions and
+    /// [`ModuleBuilder::builtin`] to add sketches and operations.
+    fn module(&self) -> Symbol;
+}
+
+/// Plugin registry.
+#[derive(Default, Clone)]
+pub struct PluginRegistry(Vec<Rc<dyn Plugin>>);
+
+impl PluginRegistry {
+    /// Add a new plugin to the registry.
+    pub fn insert(mut self, plugin: impl Plugin + 'static) -> Self {
+        self.0.push(Rc::new(plugin));
+        self
+    }
+
+    /// Return `true` if no plugins are registered.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Build the `__plugin` module which contains the modules of all registered plugins.
+    pub fn module(&self) -> Option<Symbol> {
+        if self.is_empty() {
+            return None;
+        }
+        Some(
+            self.0
+                .iter()
+                .fold(
+                    ModuleBuilder::new(Identifier::no_ref(PLUGIN_MODULE)),
+                    |builder, plugin| {
+                        log::debug!("Adding plugin `{id}`", id = plugin.id());
+                        builder.symbol(plugin.module())
+                    },
+                )
+                .build(),
+        )
+    }
+}
+
+impl ModuleBuilder {
+    /// Add a plugin function.
+    ///
+    /// Unlike other built-in functions, the arguments are type checked against `parameters`
+    /// before `f` is called. A mismatch is reported as error and the call results in no value.
+    pub fn plugin_fn(
+        self,
+        id: &str,
+        parameters: ParameterValueList,
+        f: impl Fn(&Tuple, &mut EvalContext) -> EvalResult<Value> + 'static,
+    ) -> Self {
+        let f: Rc<BuiltinFn> = Rc::new(
+            move |params: &ParameterValueList,
+                  args: &ArgumentValueList,
+                  context: &mut EvalContext| {
+                match ArgumentMatch::find_match(args, params) {
+                    Ok(tuple) => f(&tuple, context),
+                    Err(err) => {
+                        context.error(args, err)?;
+                        Ok(Value::None)
+                    }
+                }
+            },
+        );
+
+        self.symbol(Symbol::new_builtin(BuiltinFunction {
+            id: Identifier::no_ref(id),
+            parameters,
+            f,
+            doc: None,
+        }))
+    }
+}
+
+/// Export the [`PLUGIN_ENTRY`] function of a plugin library.
+///
+/// The library must be built as `cdylib` with the same compiler and µcad version as the application
+/// which loads it, because the plugin is passed as a Rust trait object.
+/// Libraries built with other versions are rejected when loaded.
+///
+/// ```ignore
+/// microcad_lang::declare_plugin!(Profiles::default());
+/// ```
+#[macro_export]
+macro_rules! declare_plugin {
+    ($plugin:expr) => {
+        #[unsafe(no_mangle)]
+        pub extern "C" fn microcad_plugin() -> $crate::builtin::PluginDescriptor {
+            extern "C" fn create() -> *mut ::std::ffi::c_void {
+                let plugin: Box<dyn $crate::builtin::Plugin> = Box::new($plugin);
+                Box::into_raw(Box::new(plugin)).cast()
+            }
+            $crate::builtin::PluginDescriptor::new(create)
+        }
+    };
+}
@@ -5,7 +5,7 @@
 
 use custom_debug::Debug;
 use microcad_core::hash::{ComputedHash, HashId, Hashed};
-use microcad_lang_base::SrcRef;
+use microcad_lang_base::{PushDiag, SrcRef};
 
 use crate::{builtin::*, eval::*, lower::ir, model::*, render::*, value::*};
 
@@ -116,22 +116,28 @@ pub trait BuiltinWorkbenchDefinition {
                 call = microcad_lang_base::mark!(CALL),
                 id = Self::id()
             );
-            Ok(Value::Model(
-                ArgumentMatch::find_multi_match(args, params)?
-                    .args
-                    .iter()
-                    .map(|tuple| {
-                        Self::model(Creator::new(
-                            context.current_symbol().expect("Some call to a symbol"),
-                            tuple.clone(),
-                        ))
-                    })
-                    .collect::<Models>()
-                    .to_multiplicity(SrcRef::none()),
-            ))
+            let mut models = Models::default();
+            for tuple in ArgumentMatch::find_multi_match(args, params)?.args.iter() {
+                if let Err(err) = Self::validate(tuple) {
+                    context.error(args, err)?;
+                    continue;
+                }
+                models.push(Self::model(Creator::new(
+                    context.current_symbol().expect("Some call to a symbol"),
+                    tuple.clone(),
+                )));
+            }
+            Ok(Value::Model(models.to_multiplicity(SrcRef::none())))
         }
     }
 
+    /// Validate the arguments of a single call before a model is created.
+    ///
+    /// Errors are reported at the call site and no model is created for these arguments.
+    fn validate(_args: &Tuple) -> EvalResult<()> {
+        Ok(())
+    }
+
     /// Workbench function
     fn doc() -> Option<ir::DocBlock> {
         Self::help().map(ir::DocBlock::new_builtin)
@@ -289,6 +289,8 @@ impl Eval<Vec<crate::model::Attribute>> for ir::Attribute {
                         let measures: Vec<MeasureCommand> = self.eval(context)?;
                         measures.iter().cloned().map(Attr::Measure).collect()
                     }
+                    // `#[test]` only marks test workbenches.
+                    "test" => Default::default(),
                     _ => {
                         let commands: Vec<CustomCommand> = self.eval(context)?;
                         commands.iter().cloned().map(Attr::Custom).collect()
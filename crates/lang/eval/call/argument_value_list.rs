@@ -49,6 +49,19 @@ impl ArgumentValueList {
     pub fn get_by_index(&self, index: usize) -> Option<&(Identifier, ArgumentValue)> {
         self.map.get(index)
     }
+
+    /// Get value by name or, if not given by name, by position.
+    ///
+    /// Returns [`Value::None`] if there is no such argument.
+    pub fn get_by_id_or_index(&self, id: &str, index: usize) -> Value {
+        match self.get_value(id) {
+            Ok(value) => value.clone(),
+            Err(_) => self
+                .get_by_index(index)
+                .map(|(_, arg)| arg.value.clone())
+                .unwrap_or_default(),
+        }
+    }
 }
 
 impl ValueAccess for ArgumentValueList {
@@ -42,6 +42,17 @@ impl CallMethod for Array {
                 "all_equal" => self.all_equal().into(),
                 "is_ascending" => self.is_ascending().into(),
                 "is_descending" => self.is_descending().into(),
+                "min" => self.min(),
+                "max" => self.max(),
+                "sum" => match self.sum() {
+                    Ok(value) => value,
+                    Err(err) => {
+                        context.error(id, err)?;
+                        Value::None
+                    }
+                },
+                "enumerate" => self.enumerate().into(),
+                "flatten" => self.flatten().map(Value::from).unwrap_or_default(),
                 _ => {
                     context.error(id, EvalError::UnknownMethod(id.clone()))?;
                     Value::None
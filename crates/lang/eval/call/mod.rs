@@ -79,6 +79,7 @@ impl Eval for ir::Call {
                         }
                     }
                     SymbolDef::Function(f) => f.call(&args, context),
+                    SymbolDef::Value(_, Value::Function(f)) => f.call(&args, context),
                     _ => {
                         context.error(self, EvalError::SymbolCannotBeCalled(symbol.full_name()))?;
                         Ok(Value::None)
@@ -22,7 +22,7 @@ pub struct EvalContext {
     /// Symbol table
     pub root: Symbol,
     /// Source cache
-    sources: Sources,
+    pub(super) sources: Sources,
     /// Stack of currently opened scopes with symbols while evaluation.
     pub(super) stack: Stack,
     /// Output channel for [__builtin::print].
@@ -33,6 +33,8 @@ pub struct EvalContext {
     importers: ImporterRegistry,
     /// Diagnostics handler.
     pub diag: DiagHandler,
+    /// Values which replace constants or default arguments.
+    pub(super) overrides: Overrides,
 }
 
 impl EvalContext {
@@ -53,6 +55,7 @@ impl EvalContext {
             exporters,
             importers,
             stack: Stack::default(),
+            overrides: Overrides::default(),
         }
     }
 
@@ -118,8 +121,9 @@ impl EvalContext {
             self.warning(&src_ref, EvalError::UnusedGlobalSymbol(id))
         })?;
 
-       
-       Ok(model)
+        self.check_unused_overrides()?;
+
+        Ok(model)
     }
 
     /// Run the closure `f` within the given `stack_frame`.
@@ -156,6 +160,30 @@ impl EvalContext {
         result
     }
 
+    /// Name of a symbol relative to the root source file, e.g. `Bracket::width`.
+    ///
+    /// Returns `None` for symbols outside of the root source file.
+    pub(super) fn root_relative_name(&self, symbol: &Symbol) -> Option<String> {
+        let root_hash = self.sources.root().source_hash();
+        let mut ids = Vec::new();
+        let mut symbol = symbol.clone();
+        loop {
+            match symbol.with_def(|def| match def {
+                SymbolDef::SourceFile(source) => Some(source.source_hash() == root_hash),
+                _ => None,
+            }) {
+                Some(true) => break,
+                Some(false) => return None,
+                None => {
+                    ids.push(symbol.id().to_string());
+                    symbol = symbol.get_parent()?;
+                }
+            }
+        }
+        ids.reverse();
+        Some(ids.join("::"))
+    }
+
     /// All registered exporters.
     pub fn exporters(&self) -> &ExporterRegistry {
         &self.exporters
@@ -261,6 +261,21 @@ pub enum EvalError {
         )]
         src_ref: SrcRef,
     },
+
+    /// Type of an override does not match the declaration.
+    #[error("Type mismatch for override `{name}`: expected {expected}, got {found}")]
+    OverrideTypeMismatch {
+        /// Name of the override.
+        name: String,
+        /// Declared type.
+        expected: Type,
+        /// Type of the override.
+        found: Type,
+    },
+
+    /// Override which did not replace any constant or default argument.
+    #[error("Override `{0}` does not match any evaluated constant or workbench parameter")]
+    UnusedOverride(String),
 }
 
 /// Result type of any evaluation.
@@ -3,11 +3,12 @@
 
 //! µcad Function call evaluation
 
-use microcad_lang_base::PushDiag;
+use microcad_lang_base::{Identifier, PushDiag, SrcRef, SrcReferrer};
 
 use crate::{
     eval::*,
     lower::{Identifiable, ir},
+    symbol::SymbolDef,
     value::*,
 };
 
@@ -37,3 +38,57 @@ impl CallTrait for ir::FunctionDefinition {
         }
     }
 }
+
+impl CallTrait for Function {
+    fn call(&self, args: &ArgumentValueList, context: &mut EvalContext) -> EvalResult<Value> {
+        let symbol = self.symbol();
+        context.scope(
+            StackFrame::Call {
+                symbol: symbol.clone(),
+                args: args.clone(),
+                src_ref: args.src_ref(),
+            },
+            |context| {
+                symbol.with_def(|def| match def {
+                    SymbolDef::Function(f) => f.call(args, context),
+                    SymbolDef::Builtin(b) => b.call(args, context),
+                    _ => {
+                        context.error(args, EvalError::SymbolCannotBeCalled(symbol.full_name()))?;
+                        Ok(Value::None)
+                    }
+                })
+            },
+        )
+    }
+}
+
+impl Function {
+    /// Call the function with a list of values.
+    ///
+    /// The values of user-defined functions are bound to the parameters in order of their declaration,
+    /// which allows calling e.g. `fn add(a: Length, b: Length)` with two values of the same type.
+    pub fn call_with_values(
+        &self,
+        values: impl IntoIterator<Item = Value>,
+        src_ref: SrcRef,
+        context: &mut EvalContext,
+    ) -> EvalResult<Value> {
+        let ids: Vec<Identifier> = self.symbol().with_def(|def| match def {
+            SymbolDef::Function(f) => f.signature.parameters.ids().collect(),
+            _ => Vec::new(),
+        });
+
+        let args: ArgumentValueList = values
+            .into_iter()
+            .enumerate()
+            .map(|(n, value)| {
+                (
+                    ids.get(n).cloned().unwrap_or(Identifier::none()),
+                    ArgumentValue::new(value, None, src_ref),
+                )
+            })
+            .collect();
+
+        self.call(&args, context)
+    }
+}
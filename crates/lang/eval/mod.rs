@@ -40,9 +40,11 @@ mod function;
 mod init;
 mod literal;
 mod locals;
+mod overrides;
 mod parameter;
 mod source_file;
 mod statements;
+mod test;
 mod tuple;
 mod workbench;
 
@@ -51,7 +53,9 @@ pub use attribute::*;
 pub use call::*;
 pub use eval_context::*;
 pub use eval_error::*;
+pub use overrides::Overrides;
 pub use parameter::*;
+pub use test::{TestCase, TestRun};
 
 use locals::*;
 use microcad_lang_base::PushDiag;
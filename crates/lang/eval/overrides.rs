@@ -0,0 +1,158 @@
+// Copyright © 2026 The µcad authors <info@microcad.xyz>
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+//! Overrides of constants and default arguments
+
+use microcad_core::hash::HashSet;
+use microcad_lang_base::{PushDiag, SrcRef, SrcReferrer};
+
+use crate::{eval::*, lower::ir, symbol::Symbol};
+
+/// Values which replace constants or default arguments of workbench parameters.
+///
+/// Names are relative to the root source file, e.g. `size` replaces the constant `size`
+/// and `Bracket::width` the default argument of parameter `width` of the workbench `Bracket`.
+#[derive(Clone, Debug, Default)]
+pub struct Overrides {
+    values: indexmap::IndexMap<String, Value>,
+    used: HashSet<String>,
+}
+
+impl Overrides {
+    /// Add an override for a name.
+    pub fn insert(&mut self, name: impl Into<String>, value: Value) {
+        self.values.insert(name.into(), value);
+    }
+
+    /// Return `true` if there are no overrides.
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Get the value for a name and mark it as used.
+    fn get(&mut self, name: &str) -> Option<Value> {
+        let value = self.values.get(name).cloned();
+        if value.is_some() {
+            self.used.insert(name.to_string());
+        }
+        value
+    }
+
+    /// Names of all overrides which have never been used.
+    fn unused(&self) -> Vec<String> {
+        self.values
+            .keys()
+            .filter(|name| !self.used.contains(*name))
+            .cloned()
+            .collect()
+    }
+}
+
+impl<N: Into<String>> FromIterator<(N, Value)> for Overrides {
+    fn from_iter<T: IntoIterator<Item = (N, Value)>>(iter: T) -> Self {
+        Self {
+            values: iter
+                .into_iter()
+                .map(|(name, value)| (name.into(), value))
+                .collect(),
+            used: HashSet::default(),
+        }
+    }
+}
+
+impl EvalContext {
+    /// Set values which replace constants or default arguments during evaluation.
+    pub fn set_overrides(&mut self, overrides: Overrides) {
+        self.overrides = overrides;
+    }
+
+    /// Return the override of a constant, if any.
+    pub(super) fn const_override(
+        &mut self,
+        symbol: &Symbol,
+        assignment: &ir::Assignment,
+    ) -> EvalResult<Option<Value>> {
+        let Some(name) = self.override_name(symbol) else {
+            return Ok(None);
+        };
+        let Some(value) = self.overrides.get(&name) else {
+            return Ok(None);
+        };
+
+        let expected = match &assignment.specified_type {
+            Some(ty) => ty.ty(),
+            None => assignment.eval(self)?.ty(),
+        };
+        self.check_override(assignment, name, value, Some(expected))
+    }
+
+    /// Replace the default arguments of a workbench's parameters with overrides.
+    pub(super) fn parameter_overrides(
+        &mut self,
+        symbol: &Symbol,
+        parameters: ParameterValueList,
+    ) -> EvalResult<ParameterValueList> {
+        let Some(prefix) = self.override_name(symbol) else {
+            return Ok(parameters);
+        };
+
+        let mut result = ParameterValueList::default();
+        for (id, parameter) in parameters.iter() {
+            let mut parameter = parameter.clone();
+            let name = format!("{prefix}::{id}");
+            if let Some(value) = self.overrides.get(&name)
+                && let Some(value) =
+                    self.check_override(&parameter, name, value, parameter.specified_type.clone())?
+            {
+                parameter.default_value = Some(value);
+            }
+            result.insert(id.clone(), parameter)?;
+        }
+
+        Ok(result)
+    }
+
+    /// Report overrides which have not replaced anything.
+    pub(super) fn check_unused_overrides(&mut self) -> EvalResult<()> {
+        self.overrides
+            .unused()
+            .into_iter()
+            .try_for_each(|name| self.warning(&SrcRef::none(), EvalError::UnusedOverride(name)))?;
+        Ok(())
+    }
+
+    /// Check the type of an override.
+    ///
+    /// Mismatching overrides are reported once and removed.
+    fn check_override(
+        &mut self,
+        src: &impl SrcReferrer,
+        name: String,
+        value: Value,
+        expected: Option<Type>,
+    ) -> EvalResult<Option<Value>> {
+        match expected {
+            Some(expected) if expected != value.ty() => {
+                self.overrides.values.shift_remove(&name);
+                self.error(
+                    src,
+                    EvalError::OverrideTypeMismatch {
+                        name,
+                        expected,
+                        found: value.ty(),
+                    },
+                )?;
+                Ok(None)
+            }
+            _ => Ok(Some(value)),
+        }
+    }
+
+    /// Name of a symbol relative to the root source file if there are any overrides.
+    fn override_name(&self, symbol: &Symbol) -> Option<String> {
+        if self.overrides.is_empty() {
+            return None;
+        }
+        self.root_relative_name(symbol)
+    }
+}
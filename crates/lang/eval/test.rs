@@ -0,0 +1,97 @@
+// Copyright © 2026 The µcad authors <info@microcad.xyz>
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+//! Tests marked with `#[test]`
+
+use microcad_lang_base::{Diagnostics, PushDiag, SrcReferrer};
+
+use crate::{
+    eval::*,
+    model::Model,
+    symbol::{Symbol, SymbolDef, SymbolMap},
+    value::Value,
+};
+
+/// A workbench or function marked with `#[test]` in the root source file.
+#[derive(Clone, Debug)]
+pub struct TestCase {
+    /// Name of the test relative to the root source file, e.g. `tests::bracket`.
+    pub name: String,
+    /// Symbol of the test.
+    pub symbol: Symbol,
+}
+
+/// Outcome of a test run.
+#[derive(Debug)]
+pub struct TestRun {
+    /// Diagnostics the test produced.
+    pub diagnostics: Diagnostics,
+    /// Model the test workbench built or the test function returned.
+    pub model: Option<Model>,
+}
+
+impl EvalContext {
+    /// Find all tests in the root source file.
+    pub fn tests(&self) -> Vec<TestCase> {
+        self.root
+            .riter()
+            .filter(|symbol| symbol.is_test())
+            .filter_map(|symbol| {
+                self.root_relative_name(&symbol)
+                    .map(|name| (name.clone(), TestCase { name, symbol }))
+            })
+            // symbols may occur several times because of use statements
+            .collect::<indexmap::IndexMap<_, _>>()
+            .into_values()
+            .collect()
+    }
+
+    /// Run a test without arguments and return the diagnostics and the model it produced.
+    ///
+    /// A test fails if there are any errors in the result, e.g. from a failed assertion.
+    pub fn run_test(&mut self, test: &TestCase) -> EvalResult<TestRun> {
+        let previous = std::mem::take(&mut self.diag.diagnostics);
+
+        let root = self.sources.root();
+        let symbol = test.symbol.clone();
+        let src_ref = symbol.src_ref();
+        let args = ArgumentValueList::default();
+        let result = self.scope(
+            StackFrame::Source(root.id(), SymbolMap::default()),
+            |context| {
+                context.scope(
+                    StackFrame::Call {
+                        symbol: symbol.clone(),
+                        args: args.clone(),
+                        src_ref,
+                    },
+                    |context| {
+                        symbol.with_def(|def| match def {
+                            SymbolDef::Workbench(w) => {
+                                w.call(src_ref, symbol.clone(), &args, context).map(Some)
+                            }
+                            SymbolDef::Function(f) => {
+                                f.call(&args, context).map(|value| match value {
+                                    Value::Model(model) => Some(model),
+                                    _ => None,
+                                })
+                            }
+                            _ => {
+                                context.error(
+                                    &src_ref,
+                                    EvalError::SymbolCannotBeCalled(symbol.full_name()),
+                                )?;
+                                Ok(None)
+                            }
+                        })
+                    },
+                )
+            },
+        );
+        let result =
+            result.or_else(|err| self.error(&src_ref, err).map(|_| None).map_err(Into::into));
+
+        let diagnostics = std::mem::replace(&mut self.diag.diagnostics, previous);
+        result.map(|model| TestRun { diagnostics, model })
+    }
+}
@@ -128,6 +128,7 @@ impl ir::WorkbenchDefinition {
             None,
             self.parameters
                 .eval(context)
+                .and_then(|params| context.parameter_overrides(&symbol, params))
                 .and_then(|params| ArgumentMatch::find_multi_match(arguments, &params)),
         ))
         // chain the inits
@@ -136,6 +137,7 @@ impl ir::WorkbenchDefinition {
                 Some(init),
                 init.parameters
                     .eval(context)
+                    .and_then(|params| context.parameter_overrides(&symbol, params))
                     .and_then(|params| ArgumentMatch::find_multi_match(arguments, &params)),
             )
         }))
@@ -111,6 +111,18 @@ impl From<Vec<Attribute>> for AttributeList {
     }
 }
 
+impl AttributeList {
+    /// Return `true` if the list contains a bare name attribute command, e.g. `#[test]`.
+    pub fn has_ident(&self, id: &str) -> bool {
+        self.0.iter().any(|attribute| {
+            attribute
+                .commands
+                .iter()
+                .any(|command| matches!(command, AttributeCommand::Ident(name) if name.id() == id))
+        })
+    }
+}
+
 impl std::fmt::Display for AttributeList {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         self.0.iter().try_for_each(|attr| writeln!(f, "{attr}"))
@@ -15,6 +15,8 @@ pub struct FunctionDefinition {
     pub keyword_ref: SrcRef,
     /// Documentation.
     pub doc: ir::DocBlock,
+    /// Function attributes.
+    pub attribute_list: ir::AttributeList,
     /// Visibility
     pub visibility: ir::Visibility,
     /// Name of the function
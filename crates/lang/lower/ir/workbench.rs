@@ -19,6 +19,8 @@ pub enum WorkbenchKind {
     Sketch,
     /// Operation
     Operation,
+    /// 3D assembly of parts
+    Assembly,
 }
 
 impl WorkbenchKind {
@@ -28,6 +30,7 @@ impl WorkbenchKind {
             WorkbenchKind::Part => "part",
             WorkbenchKind::Sketch => "sketch",
             WorkbenchKind::Operation => "op",
+            WorkbenchKind::Assembly => "assembly",
         }
     }
 }
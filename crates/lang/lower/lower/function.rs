@@ -13,6 +13,7 @@ impl Lower for ir::FunctionDefinition {
         Ok(ir::FunctionDefinition {
             keyword_ref: context.src_ref(&node.keyword_span),
             doc: ir::DocBlock::lower(&node.doc, context)?,
+            attribute_list: ir::AttributeList::lower(&node.attr, context)?,
             visibility: node
                 .vis
                 .as_ref()
@@ -2,12 +2,12 @@
 // SPDX-License-Identifier: AGPL-3.0-or-later
 
 use crate::{
-    lower::{Lower, LowerContext, LowerError, ir},
-    value::Quantity,
+    lower::{Lower, LowerContext, LowerError, LowerErrorsWithSource, ir},
+    value::{Quantity, Value},
 };
 
-use microcad_lang_base::{Refer, SrcRef};
-use microcad_lang_parse::ast;
+use microcad_lang_base::{Hashed, Refer, SrcRef};
+use microcad_lang_parse::{Parse, ParseContext, ast};
 
 impl Lower for ir::Literal {
     type AstNode = ast::Literal;
@@ -23,18 +23,25 @@ impl Lower for ir::Literal {
             ast::LiteralKind::Float(lit) => {
                 ir::Literal(Refer::new(lit.value.into(), context.src_ref(&lit.span)))
             }
-            ast::LiteralKind::Quantity(lit) => {
-                let unit = ir::Unit::lower(&lit.unit, context)?;
-                ir::Literal(Refer::new(
-                    Quantity {
-                        value: unit.normalize(lit.value),
-                        quantity_type: unit.quantity_type(),
-                        unit,
-                    }
-                    .into(),
+            ast::LiteralKind::Quantity(lit) => match context.unit(lit.unit.name.as_str()) {
+                // User-defined unit, e.g. `2U` with `unit U = 44.45mm;`
+                Some(quantity) => ir::Literal(Refer::new(
+                    quantity.clone().map(|value| value * lit.value).into(),
                     context.src_ref(&lit.span),
-                ))
-            }
+                )),
+                None => {
+                    let unit = ir::Unit::lower(&lit.unit, context)?;
+                    ir::Literal(Refer::new(
+                        Quantity {
+                            value: unit.normalize(lit.value),
+                            quantity_type: unit.quantity_type(),
+                            unit,
+                        }
+                        .into(),
+                        context.src_ref(&lit.span),
+                    ))
+                }
+            },
             ast::LiteralKind::String(lit) => ir::Literal(Refer::new(
                 lit.content.clone().into(),
                 context.src_ref(&lit.span),
@@ -49,6 +56,58 @@ impl Lower for ir::Literal {
     }
 }
 
+impl std::str::FromStr for ir::Literal {
+    type Err = LowerErrorsWithSource;
+
+    /// Parse and lower a single literal from a string, e.g. `12.5mm` or `"foo"`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut context = LowerContext::new(s);
+        let ast =
+            ast::Literal::parse(&ParseContext::new(s)).map_err(|errors| LowerErrorsWithSource {
+                errors: errors
+                    .0
+                    .into_iter()
+                    .map(|error| {
+                        let src_ref = context.src_ref(&error.span);
+                        LowerError::AstParser(Refer::new(error, src_ref))
+                    })
+                    .collect(),
+                source_code: Some(Hashed::new(s.to_string())),
+            })?;
+        ir::Literal::lower(&ast, &mut context).map_err(|error| LowerErrorsWithSource {
+            errors: vec![error],
+            source_code: Some(Hashed::new(s.to_string())),
+        })
+    }
+}
+
+impl LowerContext<'_> {
+    /// Define a unit, e.g. `unit U = 44.45mm;`, for all following literals in the current scope.
+    pub fn define_unit_from_ast(&mut self, node: &ast::def::Unit) -> Result<(), LowerError> {
+        use std::str::FromStr;
+
+        let name = node.id.name.to_string();
+        if ir::Unit::from_str(&name).is_ok() || self.unit(&name).is_some() {
+            return Err(LowerError::UnitRedefinition(Refer::new(
+                name,
+                self.src_ref(&node.id.span),
+            )));
+        }
+
+        let quantity = match ir::Literal::lower(&node.value, self)?.value() {
+            Value::Quantity(quantity) => quantity.clone(),
+            Value::Integer(value) => Quantity::scalar(*value as f64),
+            _ => {
+                return Err(LowerError::InvalidUnitValue {
+                    src_ref: self.src_ref(&node.value.span),
+                });
+            }
+        };
+        self.define_unit(&name, quantity);
+        Ok(())
+    }
+}
+
 impl Lower for ir::Unit {
     type AstNode = ast::Unit;
 
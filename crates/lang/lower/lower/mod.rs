@@ -38,6 +38,16 @@ pub enum LowerError {
     #[error("Unknown unit: {0}")]
     UnknownUnit(#[label("Unknown unit")] Refer<String>),
 
+    #[error("Unit is already defined: {0}")]
+    UnitRedefinition(#[label("Unit already defined")] Refer<String>),
+
+    /// A unit definition with a value which is not a number
+    #[error("Units must be defined by a number")]
+    InvalidUnitValue {
+        #[label("Expected a number like `44.45mm`")]
+        src_ref: SrcRef,
+    },
+
     #[error("Duplicate argument: {id}")]
     DuplicateArgument {
         #[label(primary, "Duplicate argument")]
@@ -113,6 +123,7 @@ impl SrcReferrer for LowerError {
             | LowerError::InvalidGlobPattern(src_ref)
             | LowerError::UseGlobAlias(src_ref)
             | LowerError::InvalidLiteral { src_ref, .. }
+            | LowerError::InvalidUnitValue { src_ref }
             | LowerError::InvalidExpression { src_ref }
             | LowerError::InvalidStatement { src_ref }
             | LowerError::InvalidRangeType { src_ref }
@@ -120,6 +131,7 @@ impl SrcReferrer for LowerError {
             LowerError::ParseIntError(parse_int_error) => parse_int_error.src_ref(),
             LowerError::InvalidIdentifier(id) => id.src_ref(),
             LowerError::UnknownUnit(unit) => unit.src_ref(),
+            LowerError::UnitRedefinition(unit) => unit.src_ref(),
             LowerError::UnknownType(ty) => ty.src_ref(),
             LowerError::InvalidMatrixType(ty) => ty.src_ref(),
             LowerError::AstParser(err) => err.src_ref(),
@@ -154,9 +154,18 @@ impl Lower for ir::Statement {
             ast::Statement::FileModule(module) => ir::Statement::Module(std::rc::Rc::new(
                 ir::ModuleDefinition::from_ast_file(module, context)?,
             )),
+            ast::Statement::ExternModule(module) => {
+                ir::Statement::Use(ir::UseStatement::from_ast_extern(module, context)?)
+            }
             ast::Statement::Use(statement) => {
                 ir::Statement::Use(ir::UseStatement::lower(statement, context)?)
             }
+            // Unit definitions are handled by `ir::StatementList` and have no IR statement.
+            ast::Statement::Unit(unit) => {
+                return Err(LowerError::InvalidStatement {
+                    src_ref: context.src_ref(&unit.span),
+                });
+            }
             ast::Statement::Expression(ast::ExpressionStatement {
                 expr: ast::Expression::If(if_statement),
                 ..
@@ -220,18 +229,26 @@ impl Lower for ir::StatementList {
     type AstNode = ast::StatementList;
 
     fn lower(node: &Self::AstNode, context: &mut LowerContext) -> Result<Self, LowerError> {
-        let mut statements = Vec::new();
-        node.statements.iter().try_for_each(|(statement, _)| {
-            statements.push(ir::Statement::lower(statement, context)?);
-            Ok(())
-        })?;
-
-        if let Some(tail) = &node.tail {
-            statements.push(ir::Statement::Expression(ir::ExpressionStatement::lower(
-                tail, context,
-            )?));
-        }
-
-        Ok(ir::StatementList(statements))
+        context.with_unit_scope(|context| {
+            let mut statements = Vec::new();
+            node.statements
+                .iter()
+                .try_for_each(|(statement, _)| match statement {
+                    // Unit definitions only affect the lowering of the following literals.
+                    ast::Statement::Unit(unit) => context.define_unit_from_ast(unit),
+                    statement => {
+                        statements.push(ir::Statement::lower(statement, context)?);
+                        Ok(())
+                    }
+                })?;
+
+            if let Some(tail) = &node.tail {
+                statements.push(ir::Statement::Expression(ir::ExpressionStatement::lower(
+                    tail, context,
+                )?));
+            }
+
+            Ok(ir::StatementList(statements))
+        })
     }
 }
@@ -40,6 +40,7 @@ impl Type {
             "Weight" => Ok(Type::Quantity(QuantityType::Weight)),
             "Density" => Ok(Type::Quantity(QuantityType::Density)),
             "Model" => Ok(Type::Model),
+            "Function" => Ok(Type::Function),
             _ => Err(LowerError::UnknownType(Refer::new(ty.to_string(), src_ref))),
         }
     }
@@ -3,6 +3,7 @@
 
 use crate::lower::{Lower, LowerContext, LowerError, ir};
 
+use microcad_lang_base::Refer;
 use microcad_lang_parse::ast;
 
 impl Lower for ir::UseStatement {
@@ -61,6 +62,38 @@ impl Lower for ir::UseStatement {
     }
 }
 
+impl ir::UseStatement {
+    /// Lower an extern module declaration `extern mod foo;` into `use __plugin::foo as foo;`.
+    pub fn from_ast_extern(
+        node: &ast::def::ExternModule,
+        context: &mut LowerContext,
+    ) -> Result<Self, LowerError> {
+        let keyword_ref = context.src_ref(&node.keyword_span);
+        let id = ir::Identifier::lower(&node.id, context)?;
+        let name = ir::QualifiedName::new(
+            vec![
+                ir::Identifier(Refer::new(
+                    crate::builtin::PLUGIN_MODULE.into(),
+                    keyword_ref,
+                )),
+                id.clone(),
+            ],
+            context.src_ref(&node.span),
+        );
+        let visibility = node
+            .vis
+            .as_ref()
+            .map(|visibility| ir::Visibility::lower(visibility, context))
+            .transpose()?;
+        Ok(ir::UseStatement {
+            keyword_ref,
+            src_ref: context.src_ref(&node.span),
+            visibility: visibility.unwrap_or_default(),
+            decl: ir::UseDeclaration::UseAs(name, id),
+        })
+    }
+}
+
 impl Lower for ir::Visibility {
     type AstNode = ast::def::Visibility;
 
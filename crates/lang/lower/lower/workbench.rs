@@ -12,6 +12,7 @@ impl From<ast::def::WorkbenchKind> for ir::WorkbenchKind {
             ast::def::WorkbenchKind::Sketch => ir::WorkbenchKind::Sketch,
             ast::def::WorkbenchKind::Part => ir::WorkbenchKind::Part,
             ast::def::WorkbenchKind::Op => ir::WorkbenchKind::Operation,
+            ast::def::WorkbenchKind::Assembly => ir::WorkbenchKind::Assembly,
         }
     }
 }
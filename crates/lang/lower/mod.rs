@@ -9,10 +9,12 @@ pub mod ir;
 mod lower;
 
 use microcad_lang_base::{
-    ComputedHash, DiagResult, Diagnostic, Diagnostics, Hashed, Identifier, LineIndex, PushDiag,
-    Refer, Span, SrcRef, SrcReferrer,
+    ComputedHash, DiagResult, Diagnostic, Diagnostics, HashMap, Hashed, Identifier, LineIndex,
+    PushDiag, Refer, Span, SrcRef, SrcReferrer,
 };
 
+use crate::value::Quantity;
+
 pub use lower::{LowerError, LowerErrorsWithSource, LowerResult};
 
 /// Check if the element only includes one identifier
@@ -61,6 +63,8 @@ pub struct LowerContext<'source> {
     line_index: LineIndex,
     line_offset: u32,
     diagnostics: Diagnostics,
+    /// Stack of scopes with user-defined units, e.g. `unit U = 44.45mm;`.
+    units: Vec<HashMap<String, Quantity>>,
 }
 
 impl<'source> LowerContext<'source> {
@@ -70,6 +74,7 @@ impl<'source> LowerContext<'source> {
             line_index: LineIndex::new(source),
             line_offset: 0,
             diagnostics: Diagnostics::default(),
+            units: Vec::new(),
         }
     }
 
@@ -79,9 +84,35 @@ impl<'source> LowerContext<'source> {
             line_index: self.line_index,
             line_offset,
             diagnostics: Diagnostics::default(),
+            units: self.units,
         }
     }
 
+    /// Lower `f` within a new scope for user-defined units.
+    pub fn with_unit_scope<T>(&mut self, f: impl FnOnce(&mut Self) -> T) -> T {
+        self.units.push(HashMap::default());
+        let result = f(self);
+        self.units.pop();
+        result
+    }
+
+    /// Define a unit within the current scope.
+    ///
+    /// `quantity` is the value of one unit.
+    pub fn define_unit(&mut self, name: &str, quantity: Quantity) {
+        if self.units.is_empty() {
+            self.units.push(HashMap::default());
+        }
+        if let Some(scope) = self.units.last_mut() {
+            scope.insert(name.to_string(), quantity);
+        }
+    }
+
+    /// Find a user-defined unit in all visible scopes.
+    pub fn unit(&self, name: &str) -> Option<&Quantity> {
+        self.units.iter().rev().find_map(|scope| scope.get(name))
+    }
+
     pub fn src_ref(&self, span: &Span) -> SrcRef {
         self.line_index
             .src_ref(self.source.value(), span, self.source.computed_hash())
@@ -0,0 +1,124 @@
+// Copyright © 2026 The µcad authors <info@microcad.xyz>
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+//! Bill of materials of an assembly.
+
+use derive_more::Deref;
+use microcad_lang_base::Identifier;
+
+use crate::{
+    model::*,
+    value::{Value, ValueAccess},
+};
+
+/// A single line of a [`Bom`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct BomEntry {
+    /// Name of the part, e.g. `Bracket`.
+    pub name: String,
+    /// Arguments the part has been created with, e.g. `(length=10mm)`.
+    pub parameters: String,
+    /// Number of identical parts within the assembly.
+    pub count: usize,
+    /// Material the part is made of, given by the `stock` argument of a `bom` attribute.
+    pub material: Option<String>,
+}
+
+impl BomEntry {
+    fn from_part(part: &Model) -> Self {
+        let part_ = part.borrow();
+        let creator = part_.element.creator();
+
+        // Optional information from a `#[bom(name = "...", stock = "...")]` attribute.
+        let bom = part_
+            .attributes
+            .get_custom_attributes(&Identifier::no_ref("bom"))
+            .into_iter()
+            .next()
+            .unwrap_or_default();
+        let bom_string = |id: &str| match bom.by_id(&Identifier::no_ref(id)) {
+            Some(Value::String(s)) if !s.is_empty() => Some(s.clone()),
+            _ => None,
+        };
+
+        Self {
+            name: bom_string("name")
+                .or_else(|| creator.map(|creator| creator.symbol.id().to_string()))
+                .unwrap_or_else(|| part_.element.to_string()),
+            parameters: creator
+                .map(|creator| creator.arguments.to_string())
+                .unwrap_or_default(),
+            count: 1,
+            material: bom_string("stock"),
+        }
+    }
+
+    /// Two entries describe the same part if name, parameters and material are equal.
+    fn is_same_part(&self, other: &Self) -> bool {
+        self.name == other.name
+            && self.parameters == other.parameters
+            && self.material == other.material
+    }
+}
+
+/// Bill of materials.
+///
+/// Lists each distinct part of all assemblies in a model together with its count.
+#[derive(Clone, Debug, Default, Deref)]
+pub struct Bom(Vec<BomEntry>);
+
+impl Bom {
+    /// Create the bill of materials for all assemblies within a model.
+    pub fn new(model: &Model) -> Self {
+        let mut entries: Vec<BomEntry> = Vec::new();
+        model.assembly_parts().iter().for_each(|part| {
+            let entry = BomEntry::from_part(part);
+            match entries.iter_mut().find(|e| e.is_same_part(&entry)) {
+                Some(existing) => existing.count += 1,
+                None => entries.push(entry),
+            }
+        });
+        Self(entries)
+    }
+
+    /// Write the bill of materials as CSV with columns `name`, `parameters`, `count` and `material`.
+    pub fn write_csv(&self, w: &mut impl std::io::Write) -> std::io::Result<()> {
+        fn quote(field: &str) -> String {
+            if field.contains([',', '"', '\n']) {
+                format!("\"{}\"", field.replace('"', "\"\""))
+            } else {
+                field.to_string()
+            }
+        }
+
+        writeln!(w, "name,parameters,count,material")?;
+        self.0.iter().try_for_each(|entry| {
+            writeln!(
+                w,
+                "{name},{parameters},{count},{material}",
+                name = quote(&entry.name),
+                parameters = quote(&entry.parameters),
+                count = entry.count,
+                material = quote(entry.material.as_deref().unwrap_or_default()),
+            )
+        })
+    }
+}
+
+impl std::fmt::Display for Bom {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.iter().try_for_each(|entry| {
+            write!(
+                f,
+                "{count} x {name}{parameters}",
+                count = entry.count,
+                name = entry.name,
+                parameters = entry.parameters
+            )?;
+            match &entry.material {
+                Some(material) => writeln!(f, " [{material}]"),
+                None => writeln!(f),
+            }
+        })
+    }
+}
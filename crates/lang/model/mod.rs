@@ -4,6 +4,7 @@
 //! Model tree module
 
 pub mod attribute;
+pub mod bom;
 pub mod builder;
 pub mod creator;
 pub mod element;
@@ -17,6 +18,7 @@ pub mod properties;
 pub mod workpiece;
 
 pub use attribute::*;
+pub use bom::*;
 pub use builder::*;
 pub use creator::*;
 pub use element::*;
@@ -37,7 +39,11 @@ use microcad_lang_base::{
     Identifier, RcMut, SrcRef, SrcReferrer, TreeDisplay, TreeState, WriteToFile,
 };
 
-use crate::{lower::ir::WorkbenchKind, value::Value};
+use crate::{
+    builtin::{BuiltinWorkbenchKind, BuiltinWorkpiece},
+    lower::ir::WorkbenchKind,
+    value::Value,
+};
 
 /// A reference counted, mutable [`Model`].
 #[derive(Clone, Deref, DerefMut)]
@@ -157,6 +163,60 @@ impl Model {
             .filter(|model| matches!(model.borrow().element.value, Element::Group))
     }
 
+    /// Return `true`, if the model has been created by an `assembly` workbench.
+    pub fn is_assembly(&self) -> bool {
+        matches!(
+            &self.borrow().element.value,
+            Element::Workpiece(workpiece) if workpiece.kind == WorkbenchKind::Assembly
+        )
+    }
+
+    /// Return the parts of all assemblies within this model.
+    ///
+    /// Groups, multiplicities, transformations and nested assemblies are descended into.
+    /// Any other model with output inside an assembly is a single part which keeps its identity.
+    pub fn assembly_parts(&self) -> Models {
+        fn collect(model: &Model, in_assembly: bool, parts: &mut Models) {
+            let is_container = model.is_assembly()
+                || matches!(
+                    &model.borrow().element.value,
+                    Element::Group
+                        | Element::Multiplicity
+                        | Element::BuiltinWorkpiece(BuiltinWorkpiece {
+                            kind: BuiltinWorkbenchKind::Transform,
+                            ..
+                        })
+                );
+
+            if in_assembly && !is_container {
+                if !model.has_no_output() {
+                    parts.push(model.clone());
+                }
+                return;
+            }
+
+            let in_assembly = in_assembly || model.is_assembly();
+            model
+                .borrow()
+                .children
+                .iter()
+                .for_each(|child| collect(child, in_assembly, parts));
+        }
+
+        let mut parts = Models::default();
+        collect(self, false, &mut parts);
+        parts
+    }
+
+    /// Return the name of a part: the model's id if it has been assigned, otherwise the name of its creator.
+    pub fn part_name(&self) -> Option<Identifier> {
+        let self_ = self.borrow();
+        self_
+            .id
+            .clone()
+            .or_else(|| self_.element.creator().map(|creator| creator.symbol.id()))
+    }
+
     /// Set the id of a model. This happens if the model was created by an assignment.
     ///
     /// For example, the assignment statement `a = Circle(4mm)` will result in a model with id `a`.
@@ -47,3 +47,12 @@ impl std::ops::BitAnd for Model {
         Ok(self.boolean_op(BooleanOp::Intersect, rhs))
     }
 }
+
+/// symmetric difference operator `^`
+impl std::ops::BitXor for Model {
+    type Output = ModelResult;
+
+    fn bitxor(self, rhs: Self) -> Self::Output {
+        Ok(self.boolean_op(BooleanOp::Xor, rhs))
+    }
+}
@@ -56,7 +56,7 @@ impl From<WorkbenchKind> for OutputType {
     fn from(kind: WorkbenchKind) -> Self {
         match kind {
             WorkbenchKind::Sketch => Self::Geometry2D,
-            WorkbenchKind::Part => Self::Geometry3D,
+            WorkbenchKind::Part | WorkbenchKind::Assembly => Self::Geometry3D,
             WorkbenchKind::Operation => Self::NotDetermined,
         }
     }
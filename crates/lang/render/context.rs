@@ -3,15 +3,73 @@
 
 //! Render context
 
-use std::sync::mpsc;
+use std::sync::{
+    Arc,
+    atomic::{AtomicBool, Ordering},
+    mpsc,
+};
 
-use microcad_core::{RenderResolution, hash::ComputedHash};
-use microcad_lang_base::RcMut;
+use microcad_core::{BooleanOp, RenderResolution, hash::ComputedHash};
+use microcad_lang_base::{Diagnostics, PushDiag, RcMut, SrcRef, SrcReferrer};
 
 use crate::{model::Model, render::*};
 
+/// Progress event which is sent while rendering.
+#[derive(Debug, Clone, PartialEq)]
+pub enum RenderProgress {
+    /// A model has been rendered.
+    Model {
+        /// Element of the rendered model, e.g. `Sphere(radius = 1mm)`.
+        element: String,
+        /// Number of models which have been rendered.
+        rendered: usize,
+        /// Number of models to be rendered.
+        total: usize,
+    },
+    /// A boolean operation is about to be processed.
+    BooleanOp {
+        /// The operation.
+        op: BooleanOp,
+        /// Number of operands.
+        operands: usize,
+    },
+}
+
+impl RenderProgress {
+    /// Return render progress in percent (`None` for operations).
+    pub fn percent(&self) -> Option<f32> {
+        match self {
+            RenderProgress::Model {
+                rendered, total, ..
+            } => Some(match total {
+                0 => 100.0,
+                total => (*rendered as f32 / *total as f32) * 100.0,
+            }),
+            RenderProgress::BooleanOp { .. } => None,
+        }
+    }
+}
+
 /// Our progress sender.
-pub type ProgressTx = mpsc::Sender<f32>;
+pub type ProgressTx = mpsc::Sender<RenderProgress>;
+
+/// Token to cancel a running render, e.g. from another thread when the source has changed.
+///
+/// Clones share the same state.
+#[derive(Debug, Clone, Default)]
+pub struct CancellationToken(Arc<AtomicBool>);
+
+impl CancellationToken {
+    /// Request cancellation.
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+
+    /// Return `true` if cancellation has been requested.
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+}
 
 /// The render context.
 ///
@@ -30,8 +88,14 @@ pub struct RenderContext {
     /// The number of model that been been rendered.
     models_rendered: usize,
 
-    /// Progress is given as a percentage between 0.0 and 100.0.
+    /// Sender for progress events.
     pub progress_tx: Option<ProgressTx>,
+
+    /// Token to cancel rendering.
+    pub cancellation: Option<CancellationToken>,
+
+    /// Warnings which occurred during rendering.
+    pub diagnostics: Diagnostics,
 }
 
 impl RenderContext {
@@ -41,6 +105,7 @@ impl RenderContext {
         resolution: RenderResolution,
         cache: Option<RcMut<RenderCache>>,
         progress_tx: Option<ProgressTx>,
+        cancellation: Option<CancellationToken>,
     ) -> RenderResult<Self> {
         Ok(Self {
             model_stack: vec![model.clone()],
@@ -48,6 +113,8 @@ impl RenderContext {
             models_to_render: model.prerender(resolution)?,
             models_rendered: 0,
             progress_tx,
+            cancellation,
+            diagnostics: Diagnostics::default(),
         })
     }
 
@@ -58,29 +125,60 @@ impl RenderContext {
 
     /// Run the closure `f` within the given `model`.
     pub fn with_model<T>(&mut self, model: Model, f: impl FnOnce(&mut RenderContext) -> T) -> T {
-        self.model_stack.push(model);
+        self.model_stack.push(model.clone());
         let result = f(self);
         self.model_stack.pop();
 
-        self.step();
+        self.step(&model);
 
         result
     }
 
-    /// Make a single progress step. A progress signal is sent with each new percentage.
-    fn step(&mut self) {
-        let old_percent = self.progress_in_percent();
+    /// Make a single progress step and send a progress event.
+    fn step(&mut self, model: &Model) {
         self.models_rendered += 1;
-        let new_percent = self.progress_in_percent();
+        self.send_progress(|| RenderProgress::Model {
+            element: model.borrow().element().to_string(),
+            rendered: self.models_rendered,
+            total: self.models_to_render,
+        });
+    }
 
-        // Check if integer percentage increased
-        if (old_percent.floor() as u32) < (new_percent.floor() as u32)
-            && let Some(progress_tx) = &mut self.progress_tx
-        {
-            progress_tx.send(new_percent).expect("No error");
+    /// Send a progress event, if there is a progress sender.
+    ///
+    /// A closed channel is ignored, because the receiver may have stopped listening.
+    pub fn send_progress(&self, event: impl FnOnce() -> RenderProgress) {
+        if let Some(progress_tx) = &self.progress_tx {
+            progress_tx.send(event()).ok();
         }
     }
 
+    /// Return an error if rendering has been cancelled.
+    pub fn check_cancelled(&self) -> RenderResult<()> {
+        match &self.cancellation {
+            Some(cancellation) if cancellation.is_cancelled() => Err(RenderError::Cancelled),
+            _ => Ok(()),
+        }
+    }
+
+    /// Report a warning about the current model.
+    ///
+    /// Models of built-in workbenches have no source reference,
+    /// so the warning refers to the nearest model in the stack which has one.
+    pub fn warning(&mut self, message: impl std::fmt::Display) {
+        let src_ref = self
+            .model_stack
+            .iter()
+            .rev()
+            .map(|model| model.src_ref())
+            .find(|src_ref| !src_ref.is_none())
+            .unwrap_or(SrcRef::none());
+        // Collected diagnostics have no error limit.
+        self.diagnostics
+            .warning(&src_ref, miette::miette!("{message}"))
+            .ok();
+    }
+
     /// Return render progress in percent.
     pub fn progress_in_percent(&self) -> f32 {
         (self.models_rendered as f32 / self.models_to_render as f32) * 100.0
@@ -38,6 +38,10 @@ pub enum RenderError {
     /// Nothing to render.
     #[error("Nothing to render")]
     NothingToRender,
+
+    /// Rendering has been cancelled.
+    #[error("Render cancelled")]
+    Cancelled,
 }
 
 /// A result from rendering a model.
@@ -179,7 +183,9 @@ impl CalcBounds2D for Model {
 /// * It is assumed the model has been pre-rendered.
 impl RenderWithContext<Geometry2DOutput> for Model {
     fn render_with_context(&self, context: &mut RenderContext) -> RenderResult<Geometry2DOutput> {
+        context.check_cancelled()?;
         context.with_model(self.clone(), |context| {
+            let start = std::time::Instant::now();
             let model = context.model();
             let geometry: Geometry2DOutput = {
                 let model_ = model.borrow();
@@ -198,9 +204,10 @@ impl RenderWithContext<Geometry2DOutput> for Model {
                 }
             }?;
 
-            self.borrow_mut()
-                .output_mut()
-                .set_geometry(GeometryOutput::Geometry2D(geometry.clone()));
+            let mut model_ = self.borrow_mut();
+            let output = model_.output_mut();
+            output.set_geometry(GeometryOutput::Geometry2D(geometry.clone()));
+            output.set_render_time(start.elapsed());
             Ok(geometry)
         })
     }
@@ -213,7 +220,9 @@ impl RenderWithContext<Geometry2DOutput> for Model {
 /// * It is assumed the model has been pre-rendered.
 impl RenderWithContext<Geometry3DOutput> for Model {
     fn render_with_context(&self, context: &mut RenderContext) -> RenderResult<Geometry3DOutput> {
+        context.check_cancelled()?;
         context.with_model(self.clone(), |context| {
+            let start = std::time::Instant::now();
             let model = context.model();
             let geometry: Geometry3DOutput = {
                 let model_ = model.borrow();
@@ -232,9 +241,10 @@ impl RenderWithContext<Geometry3DOutput> for Model {
                 }
             }?;
 
-            self.borrow_mut()
-                .output_mut()
-                .set_geometry(GeometryOutput::Geometry3D(geometry.clone()));
+            let mut model_ = self.borrow_mut();
+            let output = model_.output_mut();
+            output.set_geometry(GeometryOutput::Geometry3D(geometry.clone()));
+            output.set_render_time(start.elapsed());
             Ok(geometry)
         })
     }
@@ -80,6 +80,8 @@ pub struct RenderOutput {
     pub geometry: Option<GeometryOutput>,
     /// Render attributes.
     pub attributes: RenderAttributes,
+    /// Time to render the geometry including its children.
+    pub render_time: Option<std::time::Duration>,
     /// Computed model hash.
     hash: HashId,
 }
@@ -104,6 +106,7 @@ impl RenderOutput {
             resolution: None,
             geometry: None,
             attributes: model.into(),
+            render_time: None,
             hash,
         })
     }
@@ -118,6 +121,11 @@ impl RenderOutput {
         self.geometry = Some(geo)
     }
 
+    /// Set the time it took to render the geometry.
+    pub fn set_render_time(&mut self, render_time: std::time::Duration) {
+        self.render_time = Some(render_time)
+    }
+
     /// Get render resolution.
     pub fn resolution(&self) -> &Option<RenderResolution> {
         &self.resolution
@@ -37,7 +37,9 @@ impl LookupTarget {
                     matches!(self, Self::Any | Self::AnyButMethod | Self::Module)
                 }
                 SymbolDef::Workbench(wd) => match *wd.kind {
-                    ir::WorkbenchKind::Part | ir::WorkbenchKind::Sketch => {
+                    ir::WorkbenchKind::Part
+                    | ir::WorkbenchKind::Sketch
+                    | ir::WorkbenchKind::Assembly => {
                         matches!(self, Self::Any | Self::AnyButMethod | Self::Function)
                     }
                     ir::WorkbenchKind::Operation => matches!(self, Self::Any | Self::Method),
@@ -63,6 +65,12 @@ impl LookupTarget {
                         matches!(self, Self::Any | Self::AnyButMethod | Self::Value)
                     }
                 },
+                SymbolDef::Value(_, crate::value::Value::Function(..)) => {
+                    matches!(
+                        self,
+                        Self::Any | Self::AnyButMethod | Self::Value | Self::Function
+                    )
+                }
                 SymbolDef::Value(..) | SymbolDef::Assignment(..) => {
                     matches!(self, Self::Any | Self::AnyButMethod | Self::Value)
                 }
@@ -8,6 +8,7 @@ mod grant;
 mod lookup;
 mod resolve_context;
 mod resolve_error;
+mod source_cache;
 mod sources;
 mod symbolize;
 
@@ -15,6 +16,7 @@ pub use externals::*;
 pub use lookup::*;
 pub use resolve_context::*;
 pub use resolve_error::*;
+pub use source_cache::*;
 pub use sources::*;
 
 use grant::*;
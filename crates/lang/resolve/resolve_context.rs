@@ -23,18 +23,37 @@ pub struct ResolveContext {
 
 impl ResolveContext {
     /// Load resolve and check a source file and referenced files.
+    ///
+    /// `libraries` are built-in modules like `__builtin` or `__plugin` which are added to the root.
     pub fn create(
         root: std::rc::Rc<ir::Source>,
         search_paths: Vec<std::path::PathBuf>,
-        builtin: Option<Symbol>,
+        libraries: impl IntoIterator<Item = Symbol>,
+        diag: DiagHandler,
+    ) -> ResolveResult<Self> {
+        Self::create_with_cache(
+            root,
+            search_paths,
+            libraries,
+            diag,
+            &mut SourceCache::default(),
+        )
+    }
+
+    /// Like [`ResolveContext::create`] but takes unchanged files from search paths out of `cache`.
+    pub fn create_with_cache(
+        root: std::rc::Rc<ir::Source>,
+        search_paths: Vec<std::path::PathBuf>,
+        libraries: impl IntoIterator<Item = Symbol>,
         mut diag: DiagHandler,
+        cache: &mut SourceCache,
     ) -> ResolveResult<Self> {
         let mut context = Self {
-            sources: Sources::load(root.clone(), search_paths, &mut diag.diagnostics)?,
+            sources: Sources::load(root.clone(), search_paths, cache, &mut diag.diagnostics)?,
             diag,
             root: Symbol::default(),
         };
-        match context.load(builtin) {
+        match context.load(libraries, cache) {
             Ok(()) => Ok(context),
             Err(err) => {
                 context.error(&err.src_ref(), err)?;
@@ -43,14 +62,20 @@ impl ResolveContext {
         }
     }
 
-    fn load(&mut self, builtin: Option<Symbol>) -> ResolveResult<()> {
+    fn load(
+        &mut self,
+        libraries: impl IntoIterator<Item = Symbol>,
+        cache: &mut SourceCache,
+    ) -> ResolveResult<()> {
         self.symbolize()?;
         log::trace!("Symbolized Context:\n{self:?}");
-        if let Some(builtin) = builtin {
-            log::trace!("Added builtin library {id}.", id = builtin.id());
-            self.root.add_symbol(builtin)?;
+        let mut library_ids = Vec::new();
+        for library in libraries {
+            log::trace!("Added builtin library {id}.", id = library.id());
+            library_ids.push(library.id());
+            self.root.add_symbol(library)?;
         }
-        self.resolve()?;
+        self.resolve(library_ids, cache)?;
 
         Ok(())
     }
@@ -84,11 +109,13 @@ impl ResolveContext {
         Ok(())
     }
 
-    fn resolve(&mut self) -> ResolveResult<()> {
+    fn resolve(
+        &mut self,
+        library_ids: Vec<ir::Identifier>,
+        cache: &mut SourceCache,
+    ) -> ResolveResult<()> {
         // resolve std as first
-        if let Some(std) = self.root.get_child(&ir::Identifier::no_ref("std")) {
-            std.resolve(self)?;
-        }
+        self.resolve_std(library_ids, cache)?;
 
         // multi pass resolve
         const MAX_PASSES: usize = 3;
@@ -118,6 +145,41 @@ impl ResolveContext {
         Ok(())
     }
 
+    /// Resolve the standard library or take a copy of it from the `cache`.
+    ///
+    /// The standard library is cached if it has been resolved without any diagnostics.
+    fn resolve_std(
+        &mut self,
+        library_ids: Vec<ir::Identifier>,
+        cache: &mut SourceCache,
+    ) -> ResolveResult<()> {
+        let id = ir::Identifier::no_ref("std");
+        let Some(std) = self.root.get_child(&id) else {
+            return Ok(());
+        };
+
+        let key = (
+            library_ids,
+            self.sources
+                .iter()
+                .filter(|source| source.name.first() == Some(&id))
+                .map(|source| source.source_hash())
+                .collect(),
+        );
+        if let Some(resolved) = cache.resolved_std(&key) {
+            log::trace!("Using cached resolved {id}");
+            Symbol::add_child(&self.root, resolved);
+            return Ok(());
+        }
+
+        let count = (self.error_count(), self.warning_count());
+        std.resolve(self)?;
+        if count == (self.error_count(), self.warning_count()) {
+            cache.set_resolved_std(key, &std);
+        }
+        Ok(())
+    }
+
     fn has_links(&self) -> bool {
         self.root
             .iter()
@@ -125,6 +187,14 @@ impl ResolveContext {
             .any(|symbol| symbol.has_links())
     }
 
+    /// Return the paths of all loaded source files.
+    pub fn source_paths(&self) -> Vec<std::path::PathBuf> {
+        self.sources
+            .iter()
+            .map(|source| source.filename())
+            .collect()
+    }
+
     /// Load file into source cache and symbolize it into a symbol.
     pub fn symbolize_file(
         &mut self,
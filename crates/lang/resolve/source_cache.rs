@@ -0,0 +1,126 @@
+// Copyright © 2026 The µcad authors <info@microcad.xyz>
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+//! Cache of loaded external source files
+
+use std::{
+    path::{Path, PathBuf},
+    rc::Rc,
+    time::SystemTime,
+};
+
+use microcad_lang_base::{Diagnostics, HashMap};
+
+use crate::{
+    lower::{LowerErrorsWithSource, ir},
+    symbol::Symbol,
+};
+
+/// Cache of external source files (e.g. the standard library) which can be shared by resolve contexts.
+///
+/// Files are parsed and lowered once and are loaded again only if their modification time changes.
+/// Warnings of a cached file are stored with it and reported again with each load.
+/// Files with errors are not cached, so their diagnostics are reported with each load.
+///
+/// The resolved symbols of the standard library are cached, too, and each resolve context gets a copy of them.
+#[derive(Default)]
+pub struct SourceCache {
+    files: HashMap<PathBuf, CachedFile>,
+    std: Option<(ResolvedKey, Symbol)>,
+}
+
+/// Libraries and source hashes the standard library has been resolved with.
+pub(crate) type ResolvedKey = (Vec<ir::Identifier>, Vec<u64>);
+
+/// A cached source file.
+struct CachedFile {
+    /// Modification time of the file when it was loaded.
+    modified: SystemTime,
+    /// Lowered source.
+    source: Rc<ir::Source>,
+    /// Warnings of loading the file.
+    diagnostics: Diagnostics,
+}
+
+impl SourceCache {
+    /// Return the cached source of a file or load it.
+    pub(crate) fn load(
+        &mut self,
+        path: &Path,
+        name: ir::QualifiedName,
+        diagnostics: &mut Diagnostics,
+    ) -> (Rc<ir::Source>, Option<LowerErrorsWithSource>) {
+        let modified = std::fs::metadata(path)
+            .and_then(|metadata| metadata.modified())
+            .ok();
+
+        if let (Some(modified), Some(cached)) = (modified, self.files.get(path))
+            && cached.modified == modified
+            && cached.source.name == name
+        {
+            log::trace!("Using cached file {path}", path = path.display());
+            diagnostics.append(cached.diagnostics.clone());
+            return (cached.source.clone(), None);
+        }
+
+        let mut file_diagnostics = Diagnostics::default();
+        let (source_file, error) = ir::Source::load_with_name(path, name, &mut file_diagnostics);
+        match (modified, &error) {
+            (Some(modified), None) => {
+                self.files.insert(
+                    path.to_path_buf(),
+                    CachedFile {
+                        modified,
+                        source: source_file.clone(),
+                        diagnostics: file_diagnostics.clone(),
+                    },
+                );
+            }
+            _ => {
+                self.files.remove(path);
+            }
+        }
+        diagnostics.append(file_diagnostics);
+        (source_file, error)
+    }
+
+    /// Return a copy of the resolved standard library if it has been resolved with the same `key`.
+    pub(crate) fn resolved_std(&self, key: &ResolvedKey) -> Option<Symbol> {
+        self.std
+            .as_ref()
+            .filter(|(cached, _)| cached == key)
+            .map(|(_, std)| std.deep_clone())
+    }
+
+    /// Store a copy of the resolved standard library.
+    pub(crate) fn set_resolved_std(&mut self, key: ResolvedKey, std: &Symbol) {
+        self.std = Some((key, std.deep_clone()));
+    }
+
+    /// Remove a file from the cache, e.g. after it has been changed.
+    ///
+    /// Returns `true` if the file was cached.
+    pub fn remove(&mut self, path: impl AsRef<Path>) -> bool {
+        let removed = self.files.remove(path.as_ref()).is_some();
+        if removed {
+            self.std = None;
+        }
+        removed
+    }
+
+    /// Remove all files and the resolved standard library from the cache.
+    pub fn clear(&mut self) {
+        self.files.clear();
+        self.std = None;
+    }
+
+    /// Number of cached files.
+    pub fn len(&self) -> usize {
+        self.files.len()
+    }
+
+    /// Return `true` if no file is cached.
+    pub fn is_empty(&self) -> bool {
+        self.files.is_empty()
+    }
+}
@@ -41,10 +41,11 @@ pub struct Sources {
 impl Sources {
     /// Create source cache
     ///
-    /// Inserts the `root` file and loads all files from `search_paths`.
+    /// Inserts the `root` file and loads all files from `search_paths` (or takes them from `cache`).
     pub fn load(
         root: Rc<ir::Source>,
         search_paths: Vec<std::path::PathBuf>,
+        cache: &mut SourceCache,
         diagnostics: &mut Diagnostics,
     ) -> ResolveResult<Self> {
         let mut source_files = Vec::new();
@@ -60,8 +61,7 @@ impl Sources {
         // load all external source files into cache
         Externals::new(&search_paths)?.iter().try_for_each(
             |(name, path)| -> Result<(), LowerErrorsWithSource> {
-                let (source_file, error) =
-                    ir::Source::load_with_name(path.clone(), name.clone(), diagnostics);
+                let (source_file, error) = cache.load(path, name.clone(), diagnostics);
                 let index = source_files.len();
                 by_hash.insert(source_file.source_hash(), index);
                 by_path.insert(source_file.filename(), index);
@@ -87,7 +87,7 @@ impl Symbol {
         Self::new_builtin(BuiltinFunction {
             id: Identifier::no_ref(name),
             parameters: parameters.collect(),
-            f,
+            f: std::rc::Rc::new(f),
             doc: doc.map(ir::DocBlock::new_builtin),
         })
     }
@@ -296,6 +296,79 @@ impl Symbol {
             inner: self.inner.clone(),
         }
     }
+
+    /// Copy this symbol and all symbols below it into a new tree without parent.
+    ///
+    /// Symbols which share their content within the tree (e.g. used symbols and their origin)
+    /// share it in the copy, too.
+    /// Symbols from outside the tree (e.g. used built-ins) are not copied.
+    pub(crate) fn deep_clone(&self) -> Symbol {
+        let mut copies = std::collections::HashMap::new();
+        let copy = self.copy_within(self, &mut copies);
+
+        // link each copy to the copy of its parent
+        copies.values().for_each(|(original, copy)| {
+            copy.borrow_mut().parent = original.borrow().parent.as_ref().and_then(|parent| {
+                copies
+                    .get(&std::rc::Rc::as_ptr(&*parent.inner))
+                    .map(|(_, inner)| Symbol {
+                        visibility: parent.visibility.clone(),
+                        src_ref: parent.src_ref,
+                        inner: inner.clone(),
+                    })
+            });
+        });
+        copy
+    }
+
+    /// Copy this symbol if it belongs to the tree of `top`.
+    ///
+    /// `copies` maps the content of each copied symbol to its original and copied content.
+    fn copy_within(
+        &self,
+        top: &Symbol,
+        copies: &mut std::collections::HashMap<
+            *const std::cell::RefCell<SymbolInner>,
+            (RcMut<SymbolInner>, RcMut<SymbolInner>),
+        >,
+    ) -> Symbol {
+        let key = std::rc::Rc::as_ptr(&*self.inner);
+        let inner = if let Some((_, copy)) = copies.get(&key) {
+            copy.clone()
+        } else if self.is_within(top) {
+            let original = self.inner.borrow();
+            let copy = RcMut::new(SymbolInner {
+                def: original.def.clone(),
+                parent: None,
+                children: SymbolMap::default(),
+                used: original.used.clone(),
+            });
+            copies.insert(key, (self.inner.clone(), copy.clone()));
+            let children = original
+                .children
+                .iter()
+                .map(|(id, child)| (id.clone(), child.copy_within(top, copies)))
+                .collect();
+            copy.borrow_mut().children = children;
+            copy
+        } else {
+            self.inner.clone()
+        };
+
+        Symbol {
+            visibility: self.visibility.clone(),
+            src_ref: self.src_ref,
+            inner,
+        }
+    }
+
+    /// Return `true` if `top` is this symbol or one of its parents.
+    fn is_within(&self, top: &Symbol) -> bool {
+        std::rc::Rc::ptr_eq(&*self.inner, &*top.inner)
+            || self
+                .get_parent()
+                .is_some_and(|parent| parent.is_within(top))
+    }
 }
 
 // definition dependent
@@ -430,7 +503,16 @@ impl Symbol {
     }
 
     pub(crate) fn is_unused_private(&self) -> bool {
-        !self.is_used() && !self.is_public() && !self.is_deleted()
+        !self.is_used() && !self.is_public() && !self.is_deleted() && !self.is_test()
+    }
+
+    /// Return `true` if symbol is a workbench or function marked with `#[test]`.
+    pub fn is_test(&self) -> bool {
+        self.with_def(|def| match def {
+            SymbolDef::Workbench(w) => w.attribute_list.has_ident("test"),
+            SymbolDef::Function(f) => f.attribute_list.has_ident("test"),
+            _ => false,
+        })
     }
 
     pub(crate) fn in_module(&self) -> Option<ir::QualifiedName> {
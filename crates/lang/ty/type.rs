@@ -26,6 +26,8 @@ pub enum Type {
     Matrix(MatrixType),
     /// Model.
     Model,
+    /// A function: `Function`.
+    Function,
 }
 
 impl Type {
@@ -125,6 +127,7 @@ impl std::fmt::Display for Type {
             Self::Tuple(t) => write!(f, "{t}"),
             Self::Matrix(t) => write!(f, "{t}"),
             Self::Model => write!(f, "Model"),
+            Self::Function => write!(f, "Function"),
         }
     }
 }
@@ -5,6 +5,7 @@
 
 use crate::{ty::*, value::*};
 use derive_more::{Deref, DerefMut};
+use microcad_lang_base::Identifier;
 
 /// Collection of values of the same type.
 #[derive(Clone, Debug, Deref, DerefMut)]
@@ -100,6 +101,130 @@ impl Array {
     pub fn is_descending(&self) -> bool {
         self.as_slice().windows(2).all(|w| w[0] >= w[1])
     }
+
+    /// Return all items for which `f` returns `true`.
+    pub fn filter<E>(&self, mut f: impl FnMut(&Value) -> Result<bool, E>) -> Result<Array, E> {
+        let mut items = ValueList::default();
+        for item in self.items.iter() {
+            if f(item)? {
+                items.push(item.clone());
+            }
+        }
+        Ok(Array::from_values(items, self.ty.clone()))
+    }
+
+    /// Return the elements from index `start` up to (but excluding) index `end`.
+    ///
+    /// Indices exceeding the length of the array are clamped.
+    pub fn slice(&self, start: usize, end: usize) -> Array {
+        let end = end.min(self.len());
+        let start = start.min(end);
+        Array::from_values(
+            self.items[start..end].iter().cloned().collect(),
+            self.ty.clone(),
+        )
+    }
+
+    /// Flatten an array of arrays by one level: `[[1,2],[3]]` becomes `[1,2,3]`.
+    ///
+    /// Returns `None` if the items are not arrays.
+    pub fn flatten(&self) -> Option<Array> {
+        match &self.ty {
+            Type::Array(ty) => Some(Array::from_values(
+                self.items
+                    .iter()
+                    .flat_map(|item| match item {
+                        Value::Array(array) => array.items.to_vec(),
+                        _ => Vec::new(),
+                    })
+                    .collect(),
+                ty.as_ref().clone(),
+            )),
+            _ => None,
+        }
+    }
+
+    /// Return an array of `(index, value)` tuples.
+    pub fn enumerate(&self) -> Array {
+        Array::from_values(
+            self.items
+                .iter()
+                .enumerate()
+                .map(|(index, value)| {
+                    crate::create_tuple_value!(index = index, value = value.clone())
+                })
+                .collect(),
+            Self::tuple_type([("index", Type::Integer), ("value", self.ty.clone())]),
+        )
+    }
+
+    /// Combine the items of two arrays pairwise into `(a, b)` tuples.
+    ///
+    /// The resulting array has the length of the shorter array.
+    pub fn zip(&self, other: &Array) -> Array {
+        Array::from_values(
+            self.items
+                .iter()
+                .zip(other.items.iter())
+                .map(|(a, b)| crate::create_tuple_value!(a = a.clone(), b = b.clone()))
+                .collect(),
+            Self::tuple_type([("a", self.ty.clone()), ("b", other.ty.clone())]),
+        )
+    }
+
+    /// Return the smallest item or `None` if the array is empty or its items cannot be compared.
+    pub fn min(&self) -> Value {
+        self.extremum(|a, b| a < b)
+    }
+
+    /// Return the largest item or `None` if the array is empty or its items cannot be compared.
+    pub fn max(&self) -> Value {
+        self.extremum(|a, b| a > b)
+    }
+
+    /// Return the sum of all items.
+    ///
+    /// The sum of an empty array of integers or quantities is zero.
+    pub fn sum(&self) -> ValueResult {
+        let mut items = self.items.iter().cloned();
+        match items.next() {
+            Some(first) => items.try_fold(first, |sum, item| sum + item),
+            None => Ok(match &self.ty {
+                Type::Integer => Value::Integer(0),
+                Type::Quantity(quantity_type) => {
+                    Value::Quantity(Quantity::new(0.0, quantity_type.clone()))
+                }
+                _ => Value::None,
+            }),
+        }
+    }
+
+    /// Find the item which is preferred by `prefer` over all others.
+    fn extremum(&self, prefer: impl Fn(&Value, &Value) -> bool) -> Value {
+        match self.ty {
+            Type::Integer | Type::Quantity(..) => self
+                .items
+                .iter()
+                .fold(None, |result: Option<&Value>, item| match result {
+                    Some(result) if !prefer(item, result) => Some(result),
+                    _ => Some(item),
+                })
+                .cloned()
+                .unwrap_or_default(),
+            _ => Value::None,
+        }
+    }
+
+    /// Create a named tuple type from a list of names and types.
+    fn tuple_type<const N: usize>(items: [(&str, Type); N]) -> Type {
+        Type::Tuple(Box::new(TupleType {
+            named: items
+                .into_iter()
+                .map(|(id, ty)| (Identifier::no_ref(id), ty))
+                .collect(),
+            unnamed: Default::default(),
+        }))
+    }
 }
 
 impl PartialEq for Array {
@@ -0,0 +1,49 @@
+// Copyright © 2026 The µcad authors <info@microcad.xyz>
+// SPDX-License-Identifier: AGPL-3.0-or-later
+
+//! Function value evaluation entity
+
+use crate::{symbol::Symbol, ty::*};
+
+/// A function which is passed around as a value.
+///
+/// A function value is created when the name of a user-defined function or a built-in function
+/// is used within an expression, e.g. `std::array::map([1, 2, 3], double)`.
+#[derive(Clone, PartialEq)]
+pub struct Function(Symbol);
+
+impl Function {
+    /// Create a new function value from a callable symbol.
+    pub fn new(symbol: Symbol) -> Self {
+        Self(symbol)
+    }
+
+    /// Return the symbol of the function.
+    pub fn symbol(&self) -> &Symbol {
+        &self.0
+    }
+}
+
+impl Ty for Function {
+    fn ty(&self) -> Type {
+        Type::Function
+    }
+}
+
+impl std::fmt::Display for Function {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "fn {}", self.0.full_name())
+    }
+}
+
+impl std::fmt::Debug for Function {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "fn {:?}", self.0.full_name())
+    }
+}
+
+impl std::hash::Hash for Function {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.0.full_name().to_string().hash(state)
+    }
+}
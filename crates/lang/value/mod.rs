@@ -7,6 +7,7 @@
 //! to process or ends up as the overall evaluation result.
 
 mod array;
+mod function;
 mod matrix;
 mod quantity;
 mod tuple;
@@ -16,6 +17,7 @@ mod value_list;
 
 pub use array::*;
 use derive_more::From;
+pub use function::*;
 pub use matrix::*;
 pub use quantity::*;
 pub use tuple::*;
@@ -52,6 +54,8 @@ pub enum Value {
     Matrix(Box<Matrix>),
     /// A model in the model tree.
     Model(model::Model),
+    /// A function which can be called.
+    Function(Function),
     /// Return value
     Return(Box<Value>),
 }
@@ -79,7 +83,10 @@ impl Value {
             "-" => lhs - rhs,
             "*" => lhs * rhs,
             "/" => lhs / rhs,
-            "^" => lhs.pow(&rhs),
+            "^" => match (&lhs, &rhs) {
+                (Value::Model(_), Value::Model(_)) => lhs ^ rhs,
+                _ => lhs.pow(&rhs),
+            },
             "&" | "and" => lhs & rhs,
             "|" | "or" => lhs | rhs,
             ">" => Ok(Value::Bool(lhs > rhs)),
@@ -167,6 +174,7 @@ impl crate::ty::Ty for Value {
             Value::Tuple(tuple) => tuple.ty(),
             Value::Matrix(matrix) => matrix.ty(),
             Value::Model(_) => Type::Model,
+            Value::Function(function) => function.ty(),
             Value::Return(r) => r.ty(),
         }
     }
@@ -370,6 +378,18 @@ impl std::ops::BitAnd for Value {
     }
 }
 
+/// Rules for operator `^` (symmetric difference).
+impl std::ops::BitXor for Value {
+    type Output = ValueResult;
+
+    fn bitxor(self, rhs: Self) -> Self::Output {
+        match (self, rhs) {
+            (Value::Model(lhs), Value::Model(rhs)) => map_model_result(lhs ^ rhs),
+            (lhs, rhs) => Err(ValueError::InvalidOperator(format!("{lhs} ^ {rhs}"))),
+        }
+    }
+}
+
 impl std::fmt::Display for Value {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         match self {
@@ -382,6 +402,7 @@ impl std::fmt::Display for Value {
             Value::Tuple(t) => write!(f, "{t}"),
             Value::Matrix(m) => write!(f, "{m}"),
             Value::Model(n) => write!(f, "{n}"),
+            Value::Function(function) => write!(f, "{function}"),
             Value::Return(r) => write!(f, "{r}"),
         }
     }
@@ -399,6 +420,7 @@ impl std::hash::Hash for Value {
             Value::Tuple(tuple) => tuple.hash(state),
             Value::Matrix(matrix) => matrix.hash(state),
             Value::Model(model) => model.hash(state),
+            Value::Function(function) => function.hash(state),
             Value::Return(value) => value.hash(state),
         }
     }
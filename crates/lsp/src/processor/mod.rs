@@ -67,8 +67,8 @@ pub struct Processor {
     /// Response handler.
     pub response_sender: Sender<ProcessorResponse>,
 
-    /// Processor documents.
-    pub documents: mu::HashMap<Url, mu::document::Source>,
+    /// Open documents which share parsed library files.
+    pub session: mu::Session,
 }
 
 /// Type alias for a Result from a processor command.
@@ -91,49 +91,40 @@ impl Processor {
 
     /// Process a µcad file (parse, resolve, eval).
     pub fn add_document(&mut self, url: Url) -> ProcessorResult {
-        match mu::document::Source::load(url.clone()) {
-            Ok(mut document) => {
-                document.load_from_file()?;
-                Self::compile_document(&mut document)?;
-                self.documents.insert(url, document);
-            }
+        match self.session.add_document(url.clone()) {
+            Ok(_) => self.compile(),
             Err(_) => {
-                log::error!("Could not load document: {url}")
+                log::error!("Could not load document: {url}");
+                Ok(vec![])
             }
         }
-        Ok(vec![])
     }
 
     /// Remove µcad file.
     pub fn remove_document(&mut self, url: &Url) -> ProcessorResult {
-        self.documents.remove(url);
+        self.session.remove_document(url);
         Ok(vec![])
     }
 
-    fn compile_document(document: &mut mu::document::Source) -> ProcessorResult {
-        match document
-            .parse()
-            .and(document.lower())
-            .and(document.resolve(mu::ResolveParameters::default()))
-            .and(document.eval())
-        {
-            Ok(_) => Ok(vec![]),
-            Err(_) => {
-                log::error!("Error compiling document");
-                Ok(vec![])
+    /// Compile all outdated documents of the session.
+    fn compile(&mut self) -> ProcessorResult {
+        for (url, result) in self.session.compile() {
+            if let Err(err) = result {
+                log::error!("Error compiling document `{url}`: {err}");
             }
         }
+        Ok(vec![])
     }
 
-    /// Update (re-evaluate) a document.
+    /// Update (re-evaluate) a document and all documents which depend on its file.
     pub fn update_document(&mut self, url: &Url) -> ProcessorResult {
-        match self.documents.get_mut(url) {
-            Some(document) => {
-                document.load_from_file()?;
-                Self::compile_document(document)
+        match url.to_file_path() {
+            Ok(path) => {
+                self.session.invalidate(path)?;
+                self.compile()
             }
-            None => {
-                log::error!("Document does not exist!");
+            Err(_) => {
+                log::error!("Document `{url}` is not a local file!");
                 Ok(vec![])
             }
         }
@@ -141,43 +132,40 @@ impl Processor {
 
     /// Update document code.
     pub fn update_document_code(&mut self, url: &Url, code: String) -> ProcessorResult {
-        let document =
-            self.documents
-                .entry(url.clone())
-                .or_insert(mu::document::Source::from_source(mu::base::Source {
-                    url: url.clone(),
-                    line_offset: 0,
-                    code: mu::Hashed::new(code),
-                }));
-
-        Self::compile_document(document)
+        let source = mu::document::Source::from_source(mu::base::Source {
+            url: url.clone(),
+            line_offset: 0,
+            code: mu::Hashed::new(code),
+        });
+        self.session.insert_document(Box::new(source).into());
+        self.compile()
     }
 
     /// Format document code.
     pub fn format_document(&mut self, url: &Url) -> ProcessorResult {
-        match self.documents.get_mut(url) {
-            Some(document) => match document
-                .load_from_file()
-                .and(Self::compile_document(document))
-                .and(document.format(&mu::FormatParameters::default()))
-            {
-                Ok(formatted) => {
-                    if formatted {
-                        Self::compile_document(document)?;
+        self.update_document(url)?;
+        match self.session.remove_document(url) {
+            Some(mut document) => {
+                let formatted = document.format(&mu::FormatParameters::default());
+                let code = match &document {
+                    mu::Document::Source(source) => source.get_code().map(|s| s.to_string()),
+                    _ => None,
+                };
+                self.session.insert_document(document);
+                match formatted {
+                    Ok(_) => {
+                        self.compile()?;
+                        Ok(vec![ProcessorResponse::UpdatedDocumentCode {
+                            url: url.clone(),
+                            code: code.unwrap_or_default(),
+                        }])
+                    }
+                    Err(err) => {
+                        log::error!("Error formatting document `{url}`: {err}");
+                        Ok(vec![])
                     }
-                    Ok(vec![ProcessorResponse::UpdatedDocumentCode {
-                        url: url.clone(),
-                        code: document
-                            .get_code()
-                            .map(|s| s.to_string())
-                            .unwrap_or_default(),
-                    }])
-                }
-                Err(err) => {
-                    log::error!("Error formatting document `{url}`: {err}");
-                    Ok(vec![])
                 }
-            },
+            }
             None => {
                 log::error!("Document does not exist!");
                 Ok(vec![])
@@ -187,10 +175,13 @@ impl Processor {
 
     fn get_full_semantic_tokens(&self, url: &Url) -> ProcessorResult {
         match self
+            .session
             .documents
             .get(url)
-            .and_then(|doc| doc.ast_source.as_ref())
-        {
+            .and_then(|document| match document {
+                mu::Document::Source(source) => source.ast_source.as_ref(),
+                _ => None,
+            }) {
             Some(ast) => {
                 use crate::semantic_tokens::SemanticTokens;
                 let mut ctx = crate::semantic_tokens::TokenContext::new(ast);
@@ -209,7 +200,7 @@ impl Processor {
     }
 
     fn get_document_diagnostics(&self, url: &Url) -> ProcessorResult {
-        Ok(match self.documents.get(url) {
+        Ok(match self.session.documents.get(url) {
             Some(document) => vec![ProcessorResponse::diagnostics(
                 url.clone(),
                 document.diags(),
@@ -240,7 +231,9 @@ impl ProcessorController {
     }
 
     /// Run the processing thread and create interface.
-    pub fn run() -> Self {
+    ///
+    /// Documents are resolved with the given search paths.
+    pub fn run(search_paths: Vec<std::path::PathBuf>) -> Self {
         let (request_sender, request_receiver) = crossbeam::channel::unbounded();
         let (response_sender, response_receiver) = crossbeam::channel::unbounded();
 
@@ -248,7 +241,10 @@ impl ProcessorController {
             let mut processor = Processor {
                 request_handler: request_receiver,
                 response_sender,
-                documents: mu::HashMap::default(),
+                session: mu::Session::new(mu::DriverConfig {
+                    search_paths,
+                    ..Default::default()
+                }),
             };
 
             loop {
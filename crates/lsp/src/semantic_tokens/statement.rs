@@ -98,6 +98,15 @@ impl_tokens!(ast::def::FileModule => |self_, ctx| {
     ctx.push_token(&self_.id.span, TokenType::NAMESPACE, &[]);
 });
 
+impl_tokens!(ast::def::ExternModule => |self_, ctx| {
+    self_.extras.semantic_tokens(ctx);
+    self_.doc.semantic_tokens(ctx);
+    self_.attr.iter().for_each(|attr| attr.semantic_tokens(ctx));
+    // TODO: self_.visibility.as_ref().map(|vis| vis.semantic_tokens(ctx));
+    ctx.push_token(&self_.keyword_span, TokenType::KEYWORD, &[]);
+    ctx.push_token(&self_.id.span, TokenType::NAMESPACE, &[]);
+});
+
 impl_tokens!(ast::def::Function => |self_, ctx| {
     self_.extras.semantic_tokens(ctx);
     self_.doc.semantic_tokens(ctx);
@@ -142,6 +151,15 @@ impl_tokens!(ast::def::Constant => |self_, ctx| {
     self_.expr.semantic_tokens(ctx);
 });
 
+impl_tokens!(ast::def::Unit => |self_, ctx| {
+    self_.extras.semantic_tokens(ctx);
+    self_.doc.semantic_tokens(ctx);
+    self_.attr.iter().for_each(|attr| attr.semantic_tokens(ctx));
+    ctx.push_token(&self_.keyword_span, TokenType::KEYWORD, &[]);
+    ctx.push_token(&self_.id.span, TokenType::TYPE, &[]);
+    self_.value.semantic_tokens(ctx);
+});
+
 impl_tokens!(ast::PropertyAssignment => |self_, ctx| {
     self_.extras.semantic_tokens(ctx);
     self_.attr.iter().for_each(|attr| attr.semantic_tokens(ctx));
@@ -166,6 +184,8 @@ impl_tokens!(ast::Statement => |self_, ctx| {
         Self::Workbench(workbench_definition) => workbench_definition.semantic_tokens(ctx),
         Self::InlineModule(inline_module) => inline_module.semantic_tokens(ctx),
         Self::FileModule(file_module) => file_module.semantic_tokens(ctx),
+        Self::ExternModule(extern_module) => extern_module.semantic_tokens(ctx),
+        Self::Unit(unit) => unit.semantic_tokens(ctx),
         Self::Function(function_definition) => function_definition.semantic_tokens(ctx),
         Self::Use(use_statement) => use_statement.semantic_tokens(ctx),
         Self::Const(const_assignment) => const_assignment.semantic_tokens(ctx),
@@ -8,10 +8,10 @@ use std::path::PathBuf;
 use rust_embed::RustEmbed;
 use thiserror::Error;
 
-use crate::manifest::{Manifest, ManifestError};
-
 mod manifest;
 
+pub use manifest::{Library, Manifest, ManifestError};
+
 /// Standard library error.
 #[derive(Debug, Error)]
 pub enum StdLibError {
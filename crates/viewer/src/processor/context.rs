@@ -44,6 +44,9 @@ pub struct ProcessorContext {
 
     /// µcad Render cache.
     pub(super) render_cache: mu::RcMut<mu::RenderCache>,
+
+    /// Parsed files of the search paths.
+    pub(super) source_cache: mu::RcMut<mu::SourceCache>,
 }
 
 impl Default for ProcessorContext {
@@ -58,6 +61,7 @@ impl Default for ProcessorContext {
             line_number: None,
             instance_registry: Default::default(),
             render_cache: mu::RcMut::new(mu::RenderCache::new()),
+            source_cache: mu::RcMut::new(mu::SourceCache::default()),
         }
     }
 }
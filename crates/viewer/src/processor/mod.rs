@@ -21,7 +21,10 @@ use bevy::{
     mesh::Mesh,
 };
 
+use std::sync::{Arc, Mutex};
+
 use crossbeam::channel::{Receiver, Sender};
+use microcad_driver::prelude as mu;
 use miette::IntoDiagnostic;
 
 /// A processor response.
@@ -45,6 +48,8 @@ pub enum ProcessorResponse {
 /// The processor interface.
 pub struct ProcessorInterface {
     request_sender: Sender<ProcessorRequest>,
+    /// Cancels the current render when a new source is sent.
+    cancellation: Arc<Mutex<mu::CancellationToken>>,
     /// The receiver for responses.
     pub response_receiver: Receiver<ProcessorResponse>,
 }
@@ -52,6 +57,13 @@ pub struct ProcessorInterface {
 impl ProcessorInterface {
     /// Send request.
     pub fn send_request(&self, request: ProcessorRequest) -> miette::Result<()> {
+        // A new source makes the current render obsolete.
+        if matches!(
+            request,
+            ProcessorRequest::ParseFile(_) | ProcessorRequest::ParseSource { .. }
+        ) {
+            self.cancellation.lock().expect("No poisoned lock").cancel();
+        }
         self.request_sender.send(request).into_diagnostic()
     }
 
@@ -59,12 +71,15 @@ impl ProcessorInterface {
     pub fn run() -> Self {
         let (request_sender, request_receiver) = crossbeam::channel::unbounded();
         let (response_sender, response_receiver) = crossbeam::channel::unbounded();
+        let cancellation = Arc::new(Mutex::new(mu::CancellationToken::default()));
 
+        let processor_cancellation = cancellation.clone();
         std::thread::spawn(move || {
             let mut processor = Processor {
                 context: ProcessorContext::default(),
                 request_receiver,
                 response_sender,
+                cancellation: processor_cancellation,
             };
 
             loop {
@@ -80,6 +95,7 @@ impl ProcessorInterface {
 
         Self {
             request_sender,
+            cancellation,
             response_receiver,
         }
     }
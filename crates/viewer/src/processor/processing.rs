@@ -25,6 +25,9 @@ pub struct Processor {
 
     /// Output responses.
     pub response_sender: Sender<ProcessorResponse>,
+
+    /// Token to cancel the current render, shared with the processor interface.
+    pub cancellation: std::sync::Arc<std::sync::Mutex<mu::CancellationToken>>,
 }
 
 impl Processor {
@@ -46,24 +49,38 @@ impl Processor {
         let (tx, rx) = std::sync::mpsc::channel();
         let sender = self.response_sender.clone();
         std::thread::spawn(move || {
+            // Only forward the progress when the integer percentage changes.
+            let mut last_percent = 0;
             while let Ok(progress) = rx.recv() {
-                sender
-                    .send(ProcessorResponse::StateChanged(ProcessingState::Busy(
-                        progress,
-                    )))
-                    .expect("No error");
+                if let Some(progress) = mu::RenderProgress::percent(&progress)
+                    && progress.floor() as u32 != last_percent
+                {
+                    last_percent = progress.floor() as u32;
+                    sender
+                        .send(ProcessorResponse::StateChanged(ProcessingState::Busy(
+                            progress,
+                        )))
+                        .expect("No error");
+                }
             }
         });
 
+        let cancellation = mu::CancellationToken::default();
+        *self.cancellation.lock().expect("No poisoned lock") = cancellation.clone();
+
         let compiler_params = CompileParameters {
             resolve: compile::ResolveParameters {
                 search_paths: self.context.search_paths.clone(),
+                source_cache: Some(self.context.source_cache.clone()),
+                ..Default::default()
             },
+            ..Default::default()
         };
         let render_params = RenderParameters {
             resolution: self.context.resolution.clone(),
             cache: Some(self.context.render_cache.clone()),
             progress_tx: Some(tx),
+            cancellation: Some(cancellation),
         };
 
         let responses = match document
@@ -71,6 +88,16 @@ impl Processor {
             .and(document.render(render_params))
         {
             Ok(model) => self.respond(model),
+            // A newer source is waiting to be processed.
+            Err(err)
+                if matches!(
+                    err.downcast_ref::<mu::RenderError>(),
+                    Some(mu::RenderError::Cancelled)
+                ) =>
+            {
+                log::info!("Render cancelled");
+                Ok(vec![])
+            }
             Err(err) => {
                 eprintln!(
                     "{}",
@@ -59,8 +59,9 @@ pub fn run_test(env: TestEnv) -> std::io::Result<()> {
     let model = source.compile(mu::CompileParameters {
         resolve: mu::ResolveParameters {
             search_paths: vec!["../crates/std/lib".into(), "../assets".into()],
-            no_builtin: false,
+            ..Default::default()
         },
+        ..Default::default()
     });
     let diag = source.diags();
     let error_lines = diag.error_lines();
@@ -161,8 +162,7 @@ fn report_model(
         } else {
             mu::RenderResolution::medium()
         },
-        cache: None,
-        progress_tx: None,
+        ..Default::default()
     }) {
         Ok(model) => model,
         Err(err) => {